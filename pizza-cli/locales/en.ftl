@@ -0,0 +1,244 @@
+# English messages; also the fallback for ids missing in other languages.
+# Placeholders are written `{ $name }`, as in Fluent.
+
+## Section titles and table headers
+
+recipe-title = Pizza dough
+ingredients = Ingredients
+ingredients-summary = Ingredients summary
+col-ingredient = Ingredient
+col-amount = Amount
+col-notes = Notes
+col-phase = Phase
+col-duration = Duration
+col-ends = Ends
+col-price = Price
+per-ball = Per ball
+nutrition = Nutrition
+nutrition-estimate = Nutrition (estimate)
+per-100g = Per 100 g
+cost = Cost
+batch = Batch
+ball-size = { $weight } ball
+timeline = Timeline (room { $room })
+total = Total
+hands-on = Hands-on
+notes = Notes
+ends-at = → ~end at { $time }
+room-by-schedule = ≈{ $temp } by schedule
+room-by-log = ≈{ $temp } from the log
+effort = ~{ $active } active work across { $hours } h
+
+## Ingredients
+
+ing-balls = Balls
+ing-flour = Flour
+ing-water = Water
+ing-water-temp = Water temp
+ing-salt = Salt
+ing-yeast = Yeast
+ing-dry-yeast = Dry yeast
+ing-fresh-yeast = Fresh yeast
+ing-malt = Diastatic malt
+ing-gf-blend = Gluten-free flour blend
+ing-psyllium = Psyllium husk powder
+ing-xanthan = Xanthan gum
+ing-milk = Milk
+ing-oil = Oil
+ing-sugar = Sugar
+ing-wheat-flour = Wheat flour
+ing-rice-flour = Rice flour
+ing-soy-flour = Soy flour
+
+nutrient-energy = Energy
+nutrient-carbs = Carbohydrates
+nutrient-protein = Protein
+nutrient-fat = Fat
+nutrient-salt = Salt
+
+## Ingredient notes
+
+balls-split = { $dough } dough, split by size
+salt-coarse = { $base }, coarse
+salt-flaky = { $base }, flaky
+salt-delayed = { $base }; add after { $minutes } min of mixing
+yeast-estimate = ~% of flour (estimate)
+yeast-per-dry = ~{ $factor }× dry yeast
+yeast-fixed = { $pct }% of flour (fixed)
+yeast-viability = { $base }; ×{ $scale } for { $viability }% viability
+malt-share = { $pct }% of flour
+binder-share = { $pct }% of the blend; whisk it in dry
+water-binder = incl. { $water } for the binder
+milk-share = whole milk, in place of { $pct }% of the water
+pct-of-flour = { $pct }% of flour
+flour-absorption = { $flour } · { $wh } (above its ~{ $absorption }% absorption)
+w-from-protein = W≈{ $w } (from { $protein }% protein)
+water-warm = warm the water
+water-tap = tap water
+water-all-chilled = all from the fridge
+water-chilled = { $chilled } from the fridge + { $tap } tap
+water-iced = { $ice } ice + { $chilled } from the fridge
+water-all-ice = all ice ({ $ice }) and chill the flour too
+water-temp-note = for a { $ddt } dough (+{ $rise } from mixing); { $prep }
+
+## Phases and the actions that end them
+
+phase-bulk = Bulk rise (whole dough)
+phase-fridge = Fridge (covered)
+phase-warmup = Warmup (bench rest)
+phase-proof = Final proof (balls)
+phase-rest = Rest (binder hydrates)
+phase-rise = Rise (shaped)
+phase-pan-proof = Proof (in the pan)
+phase-levain = Levain build
+action-mix = Mix the dough
+action-feed-levain = Feed the levain
+action-fridge = Put the dough in the fridge
+action-unfridge = Take the dough out of the fridge
+action-ball = Ball the dough
+action-shape = Shape the pizzas on parchment
+action-pan = Press the dough into the oiled pan
+action-bake = Bake
+action-oval = Press into ovals and bake
+event-salt = Add the salt ({ $minutes } min into mixing)
+event-fold = Stretch and fold, { $n } of { $count } ({ $minutes } min after mixing)
+event-levain = Feed { $seed } starter + { $flour } flour + { $water } water = { $total }
+
+## Notes under the recipe
+
+warn-hydration = { $hydration }% hydration is outside the { $min }–{ $max }% the yeast and timing estimates are made for (--expert): treat them as a starting point and watch the dough.
+warn-short-for-w = { $hours } h is short for W { $w }: a flour this strong wants { $min }–{ $max } h to relax (--total-hours { $min }), or use a weaker flour.
+warn-long-for-w = { $hours } h is long for W { $w }: a flour this weak breaks down after about { $max } h; plan { $min }–{ $max } h (--total-hours { $max }) or use a stronger flour.
+note-fridge-suggested = { $hours } h at { $room } is too long out of the fridge: the dough overproofs after about { $max } h. Put it in the fridge for { $fridge } h (--fridge-hours { $fridge } --warmup-hours { $warmup }), or let --auto-fridge plan it.
+note-shorter-suggested = { $hours } h at { $room } is too long: the dough overproofs after about { $max } h. Shorten the process to { $max } h (--total-hours { $max }).
+note-small-yeast = Yeast ({ $yeast }) is below { $min } and hard to weigh: dissolve { $dissolve } in { $water } ml water and use { $use } ml (count it as part of the water).
+note-reschedule = Or shorten the process to { $hours } h (--total-hours { $hours }) and use { $yeast } yeast.
+hot-chilled = use { $chilled } of the water straight from the fridge
+hot-iced = replace { $ice } of the water with ice and take the rest from the fridge
+hot-all-ice = use ice for all the water ({ $ice }) and chill the flour too
+note-hot-kitchen = Hot kitchen ({ $room }): { $how } so the dough starts at about { $ddt }.
+note-yeast-fixed = Yeast fixed at { $pct }% of flour (--yeast-pct); the model suggests { $model }% ({ $grams }).
+note-no-malt = No malt added: it only pays off from { $hours } h in the fridge.
+note-malt = Long cold ferment: --malt adds { $pct }% diastatic malt for better browning.
+note-salt-coarse = Coarse salt dissolves slowly: stir it into part of the water until clear before mixing, or grind it fine.
+note-salt-flaky = Flaky salt: crush the flakes between your fingers, or dissolve them in the water first.
+note-gluten-free = Gluten-free: whisk the blend, binder, salt and yeast together dry, then stir in the water to a thick paste; there is no gluten to knead. Shape with wet or oiled hands on parchment and bake on it.
+note-emergency = Emergency dough: the yeast is at the most the estimates go to ({ $pct }% of flour) and the water warm enough for a dough at about { $ddt }. Keep it somewhere warm, such as the oven with only the light on, and ball it once it has doubled rather than by the clock; expect less flavour than from a slow dough.
+note-no-knead = No-knead: stir flour, water, salt and yeast just until no dry flour is left and cover the bowl; the long bulk rise develops the gluten. For each fold, lift one side of the dough with wet hands, stretch it up and fold it over, then turn the bowl and repeat four times. The yeast is less than usual because it keeps multiplying over the long rise.
+note-detroit = Detroit style: oil the pan and press the dough to the corners. Cube the brick cheese right up to the walls, where it fries into a crisp edge, then ladle the sauce in stripes on top; bake hot on the lowest rack.
+note-new-york = New York style bakes in a home oven at its hottest, { $low }–{ $high }: put the stone or steel on the top rack and preheat it a full hour, then bake about { $minutes } min; the oil and sugar brown the crust at these temperatures. Anything from 24 to 72 h works (--total-hours; all but { $room } h in the fridge): longer gives more flavour and a crisper crust.
+note-pinsa = Pinsa: the rice and soy flour take up the extra water, so the dough is wet but not slack; mix the water in gradually and use wet hands. To shape, dust the bench with rice flour and press each ball out with your fingertips into an oval about twice as long as wide, keeping the air in the rim; bake on parchment or a stone, then top.
+note-pan = Pan dough: oil the pan generously and press the dough out to the corners; if it springs back, wait 15 minutes and press again. Oil and sugar brown fast, so bake a little cooler or longer than a lean pizza.
+note-dry-air = Dry air ({ $rh }% humidity): the flour is drier than usual, so add about { $pct }% more water (--hydration { $hydration }) and keep the dough covered so it does not form a skin.
+note-humid-air = Humid air ({ $rh }% humidity): the dough will feel stickier; hold back about { $pct }% of the water (--hydration { $hydration }) and use a little more bench flour.
+note-model-q10 = Yeast amounts are heuristic (Q10≈2/{ $delta }; mild W effect). Fridge counted at configurable factor.
+note-model-arrhenius = Yeast amounts are heuristic (Arrhenius, Ea { $ea } kJ/mol; mild W effect). Fridge counted at configurable factor.
+note-warm = If dough rises too fast in warm conditions (>{ $temp }), shorten bulk or reduce yeast slightly.
+
+## --explain
+
+explain = How the yeast was worked out
+col-step = Step
+col-formula = Formula
+col-result = Result
+explain-hours = Effective hours
+explain-temp = Temperature factor
+explain-w = W factor
+explain-time = Time factor
+explain-dry-yeast = Dry yeast
+explain-clamped = Kept within
+explain-viability = Viability
+explain-no-knead = No-knead
+explain-enrichment = Milk, oil and sugar
+explain-fresh = Fresh yeast
+explain-fixed = Fixed yeast
+explain-flour = Flour
+
+## --check
+
+check-warning = Warning
+check-ok = No problems found.
+check-hydration = { $hydration }% hydration is high for { $flour } (absorbs about { $absorption }%): expect a slack, sticky dough.
+check-yeast-min = The yeast estimate ({ $pct }%) is below the model's minimum and was raised to { $min }%: the process is very long or warm for this dough.
+check-yeast-max = The yeast estimate ({ $pct }%) is above the model's maximum and was capped at { $max }%: the process is too short or cold for the dough to rise fully.
+
+## --toppings
+
+toppings = Toppings ({ $style } margherita)
+style-neapolitan = Neapolitan
+style-roman = Roman
+style-new-york = New York
+per-pizza = Per pizza
+pizza-size = { $diameter } pizza
+toppings-total = Total ({ $count })
+top-sauce = Tomato sauce
+top-fior-di-latte = Fior di latte
+top-mozzarella = Low-moisture mozzarella
+top-basil = Basil
+basil-leaves = { $count } leaves
+
+## --style detroit
+
+detroit = Detroit pan ({ $size })
+per-pan = Per pan
+pan-dough = Dough
+pan-cheese = Brick cheese, cubed
+pan-edge-cheese = ↳ along the walls
+pan-sauce = Sauce, in stripes
+
+## --sauce
+
+sauce = San Marzano sauce ({ $count } pizzas)
+sauce-tomatoes = Peeled San Marzano tomatoes
+sauce-tins = { $tins } × { $tin } tin; crush by hand or pass through a food mill
+sauce-share = { $pct }% of the tomatoes
+sauce-basil = torn in at the end
+sauce-oil = Extra virgin olive oil
+
+## --mixer
+
+mixing = Mixing ({ $mixer })
+col-speed = Speed
+mixer-hand = by hand
+mixer-planetary = stand mixer
+mixer-spiral = spiral mixer
+mixer-food-processor = food processor
+mix-speed = speed { $speed }
+mix-combine = Combine flour, water and yeast until no dry flour is left
+mix-rest = Rest, covered (the flour hydrates)
+mix-knead = Knead until smooth
+mix-add-water = Add the held-back water ({ $water }) a little at a time
+mix-windowpane = Windowpane check: stretch a piece thin; if it tears, knead 2 min more
+
+## Jobs ahead of baking, in the timeline
+
+prep-drain = Drain the mozzarella (2 h before baking)
+prep-sauce = Take the sauce out of the fridge (1 h before baking)
+prep-preheat = Start preheating the oven ({ $minutes } min before baking)
+
+## compare
+
+col-difference = Difference
+compare-ball-weight = Ball weight
+compare-hydration = Hydration
+compare-w = W
+compare-temp = Room temperature
+compare-bake = In the oven at
+
+## sweep
+
+sweep = Sweep: { $param }
+sweep-hydration = Hydration
+sweep-temp = Temperature
+sweep-hours = Total hours
+sweep-yeast-pct = Yeast %
+sweep-bulk = Bulk
+sweep-fridge = Fridge + warmup
+sweep-proof = Proof
+
+## --launches
+
+launches = Launch schedule ({ $bake } min bake + { $recovery } min recovery)
+col-pizza = Pizza
+col-in = In the oven
+col-out = Out
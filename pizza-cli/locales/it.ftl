@@ -0,0 +1,243 @@
+# Messaggi in italiano; gli id mancanti usano il testo inglese (en.ftl).
+
+## Titoli e intestazioni delle tabelle
+
+recipe-title = Impasto per pizza
+ingredients = Ingredienti
+ingredients-summary = Riepilogo ingredienti
+col-ingredient = Ingrediente
+col-amount = Quantità
+col-notes = Note
+col-phase = Fase
+col-duration = Durata
+col-ends = Fine
+col-price = Prezzo
+per-ball = Per panetto
+nutrition = Valori nutrizionali
+nutrition-estimate = Valori nutrizionali (stima)
+per-100g = Per 100 g
+cost = Costo
+batch = Impasto intero
+ball-size = Panetto da { $weight }
+timeline = Tempi (ambiente { $room })
+total = Totale
+hands-on = Lavoro manuale
+notes = Note
+ends-at = → fine verso le { $time }
+room-by-schedule = ≈{ $temp } da programma
+room-by-log = ≈{ $temp } dal registro
+effort = ~{ $active } di lavoro attivo su { $hours } h
+
+## Ingredienti
+
+ing-balls = Panetti
+ing-flour = Farina
+ing-water = Acqua
+ing-water-temp = Temp. acqua
+ing-salt = Sale
+ing-yeast = Lievito
+ing-dry-yeast = Lievito secco
+ing-fresh-yeast = Lievito fresco
+ing-malt = Malto diastasico
+ing-gf-blend = Mix di farine senza glutine
+ing-psyllium = Psyllium in polvere
+ing-xanthan = Gomma di xantano
+ing-milk = Latte
+ing-oil = Olio
+ing-sugar = Zucchero
+ing-wheat-flour = Farina di grano
+ing-rice-flour = Farina di riso
+ing-soy-flour = Farina di soia
+
+nutrient-energy = Energia
+nutrient-carbs = Carboidrati
+nutrient-protein = Proteine
+nutrient-fat = Grassi
+nutrient-salt = Sale
+
+## Note sugli ingredienti
+
+balls-split = { $dough } di impasto, diviso per peso
+salt-coarse = { $base }, grosso
+salt-flaky = { $base }, in fiocchi
+salt-delayed = { $base }; da aggiungere dopo { $minutes } min di impasto
+yeast-estimate = ~% della farina (stima)
+yeast-per-dry = ~{ $factor }× il lievito secco
+yeast-fixed = { $pct }% della farina (fisso)
+yeast-viability = { $base }; ×{ $scale } per una vitalità del { $viability }%
+malt-share = { $pct }% della farina
+binder-share = { $pct }% del mix; mescolalo a secco
+water-binder = di cui { $water } per il legante
+milk-share = latte intero, al posto del { $pct }% dell'acqua
+pct-of-flour = { $pct }% della farina
+flour-absorption = { $flour } · { $wh } (oltre il suo assorbimento di ~{ $absorption }%)
+w-from-protein = W≈{ $w } (da { $protein }% di proteine)
+water-warm = scalda l'acqua
+water-tap = acqua del rubinetto
+water-all-chilled = tutta dal frigo
+water-chilled = { $chilled } dal frigo + { $tap } dal rubinetto
+water-iced = { $ice } di ghiaccio + { $chilled } dal frigo
+water-all-ice = tutta ghiaccio ({ $ice }) e raffredda anche la farina
+water-temp-note = per un impasto a { $ddt } (+{ $rise } dall'impastamento); { $prep }
+
+## Fasi e azioni che le chiudono
+
+phase-bulk = Puntata (massa unica)
+phase-fridge = Frigo (coperto)
+phase-warmup = Riposo fuori frigo
+phase-proof = Appretto (panetti)
+phase-rest = Riposo (idratazione)
+phase-rise = Lievitazione (stese)
+phase-pan-proof = Appretto (in teglia)
+phase-levain = Rinfresco madre
+action-mix = Impasta
+action-feed-levain = Rinfresca il lievito madre
+action-fridge = Metti l'impasto in frigo
+action-unfridge = Togli l'impasto dal frigo
+action-ball = Forma i panetti
+action-shape = Stendi le pizze su carta forno
+action-pan = Stendi l'impasto nella teglia unta
+action-bake = Inforna
+action-oval = Stendi a ovale e inforna
+event-salt = Aggiungi il sale ({ $minutes } min dopo l'inizio dell'impasto)
+event-fold = Pieghe, { $n } di { $count } ({ $minutes } min dopo l'impasto)
+event-levain = Rinfresca { $seed } di madre + { $flour } di farina + { $water } d'acqua = { $total }
+
+## Note sotto la ricetta
+
+warn-hydration = L'idratazione al { $hydration }% è fuori dal { $min }–{ $max }% per cui sono pensate le stime di lievito e tempi (--expert): prendile come punto di partenza e osserva l'impasto.
+warn-short-for-w = { $hours } h sono poche per una W { $w }: una farina così forte vuole { $min }–{ $max } h per distendersi (--total-hours { $min }), oppure usa una farina più debole.
+warn-long-for-w = { $hours } h sono tante per una W { $w }: una farina così debole cede dopo circa { $max } h; prevedi { $min }–{ $max } h (--total-hours { $max }) oppure usa una farina più forte.
+note-fridge-suggested = { $hours } h a { $room } sono troppe fuori dal frigo: l'impasto si sovralievita dopo circa { $max } h. Mettilo in frigo per { $fridge } h (--fridge-hours { $fridge } --warmup-hours { $warmup }), oppure lascia che lo pianifichi --auto-fridge.
+note-shorter-suggested = { $hours } h a { $room } sono troppe: l'impasto si sovralievita dopo circa { $max } h. Accorcia il processo a { $max } h (--total-hours { $max }).
+note-small-yeast = Il lievito ({ $yeast }) è sotto { $min } e difficile da pesare: sciogline { $dissolve } in { $water } ml d'acqua e usane { $use } ml (fanno parte dell'acqua).
+note-reschedule = Oppure accorcia il processo a { $hours } h (--total-hours { $hours }) e usa { $yeast } di lievito.
+hot-chilled = usa { $chilled } dell'acqua direttamente dal frigo
+hot-iced = sostituisci { $ice } dell'acqua con ghiaccio e prendi il resto dal frigo
+hot-all-ice = usa ghiaccio per tutta l'acqua ({ $ice }) e raffredda anche la farina
+note-hot-kitchen = Cucina calda ({ $room }): { $how }, così l'impasto parte a circa { $ddt }.
+note-yeast-fixed = Lievito fissato al { $pct }% della farina (--yeast-pct); il modello suggerisce { $model }% ({ $grams }).
+note-no-malt = Niente malto: conviene solo da { $hours } h di frigo in su.
+note-malt = Lunga maturazione in frigo: --malt aggiunge { $pct }% di malto diastasico per una doratura migliore.
+note-salt-coarse = Il sale grosso si scioglie lentamente: mescolalo in parte dell'acqua finché è limpida prima di impastare, oppure macinalo fine.
+note-salt-flaky = Sale in fiocchi: sbriciola i fiocchi tra le dita, oppure scioglili prima nell'acqua.
+note-gluten-free = Senza glutine: mescola a secco il mix, il legante, il sale e il lievito, poi incorpora l'acqua fino a una pasta densa; non c'è glutine da impastare. Stendi con le mani bagnate o unte su carta forno e cuoci sopra di essa.
+note-emergency = Impasto d'emergenza: il lievito è al massimo previsto dalle stime ({ $pct }% della farina) e l'acqua è abbastanza calda per un impasto a circa { $ddt }. Tienilo in un posto caldo, come il forno con la sola luce accesa, e fai le palline quando è raddoppiato, non all'orario; avrà meno sapore di un impasto lento.
+note-no-knead = Senza impasto: mescola farina, acqua, sale e lievito solo finché non resta farina asciutta e copri la ciotola; la lunga lievitazione in massa sviluppa il glutine. Per ogni giro di pieghe, con le mani bagnate solleva un lato dell'impasto, allungalo verso l'alto e ripiegalo sopra, poi gira la ciotola e ripeti quattro volte. Il lievito è meno del solito perché continua a moltiplicarsi durante la lunga lievitazione.
+note-detroit = Stile Detroit: ungi la teglia e allarga l'impasto fino agli angoli. Distribuisci il formaggio a cubetti fino ai bordi, dove frigge in una crosta croccante, poi versa la salsa a strisce sopra; cuoci ben caldo sul ripiano più basso.
+note-new-york = La newyorkese si cuoce nel forno di casa al massimo, { $low }–{ $high }: metti la pietra o l'acciaio sul ripiano alto e scaldalo per un'ora intera, poi cuoci circa { $minutes } min; olio e zucchero dorano la crosta a queste temperature. Vanno bene da 24 a 72 h (--total-hours; tutte in frigo tranne { $room } h): più a lungo dà più sapore e una crosta più croccante.
+note-pinsa = Pinsa: le farine di riso e di soia assorbono l'acqua in più, per cui l'impasto è umido ma non molle; aggiungi l'acqua poco alla volta e lavora con le mani bagnate. Per stenderla, spolvera il banco con farina di riso e allarga ogni panetto con i polpastrelli in un ovale lungo circa il doppio che largo, senza schiacciare il bordo; cuocila su carta forno o sulla pietra, poi condiscila.
+note-pan = Impasto in teglia: ungi bene la teglia e allarga l'impasto fino agli angoli; se si ritira, aspetta 15 minuti e riprova. Olio e zucchero scuriscono in fretta: cuoci un po' più basso o più a lungo di una pizza senza grassi.
+note-dry-air = Aria secca ({ $rh }% di umidità): la farina è più asciutta del solito, quindi aggiungi circa il { $pct }% di acqua in più (--hydration { $hydration }) e tieni l'impasto coperto perché non faccia la crosta.
+note-humid-air = Aria umida ({ $rh }% di umidità): l'impasto sarà più appiccicoso; togli circa il { $pct }% dell'acqua (--hydration { $hydration }) e usa un po' più di farina sul banco.
+note-model-q10 = Le dosi di lievito sono stimate (Q10≈2/{ $delta }; lieve effetto della W). Il frigo conta con un fattore configurabile.
+note-model-arrhenius = Le dosi di lievito sono stimate (Arrhenius, Ea { $ea } kJ/mol; lieve effetto della W). Il frigo conta con un fattore configurabile.
+note-warm = Se l'impasto cresce troppo in fretta al caldo (>{ $temp }), accorcia la puntata o riduci un po' il lievito.
+
+## --explain
+
+explain = Come è stato calcolato il lievito
+col-step = Passo
+col-formula = Formula
+col-result = Risultato
+explain-hours = Ore effettive
+explain-temp = Fattore temperatura
+explain-w = Fattore W
+explain-time = Fattore tempo
+explain-dry-yeast = Lievito secco
+explain-clamped = Limitato a
+explain-viability = Vitalità
+explain-no-knead = Senza impasto
+explain-enrichment = Latte, olio e zucchero
+explain-fresh = Lievito fresco
+explain-fixed = Lievito fisso
+explain-flour = Farina
+
+## --check
+
+check-warning = Attenzione
+check-ok = Nessun problema.
+check-hydration = L'idratazione al { $hydration }% è alta per { $flour } (assorbe circa il { $absorption }%): aspettati un impasto molle e appiccicoso.
+check-yeast-min = La stima del lievito ({ $pct }%) è sotto il minimo del modello ed è stata alzata al { $min }%: il processo è molto lungo o caldo per questo impasto.
+check-yeast-max = La stima del lievito ({ $pct }%) è sopra il massimo del modello ed è stata limitata al { $max }%: il processo è troppo breve o freddo perché l'impasto cresca bene.
+
+## --toppings
+
+toppings = Condimento (margherita { $style })
+style-neapolitan = napoletana
+style-roman = romana
+style-new-york = newyorkese
+per-pizza = Per pizza
+pizza-size = Pizza da { $diameter }
+toppings-total = Totale ({ $count })
+top-sauce = Pomodoro
+top-fior-di-latte = Fior di latte
+top-mozzarella = Mozzarella low-moisture
+top-basil = Basilico
+basil-leaves = { $count } foglie
+
+## --style detroit
+
+detroit = Teglia Detroit ({ $size })
+per-pan = Per teglia
+pan-dough = Impasto
+pan-cheese = Formaggio brick a cubetti
+pan-edge-cheese = ↳ lungo i bordi
+pan-sauce = Salsa, a strisce
+
+## --sauce
+
+sauce = Salsa di San Marzano ({ $count } pizze)
+sauce-tomatoes = Pomodori pelati San Marzano
+sauce-tins = { $tins } × latta da { $tin }; schiacciali a mano o passali al passaverdure
+sauce-share = { $pct }% dei pomodori
+sauce-basil = spezzettato alla fine
+sauce-oil = Olio extravergine d'oliva
+
+## --mixer
+
+mixing = Impasto ({ $mixer })
+col-speed = Velocità
+mixer-hand = a mano
+mixer-planetary = planetaria
+mixer-spiral = impastatrice a spirale
+mixer-food-processor = robot da cucina
+mix-speed = velocità { $speed }
+mix-combine = Unisci farina, acqua e lievito finché non resta farina asciutta
+mix-rest = Riposo, coperto (la farina si idrata)
+mix-knead = Impasta fino a renderlo liscio
+mix-add-water = Aggiungi l'acqua tenuta da parte ({ $water }) poco alla volta
+mix-windowpane = Prova del velo: stendi un pezzetto sottile; se si strappa, impasta altri 2 min
+
+## Preparativi prima di infornare, nei tempi
+
+prep-drain = Scola la mozzarella (2 h prima di infornare)
+prep-sauce = Togli la salsa dal frigo (1 h prima di infornare)
+prep-preheat = Accendi il forno ({ $minutes } min prima di infornare)
+
+## compare
+
+col-difference = Differenza
+compare-ball-weight = Peso del panetto
+compare-hydration = Idratazione
+compare-w = W
+compare-temp = Temperatura ambiente
+compare-bake = In forno alle
+
+## sweep
+
+sweep = Variazione: { $param }
+sweep-hydration = Idratazione
+sweep-temp = Temperatura
+sweep-hours = Ore totali
+sweep-yeast-pct = Lievito %
+sweep-bulk = Puntata
+sweep-fridge = Frigo + riposo
+sweep-proof = Appretto
+
+## --launches
+
+launches = Infornate ({ $bake } min di cottura + { $recovery } min di recupero)
+col-pizza = Pizza
+col-in = In forno
+col-out = Fuori
@@ -0,0 +1,263 @@
+//! The bake in progress: `start` saves its parameters and the planned steps to
+//! `<data dir>/pizza/bake.json`, `status` and `next` read them back. Marking a
+//! step done late (or early) moves the steps after it by the same amount;
+//! `next --late` moves them without marking anything, and `adjust` replans
+//! them for a kitchen warmer or cooler than planned.
+
+use crate::{
+    Args, history,
+    profile::Profile,
+    recipe::{Recipe, first_action, fmt_hhmm, phase_rows},
+    theme::{Role, Theme},
+    timer::fmt_remaining,
+};
+use chrono::{Duration, NaiveDateTime};
+use pizza_core::model::FermentationModel;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::PathBuf, sync::Arc};
+
+/// One hands-on step and the phase it ends.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Step {
+    pub action: String,
+    /// The phase that ends with this step (none for mixing).
+    pub phase: Option<String>,
+    pub at: NaiveDateTime,
+    pub done: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Bake {
+    pub params: Profile,
+    pub steps: Vec<Step>,
+}
+
+fn path() -> Result<PathBuf, String> {
+    history::dir()
+        .map(|d| d.join("bake.json"))
+        .ok_or_else(|| "No data directory for the bake state".to_string())
+}
+
+impl Bake {
+    /// A bake mixed at the recipe's start time (its levain fed ahead of it).
+    pub fn new(args: &Args, r: &Recipe) -> Result<Self, String> {
+        let (first, at) = first_action(r);
+        let at = at.ok_or("A bake needs a valid --start time")?;
+        let mut steps = vec![Step {
+            action: first.to_string(),
+            phase: None,
+            at,
+            done: true,
+        }];
+        for row in phase_rows(r) {
+            if let Some(end) = row.end {
+                steps.push(Step {
+                    action: row.action.to_string(),
+                    phase: Some(row.label.to_string()),
+                    at: end,
+                    done: false,
+                });
+            }
+        }
+        Ok(Bake {
+            params: Profile::from(args),
+            steps,
+        })
+    }
+
+    pub fn load() -> Result<Self, String> {
+        let path = path()?;
+        let text = fs::read_to_string(&path)
+            .map_err(|_| "No bake in progress; begin one with `start`".to_string())?;
+        serde_json::from_str(&text).map_err(|e| format!("Cannot read {}: {e}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = path()?;
+        let write = || -> std::io::Result<()> {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, serde_json::to_string_pretty(self).unwrap())
+        };
+        write().map_err(|e| format!("Cannot write {}: {e}", path.display()))
+    }
+
+    /// The fermentation model the bake was planned with.
+    pub fn model(&self) -> Arc<dyn FermentationModel> {
+        let p = &self.params;
+        p.model.unwrap_or_default().model_for(p.no_knead)
+    }
+
+    /// Index of the first step not done yet.
+    fn pending(&self) -> Option<usize> {
+        self.steps.iter().position(|s| !s.done)
+    }
+
+    /// Move every pending step by `by`.
+    pub fn delay(&mut self, by: Duration) {
+        for s in self.steps.iter_mut().filter(|s| !s.done) {
+            s.at += by;
+        }
+    }
+
+    /// Replan the pending steps for room phases running `speed` times as
+    /// fast as planned from `since` on (a warmer kitchen: the proof ends and
+    /// the pizzas bake earlier). The time in the fridge stays as it is.
+    pub fn adjust(&mut self, since: NaiveDateTime, speed: f64) {
+        // Steps end bulk, [fridge, warmup,] proof: the fridge is the second.
+        let fridge = (self.params.fridge_hours > 0.0).then_some(2);
+        let planned: Vec<NaiveDateTime> = self.steps.iter().map(|s| s.at).collect();
+        for i in 1..self.steps.len() {
+            if self.steps[i].done {
+                continue;
+            }
+            let start = self.steps[i - 1].at;
+            let mins = (planned[i] - planned[i - 1]).num_minutes() as f64;
+            let mins = match Some(i) == fridge {
+                true => mins,
+                false => {
+                    // The part before `since` went at the planned pace.
+                    let before = ((since - start).num_minutes() as f64).clamp(0.0, mins);
+                    before + (mins - before) / speed
+                }
+            };
+            self.steps[i].at = start + Duration::minutes(mins.round() as i64);
+        }
+    }
+
+    /// Mark the next step done at `now`; the rest move by how late (or early)
+    /// it was. Returns the step's action.
+    pub fn advance(&mut self, now: NaiveDateTime) -> Option<String> {
+        let i = self.pending()?;
+        let late = now - self.steps[i].at;
+        self.steps[i].at = now;
+        self.steps[i].done = true;
+        self.delay(late);
+        Some(self.steps[i].action.clone())
+    }
+
+    /// "Ball the dough at 21:35 (in 1 h 25 min)", or how overdue it is.
+    pub fn next_line(&self, now: NaiveDateTime, theme: Theme) -> String {
+        let Some(i) = self.pending() else {
+            return "All steps done. Enjoy your pizza!".to_string();
+        };
+        let s = &self.steps[i];
+        let action = theme.paint(Role::Action, &s.action);
+        let mins = (s.at - now).num_minutes();
+        match mins {
+            m if m >= 0 => format!(
+                "Next: {action} at {} (in {})",
+                fmt_hhmm(s.at),
+                fmt_remaining(m)
+            ),
+            m => format!(
+                "Next: {action} was due at {} ({} ago); run `next --done` when it is done",
+                fmt_hhmm(s.at),
+                fmt_remaining(-m)
+            ),
+        }
+    }
+
+    /// Current phase, time into it, and the next step.
+    pub fn status(&self, now: NaiveDateTime, theme: Theme) -> String {
+        let mut out = String::new();
+        if let Some(i) = self.pending() {
+            let since = self.steps[i - 1].at;
+            let phase = self.steps[i].phase.as_deref().unwrap_or("?");
+            out.push_str(&format!(
+                "Phase: {phase} since {} ({} in)\n",
+                fmt_hhmm(since),
+                fmt_remaining((now - since).num_minutes().max(0))
+            ));
+        }
+        out.push_str(&self.next_line(now, theme));
+        out.push('\n');
+        for s in &self.steps {
+            let mark = if s.done { "✓" } else { " " };
+            out.push_str(&format!(
+                "  {mark} {} {}  {}\n",
+                s.at.format("%a"),
+                fmt_hhmm(s.at),
+                s.action
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plan, prepare, repl};
+
+    #[test]
+    fn late_steps_move_the_rest() {
+        let tokens: Vec<String> = "--w 260 --total-hours 8 --start 10:00 --lang en"
+            .split(' ')
+            .map(String::from)
+            .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let r = plan(&args).unwrap();
+        let mut bake = Bake::new(&args, &r).unwrap();
+        let start = r.start.unwrap();
+        let bake_at = bake.steps.last().unwrap().at;
+        assert_eq!(bake_at - start, Duration::hours(8));
+
+        let planned = bake.steps[1].at;
+        let late = planned + Duration::minutes(20);
+        assert!(bake.status(late, Theme::Plain).contains("was due"));
+        assert_eq!(bake.advance(late).as_deref(), Some("Ball the dough"));
+        assert_eq!(
+            bake.steps.last().unwrap().at,
+            bake_at + Duration::minutes(20)
+        );
+
+        bake.delay(Duration::minutes(10));
+        assert_eq!(bake.steps[1].at, late);
+        assert_eq!(
+            bake.steps.last().unwrap().at,
+            bake_at + Duration::minutes(30)
+        );
+        assert!(bake.next_line(late, Theme::Plain).starts_with("Next: Bake"));
+    }
+
+    #[test]
+    fn warmer_kitchen_bakes_earlier() {
+        let tokens: Vec<String> =
+            "--w 260 --total-hours 24 --fridge-hours 16 --start 10:00 --lang en"
+                .split(' ')
+                .map(String::from)
+                .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let r = plan(&args).unwrap();
+        let mut bake = Bake::new(&args, &r).unwrap();
+        let planned: Vec<NaiveDateTime> = bake.steps.iter().map(|s| s.at).collect();
+        let fridge = planned[2] - planned[1];
+
+        // Twice as fast from the start: room phases take half as long.
+        bake.adjust(planned[0], 2.0);
+        let bulk = planned[1] - planned[0];
+        let half = (bulk.num_minutes() as f64 / 2.0).round() as i64;
+        assert_eq!(bake.steps[1].at - planned[0], Duration::minutes(half));
+        assert_eq!(bake.steps[2].at - bake.steps[1].at, fridge);
+        assert!(bake.steps.last().unwrap().at < planned[planned.len() - 1]);
+
+        // Nothing changes before `since`.
+        let mut bake = Bake::new(&args, &r).unwrap();
+        bake.adjust(planned[3], 2.0);
+        assert_eq!(bake.steps[3].at, planned[3]);
+
+        // Replanned with the model it was planned with.
+        let tokens: Vec<String> = "--w 260 --total-hours 8 --model arrhenius --lang en"
+            .split(' ')
+            .map(String::from)
+            .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let bake = Bake::new(&args, &plan(&args).unwrap()).unwrap();
+        assert_eq!(bake.params.model, Some(crate::ModelFlag::Arrhenius));
+        let arrhenius = pizza_core::model::Arrhenius::default();
+        assert_eq!(
+            bake.model().relative(20.0, 5.0),
+            arrhenius.relative(20.0, 5.0)
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! `bot` (feature `telegram`): answers `/pizza 4 balls ready at 20:00 temp 24`
+//! in Telegram with the recipe, then sends a reminder at the start and at the
+//! end of each phase.
+//!
+//! Messages accept a few phrases (`N balls`, `280g`, `ready at HH:MM`, `24h`,
+//! `fridge 16h`, `70%`, `dry`/`fresh`) plus any recipe flag as `name value`
+//! (`temp 24`, `w 280`). Flags given to `bot` itself (such as `--profile`)
+//! are the defaults for every message. Reminders live in memory and are lost
+//! when the bot stops.
+
+use crate::{
+    plan, prepare,
+    recipe::{self, fmt_hhmm},
+    repl, serve,
+};
+use chrono::{Duration, Local, NaiveDateTime, NaiveTime};
+use serde_json::{Value, json};
+
+const HELP: &str = "\
+/pizza 4 balls ready at 20:00 temp 24 — recipe and reminders
+  also: 280g, 24h, fridge 16h, 70%, dry, fresh, w 280, or any flag as `name value`
+/cancel — drop your pending reminders";
+
+/// Longest wait for new messages (seconds); shorter when a reminder is due.
+const POLL_S: i64 = 50;
+
+struct Reminder {
+    at: NaiveDateTime,
+    chat: i64,
+    text: String,
+}
+
+/// Flags and the wanted ready time from the words after `/pizza`.
+fn message_tokens(text: &str) -> Result<(Vec<String>, Option<NaiveTime>), String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut tokens = Vec::new();
+    let mut ready = None;
+    let hhmm = |s: &str| {
+        NaiveTime::parse_from_str(s, "%H:%M").map_err(|_| format!("expected HH:MM, got '{s}'"))
+    };
+    fn flag(tokens: &mut Vec<String>, name: &str, value: &str) {
+        tokens.push(format!("--{name}"));
+        tokens.push(value.to_string());
+    }
+    let mut i = 0;
+    while i < words.len() {
+        let w = words[i].to_lowercase();
+        let next = words.get(i + 1).map(|s| s.to_lowercase());
+        match (w.as_str(), next.as_deref()) {
+            ("ready" | "bake", Some("at")) => {
+                let at = words.get(i + 2).ok_or("ready at HH:MM")?;
+                ready = Some(hhmm(at)?);
+                i += 3;
+                continue;
+            }
+            ("at", Some(t)) => {
+                ready = Some(hhmm(t)?);
+                i += 2;
+                continue;
+            }
+            ("fridge", Some(h)) => {
+                flag(&mut tokens, "fridge-hours", h.trim_end_matches('h'));
+                i += 2;
+                continue;
+            }
+            (n, Some("balls" | "ball")) if n.parse::<u32>().is_ok() => {
+                flag(&mut tokens, "balls", n);
+                i += 2;
+                continue;
+            }
+            ("dry" | "fresh", _) => flag(&mut tokens, "yeast", &w),
+            (g, _) if g.ends_with('g') && g[..g.len() - 1].parse::<f64>().is_ok() => {
+                flag(&mut tokens, "ball-weight", &g[..g.len() - 1])
+            }
+            (h, _) if h.ends_with('h') && h[..h.len() - 1].parse::<f64>().is_ok() => {
+                flag(&mut tokens, "total-hours", &h[..h.len() - 1])
+            }
+            (p, _) if p.ends_with('%') && p[..p.len() - 1].parse::<f64>().is_ok() => {
+                flag(&mut tokens, "hydration", &p[..p.len() - 1])
+            }
+            (name, value) => {
+                let name = name.trim_start_matches("--").replace('_', "-");
+                if serve::refused(&name) {
+                    return Err(format!("{name} is not available here"));
+                }
+                tokens.push(format!("--{name}"));
+                if repl::takes_value(&name) {
+                    tokens.push(value.ok_or(format!("{name} needs a value"))?.to_string());
+                    i += 1;
+                }
+            }
+        }
+        i += 1;
+    }
+    Ok((tokens, ready))
+}
+
+/// The reply and the reminders for one `/pizza` message.
+fn answer(
+    base: &[String],
+    text: &str,
+    chat: i64,
+    now: NaiveDateTime,
+) -> Result<(String, Vec<Reminder>), String> {
+    let (tokens, ready) = message_tokens(text)?;
+    let all: Vec<String> = base.iter().cloned().chain(tokens).collect();
+    let args = prepare(repl::parse(&all)?)?;
+    let mut r = plan(&args)?;
+    let total = Duration::minutes((recipe::total_hours(&r.tl) * 60.0).round() as i64);
+    // A --levain build comes ahead of the mix.
+    let lead = Duration::minutes(recipe::levain_minutes(&r));
+    let start = match ready {
+        // The first such time that leaves room for the whole process.
+        Some(t) => {
+            let mut at = now.date().and_time(t);
+            while at - total - lead < now {
+                at += Duration::days(1);
+            }
+            at - total
+        }
+        None => r.start.unwrap_or(now + lead),
+    };
+    r.reschedule(start);
+    let (first, first_text) = match lead.is_zero() {
+        true => ("mix", "Time to mix the dough 🍕"),
+        false => ("feed the levain", "Time to feed the levain 🍕"),
+    };
+    let first_at = start - lead;
+
+    let when = |t: NaiveDateTime| match (t.date() - now.date()).num_days() {
+        0 => fmt_hhmm(t),
+        1 => format!("{} tomorrow", fmt_hhmm(t)),
+        _ => format!("{} {}", fmt_hhmm(t), t.format("%a")),
+    };
+    let mut reply = String::from("🍕 Ingredients\n");
+    for [name, amount, _] in recipe::ingredient_rows(&r) {
+        reply.push_str(&format!("• {name}: {amount}\n"));
+    }
+    reply.push_str(&format!("\n⏱ Timeline\n• {}: {first}\n", when(first_at)));
+    let mut reminders = vec![Reminder {
+        at: first_at,
+        chat,
+        text: first_text.to_string(),
+    }];
+    for row in recipe::phase_rows(&r) {
+        if let Some(end) = row.end {
+            reply.push_str(&format!(
+                "• {}: {} ({})\n",
+                when(end),
+                row.action,
+                row.label
+            ));
+            reminders.push(Reminder {
+                at: end,
+                chat,
+                text: format!("⏰ {} — {} done", row.action, row.label),
+            });
+        }
+    }
+    reply.push_str("\nI'll remind you at each step (/cancel to stop).");
+    reminders.retain(|rem| rem.at > now);
+    Ok((reply, reminders))
+}
+
+struct Api {
+    base: String,
+}
+
+impl Api {
+    fn call(&self, method: &str, body: Value) -> Result<Value, String> {
+        let resp: Value = ureq::post(&format!("{}/{method}", self.base))
+            .send_json(body)
+            .map_err(|e| format!("{method}: {e}"))?
+            .body_mut()
+            .read_json()
+            .map_err(|e| format!("{method}: {e}"))?;
+        match resp["ok"].as_bool() {
+            Some(true) => Ok(resp["result"].clone()),
+            _ => Err(format!("{method}: {}", resp["description"])),
+        }
+    }
+
+    fn send(&self, chat: i64, text: &str) {
+        if let Err(e) = self.call("sendMessage", json!({ "chat_id": chat, "text": text })) {
+            eprintln!("{e}");
+        }
+    }
+}
+
+/// Poll Telegram for messages until interrupted.
+pub fn run(token: &str, base: Vec<String>) -> Result<(), String> {
+    // Catch bad default flags before going online.
+    repl::parse(&base)?;
+    let api = Api {
+        base: format!("https://api.telegram.org/bot{token}"),
+    };
+    let me = api.call("getMe", json!({}))?;
+    eprintln!("Running as @{}", me["username"].as_str().unwrap_or("?"));
+
+    let mut offset = 0;
+    let mut reminders: Vec<Reminder> = Vec::new();
+    loop {
+        let now = Local::now().naive_local();
+        reminders.retain(|r| {
+            let due = r.at <= now;
+            if due {
+                api.send(r.chat, &r.text);
+            }
+            !due
+        });
+        let wait = reminders
+            .iter()
+            .map(|r| (r.at - now).num_seconds())
+            .min()
+            .unwrap_or(POLL_S)
+            .clamp(0, POLL_S);
+
+        let updates = match api.call(
+            "getUpdates",
+            json!({ "offset": offset, "timeout": wait, "allowed_updates": ["message"] }),
+        ) {
+            Ok(u) => u,
+            Err(e) => {
+                eprintln!("{e}");
+                std::thread::sleep(std::time::Duration::from_secs(5));
+                continue;
+            }
+        };
+        for update in updates.as_array().into_iter().flatten() {
+            offset = update["update_id"].as_i64().unwrap_or(offset) + 1;
+            let msg = &update["message"];
+            let (Some(chat), Some(text)) = (msg["chat"]["id"].as_i64(), msg["text"].as_str())
+            else {
+                continue;
+            };
+            let (cmd, rest) = text.split_once(' ').unwrap_or((text, ""));
+            // Commands may carry the bot name in groups: /pizza@my_bot.
+            match cmd.split('@').next().unwrap_or_default() {
+                "/pizza" => match answer(&base, rest, chat, Local::now().naive_local()) {
+                    Ok((reply, mut new)) => {
+                        api.send(chat, &reply);
+                        reminders.append(&mut new);
+                    }
+                    Err(e) => api.send(chat, &e),
+                },
+                "/cancel" => {
+                    let before = reminders.len();
+                    reminders.retain(|r| r.chat != chat);
+                    api.send(
+                        chat,
+                        &format!("Cancelled {} reminder(s).", before - reminders.len()),
+                    );
+                }
+                _ => api.send(chat, HELP),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_message_phrases() {
+        let (tokens, ready) =
+            message_tokens("4 balls ready at 20:00 temp 24 fridge 16h 24h 270g 70% fresh malt")
+                .unwrap();
+        assert_eq!(
+            tokens,
+            [
+                "--balls",
+                "4",
+                "--temp",
+                "24",
+                "--fridge-hours",
+                "16",
+                "--total-hours",
+                "24",
+                "--ball-weight",
+                "270",
+                "--hydration",
+                "70",
+                "--yeast",
+                "fresh",
+                "--malt"
+            ]
+        );
+        assert_eq!(ready, NaiveTime::from_hms_opt(20, 0, 0));
+        assert!(message_tokens("ready at 25:00").is_err());
+
+        let now = NaiveDateTime::parse_from_str("2025-06-01 09:00", "%Y-%m-%d %H:%M").unwrap();
+        let (reply, reminders) = answer(
+            &["--w".into(), "260".into()],
+            "2 balls ready at 20:00 8h",
+            7,
+            now,
+        )
+        .unwrap();
+        assert!(reply.contains("Flour"), "{reply}");
+        assert!(reminders.iter().all(|r| r.at > now && r.chat == 7));
+        assert_eq!(
+            reminders.last().unwrap().at,
+            now.date().and_hms_opt(20, 0, 0).unwrap()
+        );
+    }
+}
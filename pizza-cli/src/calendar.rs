@@ -0,0 +1,197 @@
+//! Busy blocks from iCalendar (.ics) files and start-time planning around them.
+//!
+//! Only hands-on steps (mixing and the action at the end of each phase) need
+//! a free slot; passive fermentation may overlap meetings.
+
+use crate::recipe::{Activity, Recipe, steps};
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::{fs, path::PathBuf};
+
+/// Granularity and horizon of the start-time search.
+const SEARCH_STEP_MIN: i64 = 15;
+const SEARCH_DAYS: i64 = 7;
+
+/// A busy interval `[start, end)` in local time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Busy {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub summary: String,
+}
+
+/// Parse an iCalendar date or date-time value into local time.
+/// Returns the value and whether it was a date-only (all-day) value.
+fn parse_dt(params: &str, value: &str) -> Option<(NaiveDateTime, bool)> {
+    if params.contains("VALUE=DATE") && !params.contains("VALUE=DATE-TIME") || value.len() == 8 {
+        let d = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some((d.and_hms_opt(0, 0, 0)?, true));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let dt = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some((
+            Utc.from_utc_datetime(&dt)
+                .with_timezone(&Local)
+                .naive_local(),
+            false,
+        ));
+    }
+    // Floating or TZID-qualified times are taken as local wall-clock time.
+    let dt = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    Some((dt, false))
+}
+
+/// Extract busy VEVENTs from iCalendar text. Transparent (free) events are skipped.
+pub fn parse_ics(text: &str) -> Vec<Busy> {
+    // Unfold continuation lines (RFC 5545 §3.1).
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.lines() {
+        let raw = raw.trim_end_matches('\r');
+        match raw.strip_prefix([' ', '\t']) {
+            Some(cont) if !lines.is_empty() => lines.last_mut().unwrap().push_str(cont),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut in_event = false;
+    let (mut start, mut end, mut summary, mut free) = (None, None, String::new(), false);
+    for line in &lines {
+        match line.as_str() {
+            "BEGIN:VEVENT" => {
+                in_event = true;
+                (start, end, summary, free) = (None, None, String::new(), false);
+            }
+            "END:VEVENT" => {
+                in_event = false;
+                let Some((s, all_day)) = start else { continue };
+                let e = match end {
+                    Some((e, _)) => e,
+                    None if all_day => s + Duration::days(1),
+                    None => continue,
+                };
+                if !free && e > s {
+                    out.push(Busy {
+                        start: s,
+                        end: e,
+                        summary: std::mem::take(&mut summary),
+                    });
+                }
+            }
+            _ if in_event => {
+                let Some((name, value)) = line.split_once(':') else {
+                    continue;
+                };
+                let (key, params) = name.split_once(';').unwrap_or((name, ""));
+                match key {
+                    "DTSTART" => start = parse_dt(params, value),
+                    "DTEND" => end = parse_dt(params, value),
+                    "SUMMARY" => summary = value.to_string(),
+                    "TRANSP" => free = value == "TRANSPARENT",
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Read and parse all busy files.
+pub fn load(paths: &[PathBuf]) -> Result<Vec<Busy>, String> {
+    let mut busy = Vec::new();
+    for path in paths {
+        let txt = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read calendar {}: {e}", path.display()))?;
+        busy.extend(parse_ics(&txt));
+    }
+    Ok(busy)
+}
+
+/// Hands-on steps as (action, from, to).
+fn hands_on(r: &Recipe) -> Vec<(String, NaiveDateTime, NaiveDateTime)> {
+    steps(r)
+        .into_iter()
+        .filter(|s| s.activity == Activity::HandsOn)
+        .filter_map(|s| {
+            let from = s.start?;
+            Some((
+                s.label.to_string(),
+                from,
+                from + Duration::minutes(s.minutes),
+            ))
+        })
+        .collect()
+}
+
+/// Hands-on steps overlapping a busy block, as (action, busy block).
+pub fn conflicts<'b>(r: &Recipe, busy: &'b [Busy]) -> Vec<(String, &'b Busy)> {
+    let mut out = Vec::new();
+    for (action, from, to) in hands_on(r) {
+        if let Some(b) = busy.iter().find(|b| from < b.end && b.start < to) {
+            out.push((action, b));
+        }
+    }
+    out
+}
+
+/// Move the recipe to the earliest start (from its current start onward) where
+/// no hands-on step falls into a busy block. Returns the new start if it moved,
+/// or `Err` if no free start exists within the search horizon.
+pub fn plan_around(r: &mut Recipe, busy: &[Busy]) -> Result<Option<NaiveDateTime>, ()> {
+    let Some(first) = r.start else {
+        return Ok(None);
+    };
+    if conflicts(r, busy).is_empty() {
+        return Ok(None);
+    }
+    let steps = SEARCH_DAYS * 24 * 60 / SEARCH_STEP_MIN;
+    for i in 1..=steps {
+        let candidate = first + Duration::minutes(i * SEARCH_STEP_MIN);
+        r.reschedule(candidate);
+        if conflicts(r, busy).is_empty() {
+            return Ok(Some(candidate));
+        }
+    }
+    r.reschedule(first);
+    Err(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICS: &str = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;TZID=Europe/Rome:20261017T100000\r\n\
+DTEND;TZID=Europe/Rome:20261017T113000\r\n\
+SUMMARY:Team\r\n  sync\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;VALUE=DATE:20261018\r\n\
+SUMMARY:Trip\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART:20261017T150000\r\n\
+DTEND:20261017T160000\r\n\
+TRANSP:TRANSPARENT\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    fn dt(d: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_timed_all_day_and_skips_transparent() {
+        let busy = parse_ics(ICS);
+        assert_eq!(busy.len(), 2);
+        assert_eq!(busy[0].start, dt(17, 10, 0));
+        assert_eq!(busy[0].end, dt(17, 11, 30));
+        assert_eq!(busy[0].summary, "Team sync");
+        assert_eq!(busy[1].start, dt(18, 0, 0));
+        assert_eq!(busy[1].end, dt(19, 0, 0));
+    }
+}
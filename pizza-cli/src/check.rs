@@ -0,0 +1,126 @@
+//! `--check`: validate the inputs and report problems without printing the
+//! recipe. Invalid input fails in `prepare` as usual (status 1); this adds
+//! the warnings about a plan that works but may not turn out well.
+
+use crate::i18n::tr;
+use crate::recipe::{Recipe, warnings, yeast_derivation};
+use crate::theme::{self, Role, glyphs};
+use pizza_core::model::YEAST_PERCENT_DRY_RANGE;
+
+/// Exit status when the input is valid but has warnings.
+pub const WARNINGS_STATUS: i32 = 3;
+
+/// Everything worth a look before mixing, most urgent first.
+pub fn problems(r: &Recipe) -> Vec<String> {
+    let a = r.args;
+    let lang = a.lang();
+    let mut out = warnings(r);
+
+    // A pinsa's rice and soy flour take the extra water.
+    if let Some(f) = a.flour()
+        && a.pinsa().is_none()
+        && a.hydration() > f.absorption + 0.10
+    {
+        out.push(tr!(
+            lang,
+            "check-hydration",
+            hydration = format!("{:.0}", a.hydration() * 100.0),
+            flour = f.name,
+            absorption = format!("{:.0}", f.absorption * 100.0)
+        ));
+    }
+
+    if a.yeast_pct.is_none() {
+        let (_, f) = yeast_derivation(r);
+        let range = YEAST_PERCENT_DRY_RANGE;
+        let pct = |x: f64| format!("{:.2}", x * 100.0);
+        if f.raw() < *range.start() {
+            out.push(tr!(
+                lang,
+                "check-yeast-min",
+                pct = pct(f.raw()),
+                min = pct(*range.start())
+            ));
+        } else if f.raw() > *range.end() {
+            out.push(tr!(
+                lang,
+                "check-yeast-max",
+                pct = pct(f.raw()),
+                max = pct(*range.end())
+            ));
+        }
+    }
+    out
+}
+
+/// Print the problems to stderr and return the exit status: 0 when there
+/// are none, [`WARNINGS_STATUS`] otherwise.
+pub fn report(r: &Recipe) -> i32 {
+    let lang = r.args.lang();
+    let theme = theme::stderr();
+    let problems = problems(r);
+    for p in &problems {
+        let line = format!("{}: {}", lang.text("check-warning"), glyphs(p));
+        eprintln!("{}", theme.paint(Role::Warning, &line));
+    }
+    match problems.len() {
+        0 => {
+            eprintln!("{}", lang.text("check-ok"));
+            0
+        }
+        _ => WARNINGS_STATUS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plan, prepare, repl};
+
+    fn check(line: &str) -> Vec<String> {
+        let tokens: Vec<String> = line.split(' ').map(String::from).collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        problems(&plan(&args).unwrap())
+    }
+
+    #[test]
+    fn warns_about_flour_and_timing() {
+        assert!(check("--w 270 --lang en").is_empty());
+
+        let short = check("--w 350 --total-hours 8 --lang en");
+        assert_eq!(short.len(), 1);
+        assert!(
+            short[0].contains("W 350") && short[0].contains("24–96 h"),
+            "{short:?}"
+        );
+
+        let pinsa = check("--w 300 --total-hours 24 --hydration 90 --expert --lang en");
+        assert!(pinsa[0].contains("90% hydration"), "{pinsa:?}");
+
+        let wet = check("--flour-name caputo-pizzeria --hydration 0.75 --lang en");
+        assert!(wet.iter().any(|p| p.contains("Caputo Pizzeria")), "{wet:?}");
+    }
+
+    #[test]
+    fn auto_fridge_only_when_it_fits() {
+        let prepared = |line: &str| {
+            let tokens: Vec<String> = line.split(' ').map(String::from).collect();
+            prepare(repl::parse(&tokens).unwrap()).unwrap()
+        };
+        let long = prepared("--w 260 --temp 40 --total-hours 12 --auto-fridge --lang en");
+        assert_eq!(long.fridge_hours(), 6.0);
+
+        // Too short for a fridge block: planned as given, with a shorter total suggested.
+        let hot = prepared("--w 260 --temp 40 --total-hours 5.5 --auto-fridge --lang en");
+        assert_eq!(hot.fridge_hours(), 0.0);
+        let notes = problems(&plan(&hot).unwrap());
+        assert!(
+            notes.iter().any(|n| n.contains("--total-hours 4")),
+            "{notes:?}"
+        );
+        assert!(
+            !notes.iter().any(|n| n.contains("--fridge-hours")),
+            "{notes:?}"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//! `compare`: two profiles side by side, ingredients then timeline, with
+//! the difference of each value (e.g. a same-day against an overnight plan).
+
+use crate::{
+    Args, YeastFlag, prepare,
+    recipe::{Recipe, fmt_hhmm, total_hours},
+    repl,
+    units::{fmt_temp, fmt_temp_delta, fmt_weight},
+};
+use std::path::Path;
+
+/// One compared value: label, first, second and the difference (empty
+/// when they are the same).
+pub type Row = [String; 4];
+
+/// Prepared arguments for `profile` with `flags` on top.
+pub fn args(profile: &Path, flags: &[String]) -> Result<Args, String> {
+    let mut tokens = vec!["--profile".to_string(), profile.display().to_string()];
+    tokens.extend_from_slice(flags);
+    prepare(repl::parse(&tokens)?)
+}
+
+/// Header: the profile names (file stems), after an empty label column.
+pub fn header(a: &Args, first: &Path, second: &Path) -> Vec<String> {
+    let name = |p: &Path| {
+        p.file_stem().map_or_else(
+            || p.display().to_string(),
+            |s| s.to_string_lossy().into_owned(),
+        )
+    };
+    vec![
+        String::new(),
+        name(first),
+        name(second),
+        a.lang().text("col-difference").to_string(),
+    ]
+}
+
+/// Ingredients, then the phases and when the pizzas go in the oven.
+pub fn rows(a: &Recipe, b: &Recipe) -> Vec<Row> {
+    let (u, lang) = (a.args.units, a.args.lang());
+    let grams = |g: f64| fmt_weight(g, u);
+    let hours = |h: f64| format!("{h:.1} h");
+    let num = |id: &'static str, x: f64, y: f64, fmt: &dyn Fn(f64) -> String| -> Row {
+        let delta = match fmt(x) == fmt(y) {
+            true => String::new(),
+            false => format!("{}{}", if y > x { "+" } else { "−" }, fmt((y - x).abs())),
+        };
+        [lang.text(id).to_string(), fmt(x), fmt(y), delta]
+    };
+    let flour = |r: &Recipe| r.ing.flour_g + r.ing.binder_g + r.ing.rice_g + r.ing.soy_g;
+    let ball = |r: &Recipe| r.args.total_dough() / r.args.ball_count() as f64;
+    let count = |r: &Recipe| r.args.ball_count() as f64;
+    let mut rows = vec![
+        num("ing-balls", count(a), count(b), &|n| format!("{n:.0}")),
+        num("compare-ball-weight", ball(a), ball(b), &grams),
+        num("ing-flour", flour(a), flour(b), &grams),
+        num("ing-water", a.ing.water_g, b.ing.water_g, &grams),
+        num("ing-salt", a.ing.salt_g, b.ing.salt_g, &grams),
+    ];
+    let yeast = |r: &Recipe| match r.args.yeast() {
+        YeastFlag::Dry => "ing-dry-yeast",
+        YeastFlag::Fresh => "ing-fresh-yeast",
+    };
+    rows.push(match yeast(a) == yeast(b) {
+        true => num(yeast(a), a.ing.yeast_g, b.ing.yeast_g, &grams),
+        // Dry against fresh: the grams do not compare.
+        false => [
+            lang.text("ing-yeast").to_string(),
+            format!("{} ({})", grams(a.ing.yeast_g), lang.text(yeast(a))),
+            format!("{} ({})", grams(b.ing.yeast_g), lang.text(yeast(b))),
+            "≠".to_string(),
+        ],
+    });
+    for (id, x, y) in [
+        ("ing-malt", a.ing.malt_g, b.ing.malt_g),
+        ("ing-milk", a.ing.milk_g, b.ing.milk_g),
+        ("ing-oil", a.ing.oil_g, b.ing.oil_g),
+        ("ing-sugar", a.ing.sugar_g, b.ing.sugar_g),
+    ] {
+        if x > 0.0 || y > 0.0 {
+            rows.push(num(id, x, y, &grams));
+        }
+    }
+    let pct = |h: f64| format!("{:.0}%", h * 100.0);
+    let w = |w: f64| format!("{w:.0}");
+    let t = a.args.temp_units();
+    let mut temp = num("compare-temp", a.args.temp(), b.args.temp(), &|c| {
+        fmt_temp(c, t)
+    });
+    if !temp[3].is_empty() {
+        temp[3] = fmt_temp_delta(b.args.temp() - a.args.temp(), t);
+    }
+    rows.extend([
+        num(
+            "compare-hydration",
+            a.args.hydration(),
+            b.args.hydration(),
+            &pct,
+        ),
+        num("compare-w", a.args.w() as f64, b.args.w() as f64, &w),
+        temp,
+        num("phase-bulk", a.tl.bulk_h, b.tl.bulk_h, &hours),
+    ]);
+    if a.tl.fridge_h > 0.0 || b.tl.fridge_h > 0.0 {
+        rows.push(num("phase-fridge", a.tl.fridge_h, b.tl.fridge_h, &hours));
+        rows.push(num("phase-warmup", a.tl.warmup_h, b.tl.warmup_h, &hours));
+    }
+    rows.push(num("phase-proof", a.tl.proof_h, b.tl.proof_h, &hours));
+    rows.push(num("total", total_hours(&a.tl), total_hours(&b.tl), &hours));
+    let bake = |r: &Recipe| r.ends.proof.map_or_else(|| "—".to_string(), fmt_hhmm);
+    let (x, y) = (bake(a), bake(b));
+    let delta = if x == y {
+        String::new()
+    } else {
+        "≠".to_string()
+    };
+    rows.push([lang.text("compare-bake").to_string(), x, y, delta]);
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan;
+
+    #[test]
+    fn differences_only_where_the_plans_differ() {
+        let prepared = |line: &str| {
+            let tokens: Vec<String> = line.split(' ').map(String::from).collect();
+            prepare(repl::parse(&tokens).unwrap()).unwrap()
+        };
+        let same_day = prepared("--w 260 --total-hours 8 --lang en");
+        let overnight = prepared("--w 260 --total-hours 24 --fridge-hours 16 --lang en");
+        let (a, b) = (plan(&same_day).unwrap(), plan(&overnight).unwrap());
+        let rows = rows(&a, &b);
+        let row = |label: &str| rows.iter().find(|r| r[0] == label).unwrap();
+        assert_eq!(row("Ball weight")[3], "");
+        assert_eq!(row("Total")[3], "+16.0 h");
+        assert!(row("Dry yeast")[3].starts_with('−'), "{rows:?}");
+        assert_eq!(row("Fridge (covered)")[1], "0.0 h");
+    }
+}
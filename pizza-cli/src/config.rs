@@ -0,0 +1,157 @@
+//! Global user configuration (`<config dir>/pizza/config.toml`).
+//!
+//! ```toml
+//! [defaults]
+//! w = 300
+//! hydration = 0.68
+//! yeast = "fresh"
+//! model = "arrhenius"
+//! ```
+//!
+//! Values under `[defaults]` replace the built-in defaults; profiles and
+//! command-line flags still take precedence over them. `expert = true`
+//! there always acts like `--expert`.
+//!
+//! Ingredient prices for `--cost` go under `[prices]`:
+//!
+//! ```toml
+//! [prices]
+//! currency = "€"
+//! flour_per_kg = 1.20
+//! salt_per_kg = 0.60
+//! dry_yeast_per_g = 0.08
+//! ```
+
+use crate::{ModelFlag, YeastFlag};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub defaults: Defaults,
+    #[serde(default)]
+    pub prices: Option<Prices>,
+}
+
+/// Personal defaults for recipe flags; every field is optional.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Defaults {
+    pub w: Option<u16>,
+    pub temp: Option<f64>,
+    pub yeast: Option<YeastFlag>,
+    pub hydration: Option<f64>,
+    pub salt_per_kg: Option<f64>,
+    pub ball_weight: Option<f64>,
+    pub balls: Option<u32>,
+    pub total_hours: Option<f64>,
+    pub fridge_hours: Option<f64>,
+    pub warmup_hours: Option<f64>,
+    pub fridge_factor: Option<f64>,
+    pub fresh_factor: Option<f64>,
+    pub model: Option<ModelFlag>,
+    pub expert: Option<bool>,
+}
+
+/// Ingredient prices; missing ones count as free.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Prices {
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    #[serde(default)]
+    pub flour_per_kg: f64,
+    #[serde(default)]
+    pub water_per_l: f64,
+    #[serde(default)]
+    pub salt_per_kg: f64,
+    #[serde(default)]
+    pub dry_yeast_per_g: f64,
+    #[serde(default)]
+    pub fresh_yeast_per_g: f64,
+}
+
+fn default_currency() -> String {
+    "€".to_string()
+}
+
+/// Location of the config file.
+pub fn path() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("pizza").join("config.toml"))
+}
+
+#[cfg(test)]
+thread_local! {
+    /// What [`load`] returns in tests, instead of the developer's own
+    /// config file: empty unless the test sets it.
+    pub static TEST_CONFIG: std::cell::RefCell<Config> = Default::default();
+}
+
+/// Load the config file; a missing file yields the empty config.
+pub fn load() -> Result<Config, String> {
+    #[cfg(test)]
+    return Ok(TEST_CONFIG.with(|c| c.borrow().clone()));
+    #[cfg(not(test))]
+    match path().filter(|p| p.is_file()) {
+        Some(path) => read(&path),
+        None => Ok(Config::default()),
+    }
+}
+
+/// Read the config at `path`.
+pub fn read(path: &Path) -> Result<Config, String> {
+    fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|txt| toml::from_str(&txt).map_err(|e| e.to_string()))
+        .map_err(|e| format!("Invalid config {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prepare, repl};
+
+    #[test]
+    fn tests_see_only_the_config_they_set() {
+        let args = |line: &str| {
+            let tokens: Vec<String> = line.split(' ').map(String::from).collect();
+            prepare(repl::parse(&tokens).unwrap()).unwrap()
+        };
+        assert_eq!(load().unwrap().defaults.w, None);
+
+        let path = std::env::temp_dir().join(format!("pizza-config-{}.toml", std::process::id()));
+        fs::write(&path, "[defaults]\nw = 300\nhydration = 0.68\n").unwrap();
+        let cfg = read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        TEST_CONFIG.with(|c| *c.borrow_mut() = cfg);
+        let a = args("--hydration 0.7 --lang en");
+        assert_eq!((a.w(), a.hydration()), (300, 0.7));
+    }
+
+    #[test]
+    fn model_from_command_line_then_profile_then_config() {
+        let args = |line: String| {
+            let tokens: Vec<String> = line.split(' ').map(String::from).collect();
+            prepare(repl::parse(&tokens).unwrap()).unwrap()
+        };
+        let profile = std::env::temp_dir().join(format!("pizza-model-{}.json", std::process::id()));
+        let p = profile.display();
+        args(format!("--w 270 --model arrhenius --save-profile {p}"));
+        let from_profile = args(format!("--profile {p}")).model();
+        let explicit = args(format!("--profile {p} --model q10")).model();
+        fs::remove_file(&profile).unwrap();
+        assert_eq!(
+            (from_profile, explicit),
+            (ModelFlag::Arrhenius, ModelFlag::Q10)
+        );
+
+        TEST_CONFIG.with(|c| c.borrow_mut().defaults.model = Some(ModelFlag::Arrhenius));
+        assert_eq!(args("--w 270".into()).model(), ModelFlag::Arrhenius);
+        assert_eq!(args("--w 270 --model q10".into()).model(), ModelFlag::Q10);
+    }
+}
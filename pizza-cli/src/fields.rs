@@ -0,0 +1,218 @@
+//! `--fields`: print selected values of the result object by dotted path
+//! (`flour,water,timeline.bulk_h`). Paths are part of the CLI's stable
+//! output; add new fields rather than renaming existing ones.
+
+use crate::recipe::{Recipe, fmt_hhmm, hands_on_minutes, total_hours};
+use comfy_table::{Attribute, Cell, ContentArrangement, Table, presets::UTF8_FULL};
+use pizza_core::{PlanWarning, pans};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The result object: amounts in grams, durations in hours, clock times as HH:MM.
+/// With mixed ball sizes, `ball_weight` is the average.
+#[derive(Serialize)]
+pub struct Summary {
+    pub balls: u32,
+    pub ball_weight: f64,
+    pub dough: f64,
+    pub flour: f64,
+    pub water: f64,
+    pub salt: f64,
+    pub yeast: f64,
+    /// Diastatic malt (0 without `--malt`).
+    pub malt: f64,
+    /// Psyllium or xanthan with `--style gluten-free` (0 otherwise); `flour`
+    /// is then the blend.
+    pub binder: f64,
+    /// Rice and soy flour with `--style pinsa` (0 otherwise); `flour` is
+    /// then the wheat.
+    pub rice: f64,
+    pub soy: f64,
+    /// Milk, oil and sugar of an enriched dough (0 without `--milk`, `--oil`,
+    /// `--sugar` or `--style pan`).
+    pub milk: f64,
+    pub oil: f64,
+    pub sugar: f64,
+    /// Water temperature (°C) for the desired dough temperature, when computed.
+    pub water_temp: Option<f64>,
+    /// Per pan with `--style detroit`.
+    pub detroit: Option<DetroitView>,
+    pub timeline: TimelineView,
+    pub schedule: ScheduleView,
+    /// What may not turn out well, tagged by `kind` (e.g. `short_for_w`,
+    /// with the suggested `min_h`/`max_h`).
+    pub warnings: Vec<PlanWarning>,
+}
+
+#[derive(Serialize)]
+pub struct DetroitView {
+    pub width_in: f64,
+    pub length_in: f64,
+    pub dough: f64,
+    pub cheese: f64,
+    /// The part of `cheese` along the walls.
+    pub edge_cheese: f64,
+    pub sauce: f64,
+}
+
+#[derive(Serialize)]
+pub struct TimelineView {
+    pub bulk_h: f64,
+    pub fridge_h: f64,
+    pub warmup_h: f64,
+    pub proof_h: f64,
+    pub total_h: f64,
+    /// Minutes of hands-on work (mixing and phase-end actions).
+    pub hands_on_min: i64,
+}
+
+#[derive(Serialize)]
+pub struct ScheduleView {
+    pub start: Option<String>,
+    pub bulk_end: Option<String>,
+    pub fridge_end: Option<String>,
+    pub warmup_end: Option<String>,
+    pub proof_end: Option<String>,
+}
+
+fn round(x: f64, decimals: i32) -> f64 {
+    let f = 10f64.powi(decimals);
+    (x * f).round() / f
+}
+
+impl Summary {
+    pub fn new(r: &Recipe) -> Self {
+        let a = r.args;
+        let g = |x: f64| round(x, 1);
+        let h = |x: f64| round(x, 2);
+        Summary {
+            balls: a.ball_count(),
+            ball_weight: g(a.total_dough() / a.ball_count() as f64),
+            dough: g(a.total_dough()),
+            flour: g(r.ing.flour_g),
+            water: g(r.ing.water_g),
+            salt: g(r.ing.salt_g),
+            yeast: g(r.ing.yeast_g),
+            malt: g(r.ing.malt_g),
+            binder: g(r.ing.binder_g),
+            rice: g(r.ing.rice_g),
+            soy: g(r.ing.soy_g),
+            milk: g(r.ing.milk_g),
+            oil: g(r.ing.oil_g),
+            sugar: g(r.ing.sugar_g),
+            water_temp: r.water.map(|(t, _)| g(t)),
+            detroit: a.detroit_pan().map(|pan| {
+                let load = pans::detroit(pan);
+                DetroitView {
+                    width_in: h(pan.width_in),
+                    length_in: h(pan.length_in),
+                    dough: g(a.ball_weight()),
+                    cheese: g(load.cheese_g),
+                    edge_cheese: g(load.edge_cheese_g),
+                    sauce: g(load.sauce_g),
+                }
+            }),
+            timeline: TimelineView {
+                bulk_h: h(r.tl.bulk_h),
+                fridge_h: h(r.tl.fridge_h),
+                warmup_h: h(r.tl.warmup_h),
+                proof_h: h(r.tl.proof_h),
+                total_h: h(total_hours(&r.tl)),
+                hands_on_min: hands_on_minutes(r),
+            },
+            schedule: ScheduleView {
+                start: r.start.map(fmt_hhmm),
+                bulk_end: r.ends.bulk.map(fmt_hhmm),
+                fridge_end: r.ends.fridge.map(fmt_hhmm),
+                warmup_end: r.ends.warmup.map(fmt_hhmm),
+                proof_end: r.ends.proof.map(fmt_hhmm),
+            },
+            warnings: r.plan_warnings.clone(),
+        }
+    }
+}
+
+/// All leaf values below `v` as (dotted path, value).
+fn leaves(prefix: &str, v: &Value, out: &mut Vec<(String, Value)>) {
+    match v {
+        Value::Object(obj) => {
+            for (k, child) in obj {
+                let path = if prefix.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{prefix}.{k}")
+                };
+                leaves(&path, child, out);
+            }
+        }
+        other => out.push((prefix.to_string(), other.clone())),
+    }
+}
+
+/// Resolve a list of dotted paths; selecting an object yields all its leaves.
+pub fn select(root: &Value, paths: &[String]) -> Result<Vec<(String, Value)>, String> {
+    let mut out = Vec::new();
+    for path in paths {
+        let node = path
+            .split('.')
+            .try_fold(root, |node, key| node.get(key))
+            .ok_or_else(|| {
+                let mut all = Vec::new();
+                leaves("", root, &mut all);
+                let names: Vec<_> = all.into_iter().map(|(p, _)| p).collect();
+                format!("Unknown field '{path}'. Available: {}", names.join(", "))
+            })?;
+        leaves(path, node, &mut out);
+    }
+    Ok(out)
+}
+
+fn plain(v: &Value) -> String {
+    match v {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Print the selected fields as a table, or bare values one per line.
+pub fn print(r: &Recipe, paths: &[String], values_only: bool) -> Result<(), String> {
+    let root = serde_json::to_value(Summary::new(r)).unwrap();
+    let selected = select(&root, paths)?;
+    if values_only {
+        for (_, v) in &selected {
+            println!("{}", plain(v));
+        }
+        return Ok(());
+    }
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(Attribute::Bold),
+            Cell::new("Value").add_attribute(Attribute::Bold),
+        ]);
+    for (path, v) in &selected {
+        table.add_row(vec![Cell::new(path), Cell::new(plain(v))]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn selects_leaves_objects_and_rejects_unknown() {
+        let root = json!({"flour": 315.7, "timeline": {"bulk_h": 6.05, "proof_h": 4.95}});
+        let paths = ["flour".to_string(), "timeline".to_string()];
+        let got = select(&root, &paths).unwrap();
+        let names: Vec<_> = got.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(names, ["flour", "timeline.bulk_h", "timeline.proof_h"]);
+        assert_eq!(got[1].1, json!(6.05));
+        assert!(select(&root, &["timeline.nope".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! Session recall: the most recent calculation (`last.json`) and the REPL
+//! command history, both kept in `<data dir>/pizza/`.
+
+use crate::{Args, profile};
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// REPL history entries shown by `history`.
+const SHOWN: usize = 20;
+
+pub fn dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|d| d.join("pizza"))
+}
+
+/// Where the parameters of the most recent calculation are stored.
+pub fn last_path() -> Option<PathBuf> {
+    dir().map(|d| d.join("last.json"))
+}
+
+/// Remember the effective parameters of a calculation (best effort): all
+/// of them that change the plan, so `last` replays it exactly.
+pub fn remember(args: &Args) {
+    if let Some(path) = last_path() {
+        let _ = profile::write(&path, &profile::Profile::from(args));
+    }
+}
+
+fn history_path() -> Option<PathBuf> {
+    dir().map(|d| d.join("history"))
+}
+
+/// Append a REPL command line to the history file (best effort).
+pub fn append(line: &str) {
+    let Some(path) = history_path() else { return };
+    let write = || -> io::Result<()> {
+        fs::create_dir_all(path.parent().unwrap())?;
+        let mut f = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        writeln!(f, "{line}")
+    };
+    let _ = write();
+}
+
+/// The most recent REPL commands, oldest first.
+pub fn recent() -> Vec<String> {
+    let lines: Vec<String> = history_path()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .map(|t| t.lines().map(str::to_string).collect())
+        .unwrap_or_default();
+    lines[lines.len().saturating_sub(SHOWN)..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        plan, prepare,
+        recipe::{ingredient_rows, notes},
+        repl,
+    };
+
+    #[test]
+    fn last_replays_the_same_recipe() {
+        let args = |line: &str| {
+            let tokens: Vec<String> = line.split(' ').map(String::from).collect();
+            prepare(repl::parse(&tokens).unwrap()).unwrap()
+        };
+        let first =
+            args("--w 270 --total-hours 72 --fridge-hours 60 --yeast-pct 0.2 --malt --lang en");
+        let path = std::env::temp_dir().join(format!("pizza-last-{}.json", std::process::id()));
+        profile::write(&path, &profile::Profile::from(&first)).unwrap();
+        let again = args(&format!("--profile {} --lang en", path.display()));
+        fs::remove_file(&path).unwrap();
+
+        let (a, b) = (plan(&first).unwrap(), plan(&again).unwrap());
+        assert_eq!(ingredient_rows(&a), ingredient_rows(&b));
+        assert_eq!(notes(&a), notes(&b));
+        assert!(b.ing.malt_g > 0.0);
+    }
+}
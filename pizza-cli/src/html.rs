@@ -0,0 +1,183 @@
+//! Standalone printable HTML page, laid out for an A5 kitchen printout.
+
+use crate::i18n::tr;
+use crate::recipe::{
+    Recipe, cost_rows, detroit_rows, detroit_title, fmt_effort, fmt_hhmm, fmt_room,
+    ingredient_rows, launch_rows, launches_title, mixing_rows, mixing_title, notes, nutrition_rows,
+    per_ball_rows, phase_rows, sauce_rows, sauce_title, topping_rows, toppings_title, total_hours,
+};
+use std::fmt::Write;
+
+const STYLE: &str = "\
+@page { size: A5; margin: 12mm; }
+body { font-family: Georgia, 'Times New Roman', serif; font-size: 11pt; color: #222; margin: 0 auto; max-width: 148mm; }
+h1 { font-size: 16pt; margin: 0 0 4mm; border-bottom: 2px solid #b22; padding-bottom: 2mm; }
+h2 { font-size: 12pt; margin: 6mm 0 2mm; color: #b22; }
+table { width: 100%; border-collapse: collapse; }
+th, td { text-align: left; padding: 1.5mm 2mm; border-bottom: 1px solid #ccc; }
+th { background: #f3f3f3; }
+td.num { text-align: right; font-weight: bold; white-space: nowrap; }
+ul { padding-left: 5mm; margin: 0; }
+li { margin: 1mm 0; }
+.notes { font-size: 9pt; color: #555; }
+.phase-note { font-size: 9pt; font-style: italic; color: #555; }
+@media print { body { max-width: none; } }
+";
+
+/// Escape text for HTML element content.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render the recipe as a complete HTML document.
+/// Append a titled table whose first column is a label and the rest amounts.
+fn grid(s: &mut String, title: &str, (header, rows): (Vec<String>, Vec<Vec<String>>)) {
+    let _ = write!(s, "<h2>{}</h2>\n<table>\n<tr>", escape(title));
+    for h in &header {
+        let _ = write!(s, "<th>{}</th>", escape(h));
+    }
+    s.push_str("</tr>\n");
+    for row in rows {
+        let _ = write!(s, "<tr><td>{}</td>", escape(&row[0]));
+        for cell in &row[1..] {
+            let _ = write!(s, "<td class=\"num\">{}</td>", escape(cell));
+        }
+        s.push_str("</tr>\n");
+    }
+    s.push_str("</table>\n");
+}
+
+pub fn render(r: &Recipe) -> String {
+    let lang = r.args.lang();
+    let t = |id| escape(lang.text(id));
+    let mut s = String::new();
+    let _ = writeln!(
+        s,
+        "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">",
+        lang.code()
+    );
+    let _ = writeln!(s, "<title>{}</title>\n<style>", t("recipe-title"));
+    s.push_str(STYLE);
+    let _ = writeln!(
+        s,
+        "</style>\n</head>\n<body>\n<h1>{}</h1>",
+        t("recipe-title")
+    );
+
+    let _ = writeln!(s, "<h2>{}</h2>\n<table>", t("ingredients"));
+    let _ = writeln!(
+        s,
+        "<tr><th>{}</th><th>{}</th><th>{}</th></tr>",
+        t("col-ingredient"),
+        t("col-amount"),
+        t("col-notes")
+    );
+    for [name, amount, notes] in ingredient_rows(r) {
+        let _ = writeln!(
+            s,
+            "<tr><td>{}</td><td class=\"num\">{}</td><td>{}</td></tr>",
+            escape(&name),
+            escape(&amount),
+            escape(&notes)
+        );
+    }
+    s.push_str("</table>\n");
+
+    if r.args.per_ball {
+        grid(&mut s, lang.text("per-ball"), per_ball_rows(r));
+    }
+    if r.args.nutrition {
+        grid(&mut s, lang.text("nutrition-estimate"), nutrition_rows(r));
+    }
+    if let Some(p) = &r.prices {
+        let header = ["cost", "col-amount", "col-price"]
+            .map(|id| lang.text(id).to_string())
+            .to_vec();
+        let rows = cost_rows(r, p).into_iter().map(Vec::from).collect();
+        grid(&mut s, lang.text("cost"), (header, rows));
+    }
+    if r.args.toppings.is_some() {
+        grid(&mut s, &toppings_title(r), topping_rows(r));
+    }
+    if r.args.detroit_pan().is_some() {
+        grid(&mut s, &detroit_title(r), detroit_rows(r));
+    }
+    if r.args.sauce {
+        let header = ["col-ingredient", "col-amount", "col-notes"]
+            .map(|id| lang.text(id).to_string())
+            .to_vec();
+        let rows = sauce_rows(r).into_iter().map(Vec::from).collect();
+        grid(&mut s, &sauce_title(r), (header, rows));
+    }
+    if r.args.kneading().is_some() {
+        grid(&mut s, &mixing_title(r), mixing_rows(r));
+    }
+
+    let _ = writeln!(
+        s,
+        "<h2>{}</h2>\n<table>",
+        escape(&tr!(lang, "timeline", room = fmt_room(r)))
+    );
+    let _ = writeln!(
+        s,
+        "<tr><th>{}</th><th>{}</th><th>{}</th></tr>",
+        t("col-phase"),
+        t("col-duration"),
+        t("col-ends")
+    );
+    for row in phase_rows(r) {
+        let notes: String = row
+            .notes
+            .iter()
+            .map(|n| format!("<div class=\"phase-note\">↳ {}</div>", escape(n)))
+            .collect();
+        let _ = writeln!(
+            s,
+            "<tr><td>{}{}</td><td class=\"num\">{:.1} h</td><td>{}</td></tr>",
+            escape(row.label),
+            notes,
+            row.hours,
+            row.end
+                .map(|t| format!("~{}", fmt_hhmm(t)))
+                .unwrap_or_default()
+        );
+    }
+    let _ = writeln!(
+        s,
+        "<tr><th>{}</th><th>{:.1} h</th><th></th></tr>",
+        t("total"),
+        total_hours(&r.tl)
+    );
+    let _ = writeln!(
+        s,
+        "<tr><td colspan=\"3\">{}: {}</td></tr>",
+        t("hands-on"),
+        escape(&fmt_effort(r))
+    );
+    s.push_str("</table>\n");
+
+    if r.args.launches {
+        let header = ["col-pizza", "col-in", "col-out"]
+            .map(|id| lang.text(id).to_string())
+            .to_vec();
+        let rows = launch_rows(r).into_iter().map(Vec::from).collect();
+        grid(&mut s, &launches_title(r), (header, rows));
+    }
+
+    let _ = writeln!(s, "<h2>{}</h2>\n<ul class=\"notes\">", t("notes"));
+    for note in notes(r) {
+        let _ = writeln!(s, "<li>{}</li>", escape(&note));
+    }
+    s.push_str("</ul>\n</body>\n</html>\n");
+    s
+}
@@ -0,0 +1,121 @@
+//! Translated recipe output. Messages live in `locales/<lang>.ftl`, a small
+//! subset of Fluent: `id = text` lines with `{ $name }` placeholders and `#`
+//! comments. Ids missing from a language fall back to English.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, env, fmt::Display, sync::OnceLock};
+
+/// Language of the rendered recipe.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Lang {
+    /// English
+    En,
+    /// Italiano
+    It,
+}
+
+type Catalog = HashMap<&'static str, &'static str>;
+
+fn parse(src: &'static str) -> Catalog {
+    src.lines()
+        .filter(|l| !l.starts_with('#'))
+        .filter_map(|l| l.split_once('='))
+        .map(|(id, text)| (id.trim(), text.trim()))
+        .collect()
+}
+
+impl Lang {
+    /// The language of the system locale (`LC_ALL`, `LC_MESSAGES`, `LANG`),
+    /// English when it is not one we have.
+    pub fn detect() -> Lang {
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|k| env::var(k).ok())
+            .find(|v| !v.is_empty())
+            .unwrap_or_default();
+        match locale.split(['_', '.', '@']).next() {
+            Some("it") => Lang::It,
+            _ => Lang::En,
+        }
+    }
+
+    /// ISO 639-1 code, for `<html lang>`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::It => "it",
+        }
+    }
+
+    fn catalog(self) -> &'static Catalog {
+        static EN: OnceLock<Catalog> = OnceLock::new();
+        static IT: OnceLock<Catalog> = OnceLock::new();
+        match self {
+            Lang::En => EN.get_or_init(|| parse(include_str!("../locales/en.ftl"))),
+            Lang::It => IT.get_or_init(|| parse(include_str!("../locales/it.ftl"))),
+        }
+    }
+
+    /// The message `id` as is; unknown ids come back unchanged.
+    pub fn text(self, id: &'static str) -> &'static str {
+        self.catalog()
+            .get(id)
+            .or_else(|| Lang::En.catalog().get(id))
+            .copied()
+            .unwrap_or(id)
+    }
+
+    /// The message `id` with its `{ $name }` placeholders filled from `args`.
+    pub fn format(self, id: &'static str, args: &[(&str, &dyn Display)]) -> String {
+        let mut out = String::new();
+        let mut rest = self.text(id);
+        while let Some(open) = rest.find('{') {
+            let Some(close) = rest[open..].find('}').map(|c| open + c) else {
+                break;
+            };
+            out.push_str(&rest[..open]);
+            let name = rest[open + 1..close].trim().trim_start_matches('$');
+            match args.iter().find(|(k, _)| *k == name) {
+                Some((_, v)) => out.push_str(&v.to_string()),
+                None => out.push_str(&rest[open..=close]),
+            }
+            rest = &rest[close + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// `tr!(lang, "id", name = value, ...)`: the message with its placeholders
+/// filled in.
+macro_rules! tr {
+    ($lang:expr, $id:literal $(, $name:ident = $value:expr)* $(,)?) => {
+        $lang.format($id, &[$((stringify!($name), &$value as &dyn std::fmt::Display)),*])
+    };
+}
+pub(crate) use tr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_fill_in_and_fall_back() {
+        assert_eq!(Lang::It.text("phase-proof"), "Appretto (panetti)");
+        assert_eq!(Lang::En.text("no-such-id"), "no-such-id");
+        assert_eq!(
+            tr!(Lang::It, "ball-size", weight = "250 g"),
+            "Panetto da 250 g"
+        );
+        assert_eq!(
+            tr!(Lang::En, "timeline", room = "22°C"),
+            "Timeline (room 22°C)"
+        );
+        // Every Italian id has an English original to fall back on
+        for id in Lang::It.catalog().keys() {
+            assert!(Lang::En.catalog().contains_key(id), "{id}");
+        }
+    }
+}
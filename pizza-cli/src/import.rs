@@ -0,0 +1,372 @@
+//! `import`: turn a recipe file into a profile. Understands schema.org
+//! `Recipe` JSON-LD (as embedded in recipe web pages), the result object
+//! printed by `serve`/`--fields`, and pizza-cli profiles. Amounts in kg, ml,
+//! oz, lb, cups and spoons are converted to grams; the yeast amount itself is
+//! not kept, since the model recomputes it for your temperature and time.
+
+use crate::{
+    Args, YeastFlag,
+    units::{DRY_YEAST_G_PER_TSP, FINE_SALT_G_PER_TSP, FRESH_YEAST_G_PER_TSP, GRAMS_PER_OZ},
+};
+use serde_json::Value;
+use std::path::Path;
+
+/// Values found in a recipe; anything missing comes from the command line,
+/// the config file or the built-in defaults.
+#[derive(Debug, Default, PartialEq)]
+pub struct Imported {
+    pub w: Option<u16>,
+    pub yeast: Option<YeastFlag>,
+    pub hydration: Option<f64>,
+    pub salt_per_kg: Option<f64>,
+    pub ball_weight: Option<f64>,
+    pub balls: Option<u32>,
+    pub total_hours: Option<f64>,
+    pub fridge_hours: Option<f64>,
+    pub warmup_hours: Option<f64>,
+    /// Ingredient lines that were not understood.
+    pub skipped: Vec<String>,
+}
+
+impl Imported {
+    /// Fill the flags not given on the command line, rounded for the profile.
+    pub fn apply(&self, args: &mut Args) {
+        let round = |x: f64, f: f64| (x * f).round() / f;
+        args.w = args.w.or(self.w);
+        args.yeast = args.yeast.or(self.yeast);
+        args.hydration = args.hydration.or(self.hydration.map(|h| round(h, 1000.0)));
+        args.salt_per_kg = args
+            .salt_per_kg
+            .or(self.salt_per_kg.map(|s| round(s, 10.0)));
+        if args.ball_mix.is_empty() {
+            args.ball_weight = args
+                .ball_weight
+                .or(self.ball_weight.map(|g| round(g, 10.0)));
+            args.balls = args.balls.or(self.balls);
+        }
+        args.total_hours = args.total_hours.or(self.total_hours);
+        args.fridge_hours = args.fridge_hours.or(self.fridge_hours);
+        args.warmup_hours = args.warmup_hours.or(self.warmup_hours);
+    }
+}
+
+/// What a file holds.
+pub enum Source {
+    Recipe(Imported),
+    /// A pizza-cli profile: merged like `--profile`.
+    Profile,
+}
+
+pub fn read(path: &Path) -> Result<Source, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+    let v: Value = serde_json::from_str(&text)
+        .map_err(|e| format!("Invalid JSON in {}: {e}", path.display()))?;
+    if let Some(recipe) = find_recipe(&v) {
+        return schema_recipe(recipe).map(Source::Recipe);
+    }
+    if v["flour"].is_number() && v["timeline"].is_object() {
+        return summary(&v).map(Source::Recipe);
+    }
+    if v.get("w").is_some() || v.get("extends").is_some() {
+        return Ok(Source::Profile);
+    }
+    Err(format!(
+        "{} is neither a schema.org Recipe, a pizza-cli result nor a profile",
+        path.display()
+    ))
+}
+
+fn is_recipe(v: &Value) -> bool {
+    match &v["@type"] {
+        Value::String(t) => t == "Recipe",
+        Value::Array(ts) => ts.iter().any(|t| t == "Recipe"),
+        _ => false,
+    }
+}
+
+/// The Recipe node, at the top, in a top-level array or in `@graph`.
+fn find_recipe(v: &Value) -> Option<&Value> {
+    match v {
+        Value::Array(items) => items.iter().find_map(find_recipe),
+        Value::Object(_) if is_recipe(v) => Some(v),
+        Value::Object(o) => o.get("@graph").and_then(find_recipe),
+        _ => None,
+    }
+}
+
+/// The result object of `serve`/`--fields`.
+fn summary(v: &Value) -> Result<Imported, String> {
+    let num = |v: &Value| v.as_f64();
+    let (Some(flour), Some(water)) = (num(&v["flour"]), num(&v["water"])) else {
+        return Err("The result object needs flour and water".to_string());
+    };
+    let fridge_h = num(&v["timeline"]["fridge_h"]).filter(|h| *h > 0.0);
+    Ok(Imported {
+        hydration: Some(water / flour),
+        salt_per_kg: num(&v["salt"]).map(|s| s / flour * 1000.0),
+        ball_weight: num(&v["ball_weight"]),
+        balls: v["balls"].as_u64().map(|b| b as u32),
+        total_hours: num(&v["timeline"]["total_h"]),
+        fridge_hours: fridge_h,
+        warmup_hours: fridge_h.and_then(|_| num(&v["timeline"]["warmup_h"])),
+        ..Imported::default()
+    })
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Kind {
+    Flour,
+    Water,
+    Salt,
+    Yeast(YeastFlag),
+}
+
+fn kind(name: &str) -> Option<Kind> {
+    let has = |words: &[&str]| words.iter().any(|w| name.contains(w));
+    // Yeast first: "yeast dissolved in water" is yeast.
+    if has(&["yeast", "lievito"]) {
+        return Some(Kind::Yeast(
+            match has(&["fresh", "cake", "compressed", "fresco"]) {
+                true => YeastFlag::Fresh,
+                false => YeastFlag::Dry,
+            },
+        ));
+    }
+    if has(&["flour", "farina"]) {
+        return Some(Kind::Flour);
+    }
+    if has(&["salt", "sale"]) {
+        return Some(Kind::Salt);
+    }
+    if has(&["water", "acqua"]) {
+        return Some(Kind::Water);
+    }
+    None
+}
+
+/// `500`, `1.5`, `1,5`, `1/2`, `1 1/2` or `½` at the start of `s`; returns
+/// the number and the rest.
+fn quantity(s: &str) -> Option<(f64, &str)> {
+    let mut total = 0.0;
+    let mut rest = s.trim_start();
+    let mut found = false;
+    loop {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | ',' | '/')))
+            .unwrap_or(rest.len());
+        let (word, tail) = rest.split_at(end);
+        let value = match word.split_once('/') {
+            Some((n, d)) => n
+                .parse::<f64>()
+                .ok()
+                .zip(d.parse::<f64>().ok())
+                .map(|(n, d)| n / d),
+            None => word.replace(',', ".").parse().ok(),
+        };
+        let (value, tail) = match (value, tail.chars().next()) {
+            (v, Some(c @ ('½' | '¼' | '¾'))) => {
+                let frac = match c {
+                    '½' => 0.5,
+                    '¼' => 0.25,
+                    _ => 0.75,
+                };
+                (Some(v.unwrap_or(0.0) + frac), &tail[c.len_utf8()..])
+            }
+            (v, _) => (v, tail),
+        };
+        match value {
+            Some(v) => {
+                total += v;
+                found = true;
+                rest = tail.trim_start();
+            }
+            None => break,
+        }
+    }
+    found.then_some((total, rest))
+}
+
+/// Grams per unit for an ingredient; `None` for units we cannot convert.
+fn grams_per(unit: &str, kind: Kind) -> Option<f64> {
+    let tsp = match kind {
+        Kind::Salt => FINE_SALT_G_PER_TSP,
+        Kind::Yeast(YeastFlag::Dry) => DRY_YEAST_G_PER_TSP,
+        Kind::Yeast(YeastFlag::Fresh) => FRESH_YEAST_G_PER_TSP,
+        Kind::Water => 4.93,
+        Kind::Flour => 2.6,
+    };
+    let cup = match kind {
+        Kind::Flour => 125.0,
+        _ => 48.0 * tsp,
+    };
+    Some(match unit {
+        "g" | "gr" | "gram" | "grams" | "grammi" => 1.0,
+        "kg" | "kilogram" | "kilograms" => 1000.0,
+        "mg" => 0.001,
+        // Water weighs a gram per ml; the only liquid here.
+        "ml" if kind == Kind::Water => 1.0,
+        "l" | "liter" | "liters" | "litre" | "litres" if kind == Kind::Water => 1000.0,
+        "oz" | "ounce" | "ounces" => GRAMS_PER_OZ,
+        "lb" | "lbs" | "pound" | "pounds" => 16.0 * GRAMS_PER_OZ,
+        "tsp" | "teaspoon" | "teaspoons" => tsp,
+        "tbsp" | "tablespoon" | "tablespoons" => 3.0 * tsp,
+        "cup" | "cups" => cup,
+        _ => return None,
+    })
+}
+
+/// An ingredient line like `500 g flour (W 280)` or `1 ½ tsp salt`.
+fn ingredient(line: &str) -> Option<(Kind, f64, String)> {
+    let lower = line.to_lowercase();
+    let (qty, rest) = quantity(&lower)?;
+    // "500g" and "500 g" alike.
+    let unit_end = rest
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(rest.len());
+    let (unit, name) = rest.split_at(unit_end);
+    let name = name.trim_start_matches('.').trim();
+    let kind = kind(name).or_else(|| kind(rest))?;
+    let grams = qty * grams_per(unit, kind)?;
+    Some((kind, grams, name.to_string()))
+}
+
+/// W written in a flour line, as in `00 flour W 280` or `(W320)`.
+fn flour_w(name: &str) -> Option<u16> {
+    let at = name.find('w')?;
+    let digits: String = name[at + 1..]
+        .trim_start()
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits
+        .parse()
+        .ok()
+        .filter(|w| (100..=450).contains(w))
+        .or_else(|| flour_w(&name[at + 1..]))
+}
+
+/// ISO 8601 duration in hours: `PT24H`, `PT1H30M`, `P1DT2H`.
+fn iso_hours(s: &str) -> Option<f64> {
+    let s = s.strip_prefix('P')?;
+    let (mut hours, mut num, mut time) = (0.0, String::new(), false);
+    for c in s.chars() {
+        match c {
+            '0'..='9' | '.' => num.push(c),
+            'T' => time = true,
+            'D' | 'H' | 'M' | 'S' | 'W' => {
+                let n: f64 = num.parse().ok()?;
+                num.clear();
+                hours += n * match (c, time) {
+                    ('W', false) => 168.0,
+                    ('D', false) => 24.0,
+                    ('H', true) => 1.0,
+                    ('M', true) => 1.0 / 60.0,
+                    ('S', true) => 1.0 / 3600.0,
+                    _ => return None,
+                };
+            }
+            _ => return None,
+        }
+    }
+    Some(hours)
+}
+
+/// First whole number in `recipeYield` ("4", 4, "4 pizzas", ["4", "4 pizzas"]).
+fn yield_count(v: &Value) -> Option<u32> {
+    match v {
+        Value::Number(n) => n.as_u64().map(|n| n as u32),
+        Value::String(s) => s
+            .split(|c: char| !c.is_ascii_digit())
+            .find(|w| !w.is_empty())
+            .and_then(|w| w.parse().ok()),
+        Value::Array(items) => items.iter().find_map(yield_count),
+        _ => None,
+    }
+}
+
+fn schema_recipe(r: &Value) -> Result<Imported, String> {
+    let mut imported = Imported::default();
+    let (mut flour, mut water, mut salt, mut yeast) = (0.0, 0.0, 0.0, 0.0);
+    let lines = r["recipeIngredient"]
+        .as_array()
+        .or(r["ingredients"].as_array())
+        .ok_or("The recipe has no recipeIngredient list")?;
+    for line in lines.iter().filter_map(Value::as_str) {
+        match ingredient(line) {
+            Some((Kind::Flour, g, name)) => {
+                flour += g;
+                imported.w = imported.w.or(flour_w(&name));
+            }
+            Some((Kind::Water, g, _)) => water += g,
+            Some((Kind::Salt, g, _)) => salt += g,
+            Some((Kind::Yeast(kind), g, _)) => {
+                yeast += g;
+                imported.yeast = Some(kind);
+            }
+            None => imported.skipped.push(line.to_string()),
+        }
+    }
+    if flour <= 0.0 || water <= 0.0 {
+        return Err("The recipe needs flour and water amounts in convertible units".to_string());
+    }
+    imported.hydration = Some(water / flour);
+    imported.salt_per_kg = (salt > 0.0).then(|| salt / flour * 1000.0);
+    let balls = yield_count(&r["recipeYield"]).filter(|n| *n > 0);
+    imported.balls = balls;
+    imported.ball_weight = Some((flour + water + salt + yeast) / f64::from(balls.unwrap_or(1)));
+
+    let time = |key: &str| r[key].as_str().and_then(iso_hours);
+    imported.total_hours = match (time("totalTime"), time("cookTime")) {
+        (Some(total), Some(cook)) if total > cook => Some(total - cook),
+        (total, _) => total,
+    };
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reads_schema_org_recipes() {
+        let page = json!({
+            "@context": "https://schema.org",
+            "@graph": [
+                { "@type": "WebPage" },
+                {
+                    "@type": ["Recipe"],
+                    "recipeYield": "4 pizzas",
+                    "totalTime": "P1DT15M",
+                    "cookTime": "PT15M",
+                    "recipeIngredient": [
+                        "600 g tipo 00 flour (W 280)",
+                        "0.39 l water",
+                        "1 ½ tbsp fine salt",
+                        "1/2 tsp instant dry yeast",
+                        "basil to taste"
+                    ]
+                }
+            ]
+        });
+        let Some(recipe) = find_recipe(&page) else {
+            panic!("no recipe")
+        };
+        let got = schema_recipe(recipe).unwrap();
+        assert_eq!(got.w, Some(280));
+        assert_eq!(got.yeast, Some(YeastFlag::Dry));
+        assert_eq!(got.balls, Some(4));
+        assert!((got.hydration.unwrap() - 0.65).abs() < 1e-9);
+        assert!((got.salt_per_kg.unwrap() - 45.0).abs() < 1e-9);
+        assert!((got.total_hours.unwrap() - 24.0).abs() < 1e-9);
+        assert_eq!(got.skipped, ["basil to taste"]);
+
+        assert_eq!(quantity("1 1/2 cups").map(|q| q.0), Some(1.5));
+        assert_eq!(
+            ingredient("1 lb bread flour").map(|i| i.1.round()),
+            Some(454.0)
+        );
+        assert_eq!(iso_hours("PT1H30M"), Some(1.5));
+    }
+}
@@ -0,0 +1,210 @@
+//! Bake journal: one JSON line per bake outcome in
+//! `<data dir>/pizza/journal.jsonl`, tagged like `overproofed` or
+//! `too-salty`. `suggest` turns the latest outcomes logged for a profile into
+//! parameter changes for its next bake.
+
+use crate::{history, plan, prepare, repl};
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Tags `suggest` acts on; others are kept but ignored.
+pub const TAGS: &[&str] = &["overproofed", "underproofed", "too-salty", "bland"];
+
+/// Yeast is scaled by this per net over- or underproofed bake.
+const YEAST_STEP: f64 = 0.15;
+/// Salt change in g/kg flour per too salty or bland bake.
+const SALT_STEP: f64 = 2.0;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub date: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+fn path() -> Result<PathBuf, String> {
+    history::dir()
+        .map(|d| d.join("journal.jsonl"))
+        .ok_or_else(|| "No data directory for the journal".to_string())
+}
+
+/// `Too Salty` and `too_salty` become `too-salty`.
+fn normalize(tag: &str) -> String {
+    tag.trim().to_lowercase().replace([' ', '_'], "-")
+}
+
+pub fn add(profile: Option<&Path>, tags: &[String], note: Option<String>) -> Result<(), String> {
+    let entry = Entry {
+        date: Local::now().date_naive(),
+        profile: profile.map(|p| p.display().to_string()),
+        tags: tags.iter().map(|t| normalize(t)).collect(),
+        note,
+    };
+    let path = path()?;
+    let write = || -> std::io::Result<()> {
+        fs::create_dir_all(path.parent().unwrap())?;
+        let mut f = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        writeln!(f, "{}", serde_json::to_string(&entry).unwrap())
+    };
+    write().map_err(|e| format!("Cannot write {}: {e}", path.display()))?;
+    for tag in entry.tags.iter().filter(|t| !TAGS.contains(&t.as_str())) {
+        eprintln!(
+            "Note: `suggest` ignores '{tag}' (it knows {})",
+            TAGS.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// All entries, oldest first; unreadable lines are skipped.
+pub fn entries() -> Result<Vec<Entry>, String> {
+    let path = path()?;
+    let text = fs::read_to_string(&path).unwrap_or_default();
+    Ok(text
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect())
+}
+
+pub fn list() -> Result<(), String> {
+    let entries = entries()?;
+    if entries.is_empty() {
+        println!("The journal is empty; add a bake with `journal add --tag ...`");
+    }
+    for e in entries {
+        println!(
+            "{}  {:<16} {}{}",
+            e.date,
+            e.profile.as_deref().unwrap_or("-"),
+            e.tags.join(", "),
+            e.note.map(|n| format!("  ({n})")).unwrap_or_default()
+        );
+    }
+    Ok(())
+}
+
+/// One suggested flag: name, new value and the reason.
+#[derive(Debug, PartialEq)]
+pub struct Change {
+    pub flag: &'static str,
+    pub value: f64,
+    pub reason: String,
+}
+
+/// Changes to yeast (% of flour) and salt (g/kg) for the tags in `entries`.
+pub fn changes(entries: &[&Entry], yeast_pct: f64, salt_per_kg: f64) -> Vec<Change> {
+    let count = |tag: &str| {
+        entries
+            .iter()
+            .filter(|e| e.tags.iter().any(|t| t == tag))
+            .count() as i32
+    };
+    let (over, under) = (count("overproofed"), count("underproofed"));
+    let (salty, bland) = (count("too-salty"), count("bland"));
+
+    let mut out = Vec::new();
+    let proof = under - over;
+    if proof != 0 {
+        let value = yeast_pct * (1.0 + YEAST_STEP).powi(proof);
+        out.push(Change {
+            flag: "yeast-pct",
+            value,
+            reason: format!(
+                "{} yeast ({yeast_pct:.3}% → {value:.3}%): overproofed {over}×, underproofed {under}×",
+                if proof < 0 { "less" } else { "more" }
+            ),
+        });
+    }
+    let salt = bland - salty;
+    if salt != 0 {
+        let value = salt_per_kg + SALT_STEP * f64::from(salt);
+        out.push(Change {
+            flag: "salt-per-kg",
+            value,
+            reason: format!(
+                "{} salt ({salt_per_kg} → {value} g/kg): too salty {salty}×, bland {bland}×",
+                if salt < 0 { "less" } else { "more" }
+            ),
+        });
+    }
+    out
+}
+
+/// Print the changes for the next bake with `profile`, from its `last` entries.
+pub fn suggest(profile: &Path, last: usize) -> Result<(), String> {
+    let name = profile.display().to_string();
+    let all = entries()?;
+    let mine: Vec<&Entry> = all
+        .iter()
+        .filter(|e| e.profile.as_deref() == Some(name.as_str()))
+        .collect();
+    let recent = &mine[mine.len().saturating_sub(last)..];
+    if recent.is_empty() {
+        return Err(format!(
+            "No journal entries for '{name}'; add one with `journal add --profile {name} --tag ...`"
+        ));
+    }
+
+    let tokens = ["--profile".to_string(), name.clone()];
+    let args = prepare(repl::parse(&tokens)?)?;
+    let r = plan(&args)?;
+    let yeast_pct = args
+        .yeast_pct
+        .unwrap_or(r.ing.yeast_g / r.ing.flour_g * 100.0);
+    let changes = changes(recent, yeast_pct, args.salt_per_kg());
+
+    println!("From the last {} bake(s) with '{name}':", recent.len());
+    if changes.is_empty() {
+        println!("  nothing to change");
+        return Ok(());
+    }
+    let mut cmd = format!("pizza-cli --profile {name}");
+    for c in &changes {
+        let value = match c.flag {
+            "yeast-pct" => format!("{:.3}", c.value),
+            _ => format!("{}", c.value),
+        };
+        println!("  --{} {value}  {}", c.flag, c.reason);
+        cmd.push_str(&format!(" --{} {value}", c.flag));
+    }
+    println!("Next bake: {cmd}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcomes_become_changes() {
+        let entry = |tags: &[&str]| Entry {
+            date: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+            profile: Some("neapolitan".to_string()),
+            tags: tags.iter().map(|t| normalize(t)).collect(),
+            note: None,
+        };
+        let (a, b, c) = (
+            entry(&["overproofed", "Too Salty"]),
+            entry(&["overproofed"]),
+            entry(&["underproofed", "crispy"]),
+        );
+        let got = changes(&[&a, &b, &c], 0.2, 28.0);
+        assert_eq!(got.len(), 2);
+        assert_eq!(got[0].flag, "yeast-pct");
+        assert!((got[0].value - 0.2 / 1.15).abs() < 1e-12);
+        assert_eq!(got[1].flag, "salt-per-kg");
+        assert_eq!(got[1].value, 26.0);
+        assert!(changes(&[&c, &b], 0.2, 28.0).is_empty());
+    }
+}
@@ -1,15 +1,66 @@
-use clap::{ArgGroup, Parser, ValueEnum};
-use chrono::{Local, NaiveTime, Timelike};
-use comfy_table::{presets::UTF8_FULL, Attribute, Cell, ContentArrangement, Table};
+use chrono::{Local, NaiveDate, NaiveTime, Timelike};
+use clap::{ArgGroup, CommandFactory, Parser, Subcommand, ValueEnum};
+use i18n::Lang;
 use pizza_core::{
-    compute_ingredients, effective_hours, timeline_no_fridge, timeline_with_fridge, IngredientsInput,
-    Timeline, YeastKind,
+    DEFAULT_MIN_MEASURABLE_YEAST_G, FRESH_PER_DRY, RecipeError, YeastKind,
+    ambient::{TempSchedule, equivalent_temp},
+    convert_yeast_with,
+    ddt::Mixer,
+    enriched::{self, Enrichment},
+    flours::{self, Flour, FlourType},
+    gluten_free::{self, Binder},
+    model::{Arrhenius, FermentationModel, Q10, YEAST_PERCENT_DRY_RANGE},
+    no_knead,
+    oven::Oven,
+    pans::{self, Pan},
+    pinsa::{self, FlourBlend},
+    toppings::Style,
+    viability_after_opening,
 };
+use profile::Profile;
+use recipe::fmt_g;
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
+use theme::Role;
+use units::{SaltType, Temperature, Units};
+
+mod bake;
+#[cfg(feature = "telegram")]
+mod bot;
+mod calendar;
+mod check;
+mod compare;
+mod config;
+mod fields;
+mod history;
+mod html;
+mod i18n;
+mod import;
+mod journal;
+mod mcp;
+mod pdf;
+mod profile;
+mod qr;
+mod recipe;
+mod repl;
+mod serve;
+mod share;
+mod starter;
+mod sweep;
+mod templog;
+mod text;
+mod theme;
+mod timer;
+mod tui;
+mod units;
 
 /// Yeast CLI enum mirrors pizza-core (derive for Clap).
-#[derive(Copy, Clone, Debug, ValueEnum, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 enum YeastFlag {
     Dry,
@@ -25,79 +76,979 @@ impl From<YeastFlag> for YeastKind {
     }
 }
 
-#[derive(Parser, Debug, Serialize, Deserialize)]
+/// Mixer presets mirror pizza-core (derive for Clap).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum MixerFlag {
+    /// Kneading by hand
+    #[default]
+    Hand,
+    /// Stand mixer with a dough hook
+    Planetary,
+    /// Spiral mixer
+    Spiral,
+    /// Food processor (heats the dough fast)
+    FoodProcessor,
+}
+
+impl From<MixerFlag> for Mixer {
+    fn from(m: MixerFlag) -> Self {
+        match m {
+            MixerFlag::Hand => Mixer::Hand,
+            MixerFlag::Planetary => Mixer::Planetary,
+            MixerFlag::Spiral => Mixer::Spiral,
+            MixerFlag::FoodProcessor => Mixer::FoodProcessor,
+        }
+    }
+}
+
+/// Built-in fermentation models of pizza-core (derive for Clap).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ModelFlag {
+    /// Activity doubles every 10 °C
+    #[default]
+    Q10,
+    /// Arrhenius law (Ea 60 kJ/mol); slower in the fridge
+    Arrhenius,
+}
+
+/// Oven presets mirror pizza-core (derive for Clap).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum OvenFlag {
+    /// Home oven with a baking stone (60 min)
+    #[default]
+    HomeStone,
+    /// Home oven with a baking steel (45 min)
+    HomeSteel,
+    /// Electric countertop pizza oven (30 min)
+    Electric,
+    /// Portable gas pizza oven (25 min)
+    Gas,
+    /// Wood-fired oven (90 min)
+    Wood,
+}
+
+impl From<OvenFlag> for Oven {
+    fn from(o: OvenFlag) -> Self {
+        match o {
+            OvenFlag::HomeStone => Oven::HomeStone,
+            OvenFlag::HomeSteel => Oven::HomeSteel,
+            OvenFlag::Electric => Oven::Electric,
+            OvenFlag::Gas => Oven::Gas,
+            OvenFlag::Wood => Oven::Wood,
+        }
+    }
+}
+
+/// Flour types mirror pizza-core (derive for Clap).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FlourTypeFlag {
+    /// Italian soft wheat (tipo 00 and 0)
+    Soft,
+    /// Bread and all-purpose flours (US, UK, Canada)
+    #[default]
+    Hard,
+}
+
+impl From<FlourTypeFlag> for FlourType {
+    fn from(t: FlourTypeFlag) -> Self {
+        match t {
+            FlourTypeFlag::Soft => FlourType::Soft,
+            FlourTypeFlag::Hard => FlourType::Hard,
+        }
+    }
+}
+
+/// Dough styles for `--style` (derive for Clap).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum DoughFlag {
+    /// Wheat flour, water, salt and yeast
+    #[default]
+    Classic,
+    /// Pan pizza or focaccia: 4% oil and 2% sugar unless given, proofed in the pan
+    Pan,
+    /// New York: 62% hydration, 3% oil, 2% sugar, 48 h mostly in the fridge, for a home oven
+    NewYork,
+    /// Pinsa romana: wheat, rice and soy flour, 80% hydration, 72 h cold ferment, oval shaping
+    Pinsa,
+    /// Detroit pan pizza: dough for the --pan size, brick cheese to the edges
+    Detroit,
+    /// Gluten-free blend and a binder: more water, no kneading or bulk rise
+    GlutenFree,
+}
+
+/// Ready-made plans for `--preset` (derive for Clap).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum PresetFlag {
+    /// Same-day dough in 3–4 h: the most yeast the estimates allow and warm water
+    Emergency,
+}
+
+/// Cold-ferment schedules for `--schedule` (derive for Clap).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+enum ScheduleFlag {
+    /// 24 h: 18 in the fridge, 2 h warmup
+    #[value(name = "24h-cold")]
+    #[serde(rename = "24h-cold")]
+    Cold24,
+    /// 48 h: 42 in the fridge, 2 h warmup
+    #[value(name = "48h-cold")]
+    #[serde(rename = "48h-cold")]
+    Cold48,
+    /// 72 h: 66 in the fridge, 3 h warmup
+    #[value(name = "72h-cold")]
+    #[serde(rename = "72h-cold")]
+    Cold72,
+}
+
+impl ScheduleFlag {
+    /// Total, fridge and warmup hours.
+    fn hours(self) -> (f64, f64, f64) {
+        match self {
+            ScheduleFlag::Cold24 => (24.0, 18.0, 2.0),
+            ScheduleFlag::Cold48 => (48.0, 42.0, 2.0),
+            ScheduleFlag::Cold72 => (72.0, 66.0, 3.0),
+        }
+    }
+
+    /// Weakest flour that holds up for the whole schedule.
+    fn min_w(self) -> u16 {
+        flours::min_w_for_hours(self.hours().0)
+    }
+}
+
+/// Total hours of a `--preset emergency` dough, and the default.
+const EMERGENCY_HOURS: RangeInclusive<f64> = 3.0..=4.0;
+const EMERGENCY_DEFAULT_HOURS: f64 = 3.5;
+
+/// Dough temperature a `--preset emergency` dough is mixed to (°C), so the
+/// yeast gets going at once.
+const EMERGENCY_DDT_C: f64 = 30.0;
+
+/// Hours a `--style new-york` or `pinsa` dough spends out of the fridge by
+/// default: bulk, warmup and proof.
+const COLD_FERMENT_ROOM_HOURS: f64 = 6.0;
+
+/// Parse `--blend`: parts of wheat, rice and soy flour, e.g. `80,15,5`.
+fn parse_blend(s: &str) -> Result<FlourBlend, String> {
+    let bad = || format!("expected WHEAT,RICE,SOY parts (e.g. 80,15,5), got '{s}'");
+    let parts: Vec<f64> = s
+        .split(',')
+        .map(|p| p.trim().trim_end_matches('%').parse().map_err(|_| bad()))
+        .collect::<Result<_, _>>()?;
+    let [wheat, rice, soy] = parts[..] else {
+        return Err(bad());
+    };
+    FlourBlend::from_parts(wheat, rice, soy)
+        .ok_or_else(|| format!("the blend needs some wheat and no negative parts, got '{s}'"))
+}
+
+/// Parse `--pan`: `WIDTHxLENGTH` in inches, or in centimetres with a `cm`
+/// suffix (`25x35cm`).
+fn parse_pan(s: &str) -> Result<Pan, String> {
+    let bad =
+        || format!("expected WIDTHxLENGTH in inches or cm (e.g. 10x14 or 25x35cm), got '{s}'");
+    let t = s.trim().to_ascii_lowercase();
+    let (dims, cm) = match t.strip_suffix("cm") {
+        Some(d) => (d, true),
+        None => (t.strip_suffix("in").unwrap_or(&t), false),
+    };
+    let (w, l) = dims.split_once(['x', '×']).ok_or_else(bad)?;
+    let w: f64 = w.trim().parse().map_err(|_| bad())?;
+    let l: f64 = l.trim().parse().map_err(|_| bad())?;
+    if !(w > 0.0 && l > 0.0) {
+        return Err(format!("pan sides must be positive, got '{s}'"));
+    }
+    Ok(match cm {
+        true => Pan::from_cm(w, l),
+        false => Pan {
+            width_in: w,
+            length_in: l,
+        },
+    })
+}
+
+/// Parse a duration such as `3h`, `90m`, `1h30m` or `2.5` (hours).
+fn parse_duration(s: &str) -> Result<chrono::Duration, String> {
+    let bad = || format!("expected a duration like 3h, 90m or 1h30m, got '{s}'");
+    let t = s.trim().to_ascii_lowercase();
+    let (h, m) = match t.split_once('h') {
+        Some((h, m)) => (h, m.trim_end_matches("in").trim_end_matches('m')),
+        None => match t.strip_suffix("min").or_else(|| t.strip_suffix('m')) {
+            Some(m) => ("", m),
+            None => (t.as_str(), ""),
+        },
+    };
+    let num = |x: &str| match x.trim() {
+        "" => Ok(0.0),
+        x => x.parse::<f64>().map_err(|_| bad()),
+    };
+    let minutes = num(h)? * 60.0 + num(m)?;
+    if !(minutes >= 0.0 && minutes.is_finite()) || t.is_empty() {
+        return Err(bad());
+    }
+    Ok(chrono::Duration::minutes(minutes.round() as i64))
+}
+
+/// Gluten-free binders mirror pizza-core (derive for Clap).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BinderFlag {
+    /// Psyllium husk powder, 4% of the blend; shapes almost like wheat dough
+    #[default]
+    Psyllium,
+    /// Xanthan gum, 1% of the blend; softer and stickier
+    Xanthan,
+}
+
+impl From<BinderFlag> for Binder {
+    fn from(b: BinderFlag) -> Self {
+        match b {
+            BinderFlag::Psyllium => Binder::Psyllium,
+            BinderFlag::Xanthan => Binder::Xanthan,
+        }
+    }
+}
+
+/// Pizza styles for `--toppings` (derive for Clap).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum StyleFlag {
+    /// Soft with a puffy rim; fior di latte
+    #[default]
+    Neapolitan,
+    /// Thin and crisp (tonda romana); fior di latte
+    Roman,
+    /// Large and foldable; low-moisture mozzarella
+    NewYork,
+}
+
+impl From<StyleFlag> for Style {
+    fn from(s: StyleFlag) -> Self {
+        match s {
+            StyleFlag::Neapolitan => Style::Neapolitan,
+            StyleFlag::Roman => Style::Roman,
+            StyleFlag::NewYork => Style::NewYork,
+        }
+    }
+}
+
+impl ModelFlag {
+    fn model(self) -> Arc<dyn FermentationModel> {
+        match self {
+            ModelFlag::Q10 => Arc::new(Q10::default()),
+            ModelFlag::Arrhenius => Arc::new(Arrhenius::default()),
+        }
+    }
+
+    /// The model, wrapped for a no-knead dough's yeast.
+    fn model_for(self, no_knead: bool) -> Arc<dyn FermentationModel> {
+        match no_knead {
+            true => Arc::new(no_knead::NoKnead(self.model())),
+            false => self.model(),
+        }
+    }
+}
+
+/// Output format for the recipe.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OutputFormat {
+    /// Terminal tables
+    #[default]
+    Table,
+    /// Standalone printable HTML page (A5)
+    Html,
+}
+
+/// Dough phases that notes can be attached to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PhaseKind {
+    Bulk,
+    Fridge,
+    Warmup,
+    Proof,
+}
+
+/// Free-text note or reminder attached to a phase (`--note warmup="buy basil"`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct PhaseNote {
+    phase: PhaseKind,
+    text: String,
+}
+
+impl FromStr for PhaseNote {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (phase, text) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected PHASE=TEXT, got '{s}'"))?;
+        let phase = PhaseKind::from_str(phase.trim(), true)?;
+        let text = text.trim();
+        if text.is_empty() {
+            return Err("note text must not be empty".to_string());
+        }
+        Ok(PhaseNote {
+            phase,
+            text: text.to_string(),
+        })
+    }
+}
+
+/// Balls of one size in a batch (`--ball 280x2`; the count defaults to 1).
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct BallSpec {
+    weight: f64,
+    count: u32,
+}
+
+impl FromStr for BallSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (weight, count) = s.split_once(['x', 'X', '×']).unwrap_or((s, "1"));
+        let weight: f64 = weight
+            .trim()
+            .trim_end_matches('g')
+            .parse()
+            .map_err(|_| format!("expected GRAMSxCOUNT (e.g. 280x2), got '{s}'"))?;
+        let count: u32 = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("expected GRAMSxCOUNT (e.g. 280x2), got '{s}'"))?;
+        if weight <= 0.0 || count == 0 {
+            return Err(format!("ball weight and count must be positive, got '{s}'"));
+        }
+        Ok(BallSpec { weight, count })
+    }
+}
+
+#[derive(Parser, Debug)]
 #[command(
-    name="pizza-cli",
-    about="Calculate ingredients & timeline for Neapolitan pizza (direct dough).",
-    version
+    name = "pizza-cli",
+    about = "Calculate ingredients & timeline for Neapolitan pizza (direct dough).",
+    version,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
 )]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Recipe flags for the default `calc` behavior (absent when a subcommand is used).
+    #[command(flatten)]
+    args: Option<Args>,
+
+    /// Colours for amounts, warnings and the next action [default: dark on a terminal, else plain]
+    #[arg(long, global = true, env = "PIZZA_THEME", value_enum)]
+    theme: Option<theme::Theme>,
+
+    /// Plain output without colours (also with the NO_COLOR variable set)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// ASCII tables and symbols for terminals without UTF-8 [default: from the locale]
+    #[arg(long, global = true)]
+    ascii: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Ingredients and timeline (default when no subcommand is given)
+    Calc(Args),
+    /// Timeline only
+    Timeline(Args),
+    /// Save or inspect profiles
+    #[command(subcommand)]
+    Profile(ProfileCommand),
+    /// Unit conversions
+    #[command(subcommand)]
+    Convert(ConvertCommand),
+    /// List the built-in flours usable with --flour-name
+    Flours,
+    /// Sourdough starter helpers
+    #[command(subcommand)]
+    Starter(StarterCommand),
+    /// Live dashboard: ingredients, timeline and countdown to the next action
+    Tui(Args),
+    /// Countdown to the next action in the terminal, ringing the bell when each phase ends
+    Timer(Args),
+    /// Begin tracking a bake: save its parameters and planned steps (mixing now or at --start)
+    Start(Args),
+    /// The bake in progress: current phase, time into it and the next step
+    Status,
+    /// Replan the bake in progress for a kitchen warmer or cooler than planned
+    Adjust {
+        /// Kitchen temperature the dough has been at (°C, or e.g. 82F)
+        #[arg(
+            long,
+            allow_negative_numbers = true,
+            required_unless_present = "temp_log"
+        )]
+        temp: Option<Temperature>,
+        /// How long it has been at --temp (e.g. 3h, 90m); defaults to since mixing
+        #[arg(long, value_parser = parse_duration)]
+        elapsed: Option<chrono::Duration>,
+        /// Kitchen temperatures recorded since mixing (CSV `time,temp` or JSON), in place of --temp
+        #[arg(long, value_name = "FILE", conflicts_with = "temp")]
+        temp_log: Option<PathBuf>,
+    },
+    /// The next step of the bake in progress
+    Next {
+        /// The next step is done now; later steps move by how late (or early) it was
+        #[arg(long)]
+        done: bool,
+        /// Running late: move the remaining steps this many minutes later
+        #[arg(long, value_name = "MINUTES", allow_negative_numbers = true)]
+        late: Option<i64>,
+    },
+    /// Save a recipe file (schema.org Recipe JSON-LD, a pizza-cli result or profile) as a profile
+    Import {
+        /// JSON or JSON-LD file to read
+        file: PathBuf,
+        /// Profile name (stored in the config dir) or JSON path to write
+        path: PathBuf,
+        /// Flags given here override the file (e.g. --w when the recipe does not say)
+        #[command(flatten)]
+        args: Box<Args>,
+    },
+    /// Print the effective parameters as a share string for chat messages
+    Share(Args),
+    /// Show the recipe from a share string; flags given here override it
+    Open {
+        /// String printed by `share`
+        code: String,
+        #[command(flatten)]
+        args: Box<Args>,
+    },
+    /// Log how a bake turned out
+    #[command(subcommand)]
+    Journal(JournalCommand),
+    /// Parameter changes for a profile's next bake, from its journal entries
+    Suggest {
+        /// Profile the bakes were made with, as given to `journal add --profile`
+        #[arg(long, env = "PIZZA_PROFILE")]
+        profile: PathBuf,
+        /// How many of the most recent entries to consider
+        #[arg(long, default_value_t = 3)]
+        last: usize,
+    },
+    /// Re-run the most recent calculation, with optional overrides (`last --balls 8`)
+    Last(Args),
+    /// Scale a recipe (e.g. a profile) to a bigger or smaller batch; yeast is recomputed
+    Scale {
+        /// Multiply the dough by this factor (a whole number of balls scales the count, otherwise the weight)
+        #[arg(long, required_unless_present = "balls")]
+        factor: Option<f64>,
+        #[command(flatten)]
+        args: Box<Args>,
+    },
+    /// Two profiles side by side: ingredients and timeline, with the differences
+    Compare {
+        /// First profile (name or JSON path)
+        first: PathBuf,
+        /// Second profile (name or JSON path)
+        second: PathBuf,
+        /// Recipe flags for both, e.g. `--lang it` or `--start 18:00`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        flags: Vec<String>,
+    },
+    /// Compare the recipe over a range of hydration, temperature (°C) or total hours
+    Sweep {
+        /// What to vary and how, e.g. `hydration=0.60..0.80:0.05`, `temp=18..30:2` or `hours=8..24:4`
+        #[arg(long, value_name = "PARAM=FROM..TO:STEP")]
+        vary: sweep::Vary,
+        #[command(flatten)]
+        args: Box<Args>,
+    },
+    /// Interactive session: tweak parameters and re-show the recipe
+    Repl {
+        /// Initial recipe flags, as for `calc`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// HTTP API: GET /ingredients and /timeline with recipe flags as query parameters
+    Serve {
+        /// Address to listen on
+        #[arg(long, env = "PIZZA_LISTEN", default_value = "127.0.0.1:8080")]
+        listen: String,
+    },
+    /// MCP server on stdin/stdout: the calculator as tools for assistants and editors
+    Mcp,
+    /// Telegram bot: `/pizza 4 balls ready at 20:00` replies with the recipe and phase reminders
+    #[cfg(feature = "telegram")]
+    Bot {
+        /// Bot token from @BotFather
+        #[arg(long, env = "PIZZA_TELEGRAM_TOKEN", hide_env_values = true)]
+        telegram_token: String,
+        /// Default recipe flags for every message, as for `calc`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ProfileCommand {
+    /// Save the effective parameters (after merging --profile) to a profile
+    Save {
+        /// Profile name (stored in the config dir) or JSON path
+        path: PathBuf,
+        #[command(flatten)]
+        args: Box<Args>,
+    },
+    /// Print the parameters stored in a profile
+    Show {
+        /// Profile name or JSON path
+        path: PathBuf,
+    },
+    /// List the named profiles in the config dir
+    List,
+    /// Delete a profile
+    Delete {
+        /// Profile name or JSON path
+        path: PathBuf,
+    },
+    /// Rename a profile
+    Rename {
+        /// Current profile name or JSON path
+        from: PathBuf,
+        /// New profile name or JSON path
+        to: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum JournalCommand {
+    /// Add an entry for today's bake
+    Add {
+        /// Outcome, repeatable: overproofed, underproofed, too-salty, bland (others are kept as notes)
+        #[arg(long = "tag", required = true)]
+        tags: Vec<String>,
+        /// Profile the bake was made with
+        #[arg(long, env = "PIZZA_PROFILE")]
+        profile: Option<PathBuf>,
+        /// Free-form note
+        #[arg(long)]
+        note: Option<String>,
+    },
+    /// Show all entries
+    List,
+}
+
+#[derive(Subcommand, Debug)]
+enum StarterCommand {
+    /// Feed amounts, expected peak time and whether it is ready for the mix
+    Feed {
+        /// Starter to feed, in grams
+        grams: f64,
+        /// Feeding ratio starter:flour:water
+        #[arg(long, default_value = "1:1:1")]
+        ratio: starter::FeedRatio,
+        /// Kitchen temperature (°C, or e.g. 75F)
+        #[arg(
+            long,
+            env = "PIZZA_TEMP",
+            default_value = "25",
+            allow_negative_numbers = true
+        )]
+        temp: Temperature,
+        /// Feeding time HH:MM; defaults to now
+        #[arg(long)]
+        at: Option<String>,
+        /// Planned mix time HH:MM
+        #[arg(long)]
+        mix: Option<String>,
+    },
+    /// One- or two-stage levain build (amounts and times) ending at the mix
+    Build {
+        /// Levain needed for the batch, in grams
+        grams: f64,
+        /// Seed starter you have, in grams
+        #[arg(long)]
+        seed: f64,
+        /// Kitchen temperature (°C, or e.g. 75F)
+        #[arg(
+            long,
+            env = "PIZZA_TEMP",
+            default_value = "25",
+            allow_negative_numbers = true
+        )]
+        temp: Temperature,
+        /// Mix time HH:MM the build should peak at; defaults to starting now
+        #[arg(long)]
+        mix: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ConvertCommand {
+    /// Convert a yeast amount between dry and fresh (same leavening power)
+    Yeast {
+        /// Amount in grams
+        grams: f64,
+        /// Yeast type of the given amount
+        #[arg(long, value_enum)]
+        from: YeastFlag,
+        /// Yeast type to convert to
+        #[arg(long, value_enum)]
+        to: YeastFlag,
+        /// Grams of fresh yeast per gram of dry
+        #[arg(long, env = "PIZZA_FRESH_FACTOR", default_value_t = FRESH_PER_DRY)]
+        fresh_factor: f64,
+    },
+}
+
+#[derive(clap::Args, Debug, Serialize, Deserialize)]
 #[command(group(
     ArgGroup::new("time_group")
         .args(["total_hours"])
         .required(false)
 ))]
 struct Args {
-    /// Flour strength W (e.g., 260–300)
-    #[arg(long, value_parser = clap::value_parser!(u16).range(200..=450))]
-    w: u16,
+    /// Flour strength W (e.g., 260–300); required unless a profile sets it
+    #[arg(long, env = "PIZZA_W", value_parser = clap::value_parser!(u16).range(200..=450))]
+    w: Option<u16>,
 
-    /// Ambient temperature in °C
-    #[arg(long, default_value_t = 25.0)]
-    temp: f64,
+    /// Flour from the built-in list (see `flours`), e.g. "caputo pizzeria"; sets W unless --w is given
+    #[arg(long, env = "PIZZA_FLOUR")]
+    flour_name: Option<String>,
 
-    /// Yeast type
-    #[arg(long, value_enum, default_value_t = YeastFlag::Dry)]
-    yeast: YeastFlag,
+    /// Protein % printed on the flour bag, to estimate W instead of giving --w
+    #[arg(long, env = "PIZZA_PROTEIN", value_name = "PCT", conflicts_with = "w")]
+    protein: Option<f64>,
 
-    /// Target hydration (0.55..0.85)
-    #[arg(long, default_value_t = 0.75)]
-    hydration: f64,
+    /// Wheat of the --protein flour [default: hard]
+    #[arg(long, env = "PIZZA_FLOUR_TYPE", value_enum, requires = "protein")]
+    flour_type: Option<FlourTypeFlag>,
 
-    /// Salt in g/kg flour
-    #[arg(long, default_value_t = 20.0)]
-    salt_per_kg: f64,
+    /// Dough style; gluten-free needs no --w and defaults to 80% hydration and 3 h, new-york and pinsa to a cold ferment [default: classic]
+    #[arg(long, env = "PIZZA_STYLE", value_enum)]
+    style: Option<DoughFlag>,
 
-    /// Dough ball weight in grams
-    #[arg(long, default_value_t = 280.0)]
-    ball_weight: f64,
+    /// What holds a --style gluten-free dough together [default: psyllium]
+    #[arg(long, env = "PIZZA_BINDER", value_enum)]
+    binder: Option<BinderFlag>,
 
-    /// Number of balls
-    #[arg(long, default_value_t = 2)]
-    balls: u32,
+    /// Put a dough planned too long for room temperature in the fridge: all but 6 h of --total-hours, then --warmup-hours
+    #[arg(long, env = "PIZZA_AUTO_FRIDGE")]
+    auto_fridge: bool,
 
-    /// Total process hours (mix → bake)
-    #[arg(long, default_value_t = 11.0)]
-    total_hours: f64,
+    /// Cold-ferment schedule: sets total, fridge and warmup hours and the weakest W that lasts; flags given as well still win
+    #[arg(long, value_enum, conflicts_with_all = ["preset", "no_knead"])]
+    schedule: Option<ScheduleFlag>,
 
-    /// Fridge time in hours (0 = no fridge mode)
-    #[arg(long, default_value_t = 0.0)]
-    fridge_hours: f64,
+    /// Ready-made plan; flags given as well still win
+    #[arg(long, value_enum, conflicts_with = "no_knead")]
+    preset: Option<PresetFlag>,
 
-    /// Warmup time after fridge (bench rest) in hours
-    #[arg(long, default_value_t = 3.0)]
-    warmup_hours: f64,
+    /// No-knead: stir, fold twice and let a long bulk rise at room temperature develop the gluten (18–24 h, default 18)
+    #[arg(long, conflicts_with = "mixer")]
+    no_knead: bool,
 
-    /// Fridge factor (activity speed vs room), default 0.25
-    #[arg(long, default_value_t = 0.25)]
-    fridge_factor: f64,
+    /// Detroit pan, inside size: inches, or cm with a suffix (25x35cm); sets the dough per pan [default: 10x14]
+    #[arg(long, value_name = "WxL", value_parser = parse_pan, conflicts_with_all = ["ball_weight", "ball_mix"])]
+    pan: Option<Pan>,
+
+    /// Pinsa flour blend as parts of wheat, rice and soy flour [default: 80,15,5]
+    #[arg(long, value_name = "WHEAT,RICE,SOY", value_parser = parse_blend)]
+    blend: Option<FlourBlend>,
+
+    /// Share of the water to give as whole milk, in percent [default: 0]
+    #[arg(long, env = "PIZZA_MILK", value_name = "PCT")]
+    milk: Option<f64>,
+
+    /// Oil in the dough, percent of flour [default: 4 with --style pan, else 0]
+    #[arg(long, env = "PIZZA_OIL", value_name = "PCT")]
+    oil: Option<f64>,
+
+    /// Sugar in the dough, percent of flour [default: 2 with --style pan, else 0]
+    #[arg(long, env = "PIZZA_SUGAR", value_name = "PCT")]
+    sugar: Option<f64>,
+
+    /// Ambient temperature, °C unless suffixed with F (e.g. 77F) [default: 25]
+    #[arg(long, env = "PIZZA_TEMP", allow_negative_numbers = true)]
+    temp: Option<Temperature>,
+
+    /// Ambient temperature in °F (same as --temp <F>F)
+    #[arg(long, env = "PIZZA_TEMP_F", conflicts_with = "temp", value_name = "F")]
+    temp_f: Option<f64>,
+
+    /// Room temperature over the day in °C, e.g. `18:00=26,23:00=20,07:00=19`; replaces --temp
+    #[arg(long, value_name = "HH:MM=C,...", conflicts_with_all = ["temp", "temp_f"])]
+    temp_schedule: Option<TempSchedule>,
+
+    /// Kitchen temperatures recorded over time (CSV `time,temp` or JSON); replaces --temp
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["temp", "temp_f", "temp_schedule"])]
+    temp_log: Option<PathBuf>,
+
+    /// Yeast type [default: dry]
+    #[arg(long, env = "PIZZA_YEAST", value_enum)]
+    yeast: Option<YeastFlag>,
+
+    /// Target hydration, 0.55..0.85 or 55..85 (percent) [default: 0.75]
+    #[arg(long, env = "PIZZA_HYDRATION")]
+    hydration: Option<f64>,
+
+    /// Accept a hydration outside the usual range (e.g. a 90% pinsa) with a warning instead of an error
+    #[arg(long, env = "PIZZA_EXPERT")]
+    expert: bool,
+
+    /// Salt in g/kg flour [default: 20]
+    #[arg(long, env = "PIZZA_SALT_PER_KG")]
+    salt_per_kg: Option<f64>,
+
+    /// Salt in baker's percent (same as --salt-per-kg ×10), e.g. 2.8
+    #[arg(
+        long,
+        env = "PIZZA_SALT_PCT",
+        conflicts_with = "salt_per_kg",
+        value_name = "PCT"
+    )]
+    salt_pct: Option<f64>,
+
+    /// Dough ball weight in grams [default: 280]
+    #[arg(long, env = "PIZZA_BALL_WEIGHT")]
+    ball_weight: Option<f64>,
+
+    /// Number of balls [default: 2]
+    #[arg(long, env = "PIZZA_BALLS")]
+    balls: Option<u32>,
+
+    /// Balls of mixed sizes from one dough, e.g. `--ball 280x2 --ball 450x1` (repeatable)
+    #[arg(long = "ball", value_name = "GRAMSxCOUNT", conflicts_with_all = ["balls", "ball_weight"])]
+    ball_mix: Vec<BallSpec>,
+
+    /// Total process hours (mix → bake) [default: 11]
+    #[arg(long, env = "PIZZA_TOTAL_HOURS")]
+    total_hours: Option<f64>,
+
+    /// Relative humidity of the kitchen in percent; adds hydration advice for dry or humid air
+    #[arg(long, env = "PIZZA_HUMIDITY", value_name = "PCT")]
+    humidity: Option<f64>,
+
+    /// Fridge time in hours (0 = no fridge mode) [default: 0]
+    #[arg(long, env = "PIZZA_FRIDGE_HOURS")]
+    fridge_hours: Option<f64>,
+
+    /// Warmup time after fridge (bench rest) in hours [default: 3]
+    #[arg(long, env = "PIZZA_WARMUP_HOURS")]
+    warmup_hours: Option<f64>,
+
+    /// Fridge factor (activity speed vs room) [default: 0.25]
+    #[arg(long, env = "PIZZA_FRIDGE_FACTOR")]
+    fridge_factor: Option<f64>,
+
+    /// Temperature model for yeast activity [default: q10]
+    #[arg(long, env = "PIZZA_MODEL", value_enum)]
+    model: Option<ModelFlag>,
+
+    /// Fridge temperature; sets the fridge factor from the same temperature model as the room
+    #[arg(
+        long,
+        env = "PIZZA_FRIDGE_TEMP",
+        conflicts_with = "fridge_factor",
+        allow_negative_numbers = true
+    )]
+    fridge_temp: Option<Temperature>,
 
     /// Start time HH:MM (optional); defaults to now
-    #[arg(long)]
+    #[arg(long, env = "PIZZA_START")]
     start: Option<String>,
 
-    /// Load a profile JSON before applying CLI overrides
-    #[arg(long)]
+    /// Load a profile (name in the config dir, or path to a JSON file) before applying CLI overrides
+    #[arg(long, env = "PIZZA_PROFILE")]
     profile: Option<PathBuf>,
 
-    /// Save the current effective parameters to a profile JSON
+    /// Save the current effective parameters to a profile (name or JSON path)
     #[arg(long)]
     save_profile: Option<PathBuf>,
+
+    /// Output format (table or printable html)
+    #[arg(long, env = "PIZZA_OUTPUT", value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
+    /// Smallest yeast amount in grams your scale weighs reliably; below it a dilution is suggested
+    #[arg(long, env = "PIZZA_MIN_YEAST", value_name = "G", default_value_t = DEFAULT_MIN_MEASURABLE_YEAST_G)]
+    min_yeast: f64,
+
+    /// Grams of fresh yeast per gram of dry (brands vary, 2.5–3.5) [default: 3]
+    #[arg(long, env = "PIZZA_FRESH_FACTOR")]
+    fresh_factor: Option<f64>,
+
+    /// Yeast in percent of flour (of the chosen kind), e.g. 0.2; skips the estimate
+    #[arg(long, env = "PIZZA_YEAST_PCT", value_name = "PCT")]
+    yeast_pct: Option<f64>,
+
+    /// Share of the yeast still active, e.g. 0.7 for an old jar; scales the yeast up [default: 1]
+    #[arg(long, env = "PIZZA_YEAST_VIABILITY", value_name = "FACTOR")]
+    yeast_viability: Option<f64>,
+
+    /// Date the yeast was opened (YYYY-MM-DD); viability is estimated from its age
+    #[arg(long, value_name = "DATE", conflicts_with = "yeast_viability")]
+    yeast_opened: Option<NaiveDate>,
+
+    /// Also print flour/water/salt/yeast for a single ball
+    #[arg(long, env = "PIZZA_PER_BALL")]
+    per_ball: bool,
+
+    /// Also print estimated kcal, carbs, protein, fat and salt per ball and per 100 g
+    #[arg(long, env = "PIZZA_NUTRITION")]
+    nutrition: bool,
+
+    /// Also print tomato sauce, mozzarella and basil per pizza for a style [default: neapolitan]
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STYLE",
+        num_args = 0..=1,
+        default_missing_value = "neapolitan"
+    )]
+    toppings: Option<StyleFlag>,
+
+    /// Also print a San Marzano sauce (tomatoes, salt, basil) for all the pizzas
+    #[arg(long)]
+    sauce: bool,
+
+    /// Add extra virgin olive oil to the --sauce
+    #[arg(long, requires = "sauce")]
+    sauce_oil: bool,
+
+    /// Pizza diameter in cm for --toppings [default: from the ball weight and style]
+    #[arg(
+        long,
+        value_name = "CM",
+        requires = "toppings",
+        conflicts_with = "ball_mix"
+    )]
+    diameter: Option<f64>,
+
+    /// Also print ingredient cost per batch and per ball (prices from config.toml `[prices]`)
+    #[arg(long, env = "PIZZA_COST")]
+    cost: bool,
+
+    /// Only validate the inputs and print warnings, not the recipe; exits with 0, 1 when invalid or 3 with warnings
+    #[arg(long, conflicts_with_all = ["save_profile", "export", "qr_png"])]
+    check: bool,
+
+    /// Also print how the yeast and flour were worked out, with your numbers
+    #[arg(long)]
+    explain: bool,
+
+    /// Also print a QR code of the plan (ingredients, times and flags) to scan with a phone
+    #[arg(long)]
+    qr: bool,
+
+    /// Also write the QR code to a PNG file
+    #[arg(long, value_name = "FILE")]
+    qr_png: Option<PathBuf>,
+
+    /// Also write the printable card to a file: `pdf` or `html`
+    #[arg(long, num_args = 2, value_names = ["FORMAT", "FILE"])]
+    export: Vec<String>,
+
+    /// Your oven; adds a reminder to start preheating in time for the bake
+    #[arg(long, env = "PIZZA_OVEN", value_enum)]
+    oven: Option<OvenFlag>,
+
+    /// Preheat time in minutes; overrides the --oven preset
+    #[arg(long, env = "PIZZA_PREHEAT", value_name = "MINUTES")]
+    preheat: Option<u32>,
+
+    /// Also print when each pizza goes into the oven, one at a time after the proof
+    #[arg(long)]
+    launches: bool,
+
+    /// Minutes each pizza bakes, for --launches [default: from --oven]
+    #[arg(long, value_name = "MINUTES")]
+    bake_minutes: Option<f64>,
+
+    /// Minutes the oven needs to recover between pizzas, for --launches [default: from --oven]
+    #[arg(long, value_name = "MINUTES")]
+    recovery_minutes: Option<f64>,
+
+    /// Desired dough temperature after mixing; adds a water temperature row [default: 24]
+    #[arg(long, env = "PIZZA_DDT", allow_negative_numbers = true)]
+    ddt: Option<Temperature>,
+
+    /// Flour temperature for the water temperature [default: room]
+    #[arg(long, env = "PIZZA_FLOUR_TEMP", allow_negative_numbers = true)]
+    flour_temp: Option<Temperature>,
+
+    /// How you knead; sets the friction factor for the water temperature and prints a mixing plan [default: hand]
+    #[arg(long, env = "PIZZA_MIXER", value_enum)]
+    mixer: Option<MixerFlag>,
+
+    /// Friction factor in °C (3 × the temperature rise from mixing); overrides --mixer
+    #[arg(long, env = "PIZZA_FRICTION", value_name = "C")]
+    friction: Option<f64>,
+
+    /// Add diastatic malt for 48 h+ cold ferments (browning aid), included in the dough weight
+    #[arg(long, env = "PIZZA_MALT")]
+    malt: bool,
+
+    /// Salt you use: sets the teaspoon equivalent and adds dissolving tips [default: fine]
+    #[arg(long, env = "PIZZA_SALT_TYPE", value_enum)]
+    salt_type: Option<SaltType>,
+
+    /// Add the salt this many minutes into mixing instead of at the start (e.g. after an autolyse)
+    #[arg(long, value_name = "MINUTES")]
+    salt_after: Option<u32>,
+
+    /// Build this much levain (grams) from --levain-seed, as the first steps of the timeline ending at the mix
+    #[arg(long, value_name = "GRAMS", requires = "levain_seed")]
+    levain: Option<f64>,
+
+    /// Seed starter (grams) for the --levain build
+    #[arg(long, value_name = "GRAMS", requires = "levain")]
+    levain_seed: Option<f64>,
+
+    /// Units for printed amounts (imperial: oz/lb, teaspoons, °F)
+    #[arg(long, env = "PIZZA_UNITS", value_enum, default_value_t = Units::Metric)]
+    units: Units,
+
+    /// Language of the recipe output [default: from the system locale]
+    #[arg(long, env = "PIZZA_LANG", value_enum)]
+    lang: Option<Lang>,
+
+    /// Print only these fields of the result, e.g. `flour,water,timeline.bulk_h`
+    #[arg(long, value_delimiter = ',', value_name = "PATHS")]
+    fields: Vec<String>,
+
+    /// With --fields: print bare values, one per line (for scripts)
+    #[arg(long, requires = "fields")]
+    values: bool,
+
+    /// Calendar (.ics) with busy times; the start moves so hands-on steps avoid them (repeatable)
+    #[arg(long, value_name = "ICS")]
+    busy: Vec<PathBuf>,
+
+    /// Note or reminder for a phase, e.g. `warmup="buy basil"` (repeatable)
+    #[arg(long = "note", value_name = "PHASE=TEXT")]
+    notes: Vec<PhaseNote>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Profile {
+/// Accessors for the merged recipe flags, all of which are set once `prepare` has run.
+macro_rules! merged {
+    ($($field:ident: $ty:ty),* $(,)?) => {
+        impl Args {
+            $(
+                fn $field(&self) -> $ty {
+                    self.$field.expect(concat!(stringify!($field), " is merged in prepare"))
+                }
+            )*
+        }
+    };
+}
+
+merged! {
     w: u16,
-    temp: f64,
     yeast: YeastFlag,
     hydration: f64,
     salt_per_kg: f64,
@@ -107,270 +1058,939 @@ struct Profile {
     fridge_hours: f64,
     warmup_hours: f64,
     fridge_factor: f64,
-    start: Option<String>,
+    fresh_factor: f64,
+    model: ModelFlag,
+    salt_type: SaltType,
 }
 
-impl From<&Args> for Profile {
-    fn from(a: &Args) -> Self {
-        Profile {
-            w: a.w,
-            temp: a.temp,
-            yeast: a.yeast,
-            hydration: a.hydration,
-            salt_per_kg: a.salt_per_kg,
-            ball_weight: a.ball_weight,
-            balls: a.balls,
-            total_hours: a.total_hours,
-            fridge_hours: a.fridge_hours,
-            warmup_hours: a.warmup_hours,
-            fridge_factor: a.fridge_factor,
-            start: a.start.clone(),
+impl Args {
+    /// Ambient temperature in °C.
+    fn temp(&self) -> f64 {
+        self.temp.expect("temp is merged in prepare").celsius
+    }
+
+    /// Ball sizes in the batch: the `--ball` mix, or `balls` × `ball_weight`.
+    fn sizes(&self) -> Vec<BallSpec> {
+        if self.ball_mix.is_empty() {
+            vec![BallSpec {
+                weight: self.ball_weight(),
+                count: self.balls(),
+            }]
+        } else {
+            self.ball_mix.clone()
         }
     }
-}
 
-fn fmt_g(x: f64) -> String {
-    let v = (x * 10.0).round() / 10.0;
-    if (v - v.round()).abs() < 1e-9 {
-        format!("{:.0} g", v)
-    } else {
-        format!("{:.1} g", v)
+    /// Total number of balls.
+    fn ball_count(&self) -> u32 {
+        self.sizes().iter().map(|b| b.count).sum()
     }
-}
 
-fn main() {
-    let mut args = Args::parse();
+    /// Total dough weight in grams.
+    fn total_dough(&self) -> f64 {
+        self.sizes().iter().map(|b| b.weight * b.count as f64).sum()
+    }
 
-    // Load profile if present, then apply CLI overrides (CLI wins).
-    if let Some(path) = &args.profile {
-        let Ok(txt) = fs::read_to_string(path) else {
-            eprintln!("Failed to read profile: {}", path.display());
-            std::process::exit(1);
-        };
-        let Ok(p): Result<Profile, _> = serde_json::from_str(&txt) else {
-            eprintln!("Invalid profile JSON: {}", path.display());
-            std::process::exit(1);
-        };
+    /// The binder of a gluten-free dough, `None` for wheat.
+    fn gluten_free(&self) -> Option<Binder> {
+        (self.style == Some(DoughFlag::GlutenFree)).then(|| self.binder.unwrap_or_default().into())
+    }
 
-        // Defaults snapshot to detect "unset" fields
-        let def = Args::parse_from(["pizza-cli"]);
+    /// The flour blend of a pinsa, `None` for other styles.
+    fn pinsa(&self) -> Option<FlourBlend> {
+        (self.style == Some(DoughFlag::Pinsa)).then(|| self.blend.unwrap_or(pinsa::PINSA))
+    }
 
-        macro_rules! take {
-            ($field:ident) => {
-                if args.$field == def.$field { p.$field } else { args.$field }
-            };
-        }
+    /// The pan of a Detroit-style dough, `None` for other styles.
+    fn detroit_pan(&self) -> Option<Pan> {
+        (self.style == Some(DoughFlag::Detroit)).then(|| self.pan.unwrap_or(Pan::DETROIT))
+    }
 
-        args.w = take!(w);
-        args.temp = take!(temp);
-        args.yeast = if matches!(args.yeast, YeastFlag::Dry) && !matches!(p.yeast, YeastFlag::Dry) {
-            p.yeast
-        } else {
-            args.yeast
+    /// Milk, oil and sugar: `--milk`, `--oil` and `--sugar`, with the
+    /// defaults of `--style pan` and `--style new-york`.
+    fn enrichment(&self) -> Enrichment {
+        let base = match self.style {
+            Some(DoughFlag::Pan) => enriched::PAN,
+            Some(DoughFlag::NewYork) => enriched::NEW_YORK,
+            _ => Enrichment::default(),
         };
-        args.hydration = take!(hydration);
-        args.salt_per_kg = take!(salt_per_kg);
-        args.ball_weight = take!(ball_weight);
-        args.balls = take!(balls);
-        args.total_hours = take!(total_hours);
-        args.fridge_hours = take!(fridge_hours);
-        args.warmup_hours = take!(warmup_hours);
-        args.fridge_factor = take!(fridge_factor);
-        if args.start.is_none() {
-            args.start = p.start;
+        let frac = |pct: Option<f64>, default: f64| pct.map_or(default, |p| p / 100.0);
+        Enrichment {
+            milk_share: frac(self.milk, base.milk_share),
+            oil_pct: frac(self.oil, base.oil_pct),
+            sugar_pct: frac(self.sugar, base.sugar_pct),
         }
     }
 
-    // Save profile if requested (using the effective arguments).
-    if let Some(path) = &args.save_profile {
-        let prof = Profile::from(&args);
-        if let Err(e) = fs::write(path, serde_json::to_string_pretty(&prof).unwrap()) {
-            eprintln!("Failed to save profile: {e}");
-            std::process::exit(1);
-        } else {
-            println!("Profile saved to {}", path.display());
+    /// The selected flour from the built-in list (checked in `prepare`).
+    fn flour(&self) -> Option<&'static Flour> {
+        self.flour_name.as_deref().and_then(flours::find)
+    }
+
+    /// Whether any desired-dough-temperature input was given.
+    fn ddt_requested(&self) -> bool {
+        self.ddt.is_some()
+            || self.flour_temp.is_some()
+            || self.mixer.is_some()
+            || self.friction.is_some()
+    }
+
+    /// Whether the dough stays out of the fridge longer than it keeps at
+    /// this temperature ([`pizza_core::max_room_hours`]); no-knead and
+    /// emergency doughs are planned for their hours.
+    fn too_long_at_room(&self) -> bool {
+        let max = pizza_core::max_room_hours(self.model().model().as_ref(), self.temp());
+        self.fridge_hours() == 0.0
+            && self.total_hours() > max
+            && !self.no_knead
+            && self.preset.is_none()
+    }
+
+    /// Whether the total leaves time for a fridge block besides the
+    /// [`COLD_FERMENT_ROOM_HOURS`] out of it and the warmup.
+    fn fridge_fits(&self) -> bool {
+        self.total_hours() > COLD_FERMENT_ROOM_HOURS + self.warmup_hours()
+    }
+
+    /// The mixer to plan the kneading for: `--mixer`, except for a
+    /// gluten-free or no-knead dough, which is only stirred.
+    fn kneading(&self) -> Option<Mixer> {
+        self.mixer
+            .filter(|_| self.gluten_free().is_none() && !self.no_knead)
+            .map(Mixer::from)
+    }
+
+    /// Friction factor (°C): `--friction`, or the `--mixer` preset.
+    fn friction(&self) -> f64 {
+        self.friction
+            .unwrap_or_else(|| Mixer::from(self.mixer.unwrap_or_default()).friction_c())
+    }
+
+    /// Share of the yeast still active: `--yeast-viability`, or estimated
+    /// from `--yeast-opened`.
+    fn viability(&self) -> f64 {
+        match self.yeast_opened {
+            Some(opened) => {
+                let days = (Local::now().date_naive() - opened).num_days();
+                viability_after_opening(days as f64)
+            }
+            None => self.yeast_viability.unwrap_or(1.0),
         }
     }
 
-    // Validations
-    if !(0.55..=0.85).contains(&args.hydration) {
-        eprintln!("Hydration must be between 0.55 and 0.85");
-        std::process::exit(1);
+    /// Preheat time: `--preheat`, or the `--oven` preset; `None` without either.
+    fn preheat_minutes(&self) -> Option<u32> {
+        self.preheat
+            .or_else(|| self.oven.map(|o| Oven::from(o).preheat_minutes()))
     }
-    if args.total_hours <= 0.0 {
-        eprintln!("total-hours must be > 0");
-        std::process::exit(1);
+
+    /// Bake and recovery minutes per pizza: the flags, or the `--oven` preset
+    /// (a home oven with a stone when not given).
+    fn bake_cycle(&self) -> (f64, f64) {
+        let oven = Oven::from(self.oven.unwrap_or_default());
+        (
+            self.bake_minutes.unwrap_or(oven.bake_minutes()),
+            self.recovery_minutes.unwrap_or(oven.recovery_minutes()),
+        )
     }
-    if args.fridge_hours < 0.0 || args.warmup_hours < 0.0 {
-        eprintln!("fridge-hours and warmup-hours must be >= 0");
-        std::process::exit(1);
+
+    /// Output language: `--lang`, or the system locale.
+    fn lang(&self) -> Lang {
+        self.lang.unwrap_or_else(Lang::detect)
     }
-    if args.fridge_hours > 0.0 && args.fridge_hours + args.warmup_hours >= args.total_hours {
-        eprintln!("Sum of fridge-hours and warmup-hours must be < total-hours");
-        std::process::exit(1);
+
+    /// Units for printed temperatures: °F when the temperature was given in °F.
+    fn temp_units(&self) -> Units {
+        match self.temp {
+            Some(Temperature {
+                unit: Units::Imperial,
+                ..
+            }) => Units::Imperial,
+            _ => self.units,
+        }
     }
+}
 
-    // Totals
-    let balls = args.balls as f64;
-    let total_dough = balls * args.ball_weight;
-
-    // Effective hours for yeast model
-    let eff_hours = effective_hours(args.total_hours, args.fridge_hours, args.fridge_factor);
-
-    // Ingredients
-    let ing = compute_ingredients(IngredientsInput {
-        total_dough_g: total_dough,
-        hydration: args.hydration,
-        salt_per_kg: args.salt_per_kg,
-        yeast: args.yeast.into(),
-        temp_c: args.temp,
-        w: args.w,
-        effective_hours: eff_hours,
-    });
-
-    // Timeline (with/without fridge)
-    let tl: Timeline = if args.fridge_hours > 0.0 {
-        timeline_with_fridge(args.total_hours, args.temp, args.fridge_hours, args.warmup_hours)
-    } else {
-        timeline_no_fridge(args.total_hours, args.temp)
-    };
+/// Print the error and exit with status 1.
+fn or_exit<T>(r: Result<T, String>) -> T {
+    r.unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    })
+}
 
-    // Start time and phase ends
-    let start_time = if let Some(hhmm) = args.start.as_ref() {
-        NaiveTime::parse_from_str(hhmm, "%H:%M").ok()
-    } else {
-        Some(Local::now().naive_local().time())
-    };
+/// Constant temperature with the same yeast activity as `sched` over the
+/// room-temperature phases (bulk, warmup, proof), starting at `--start`.
+fn schedule_temp(args: &Args, sched: &TempSchedule) -> f64 {
+    let start = args
+        .start
+        .as_deref()
+        .and_then(|s| NaiveTime::parse_from_str(s, "%H:%M").ok())
+        .unwrap_or_else(|| Local::now().time());
+    let h0 = start.hour() as f64 + start.minute() as f64 / 60.0;
+    let total = args.total_hours();
+    // The bulk/proof split depends on temperature: size it with the average.
+    let whole = equivalent_temp(&sched.segments(h0, h0 + total)).unwrap_or_else(|| sched.at(h0));
+    let tl = pizza_core::timeline(total, whole, args.fridge_hours(), args.warmup_hours());
+    let mut room = sched.segments(h0, h0 + tl.bulk_h);
+    room.extend(sched.segments(h0 + tl.bulk_h + tl.fridge_h, h0 + total));
+    equivalent_temp(&room).unwrap_or(whole)
+}
 
-    let (t_bulk_end, t_fridge_end, t_warmup_end, t_proof_end) = if let Some(st) = start_time {
-        let to_min = |h: f64| (h * 60.0).round() as i64;
-        let mut dt = Local::now().date_naive().and_time(st);
+/// Merge the profile (if any), save it when requested and validate the result.
+fn prepare(args: Args) -> Result<Args, String> {
+    let profile = args
+        .profile
+        .as_ref()
+        .map(|path| profile::read(path))
+        .transpose()?;
+    prepare_with(args, profile)
+}
 
-        let bulk_end = dt + chrono::Duration::minutes(to_min(tl.bulk_h));
-        dt = bulk_end;
+/// [`prepare`] with an already loaded profile (e.g. from a share string).
+fn prepare_with(mut args: Args, profile: Option<Profile>) -> Result<Args, String> {
+    // Apply the profile and config if present, then CLI overrides (CLI wins).
+    let cfg = config::load()?.defaults;
+    args.expert |= cfg.expert.unwrap_or(false);
 
-        let fridge_end = if tl.fridge_h > 0.0 {
-            let e = dt + chrono::Duration::minutes(to_min(tl.fridge_h));
-            dt = e;
-            Some(e)
-        } else {
-            None
+    // Precedence: command line (or environment) > profile > config file > built-in default.
+    macro_rules! merge {
+        ($field:ident, $default:expr) => {
+            args.$field = args
+                .$field
+                .or(profile.as_ref().map(|p| p.$field))
+                .or(cfg.$field)
+                .or($default);
         };
+    }
 
-        let warmup_end = if tl.warmup_h > 0.0 {
-            let e = dt + chrono::Duration::minutes(to_min(tl.warmup_h));
-            dt = e;
-            Some(e)
-        } else {
-            None
-        };
+    args.style = args.style.or(profile.as_ref().and_then(|p| p.style));
+    args.binder = args.binder.or(profile.as_ref().and_then(|p| p.binder));
+    args.milk = args.milk.or(profile.as_ref().and_then(|p| p.milk));
+    args.oil = args.oil.or(profile.as_ref().and_then(|p| p.oil));
+    args.sugar = args.sugar.or(profile.as_ref().and_then(|p| p.sugar));
+    args.pan = args.pan.or(profile.as_ref().and_then(|p| p.pan));
+    args.blend = args.blend.or(profile.as_ref().and_then(|p| p.blend));
+    args.no_knead |= profile.as_ref().is_some_and(|p| p.no_knead);
+    args.model = args
+        .model
+        .or(profile.as_ref().and_then(|p| p.model))
+        .or(cfg.model)
+        .or(Some(ModelFlag::default()));
+    args.preset = args.preset.or(profile.as_ref().and_then(|p| p.preset));
+    // The rest of what the plan depends on, for a profile, share string or
+    // last.json to replay it exactly.
+    if let Some(p) = &profile {
+        args.yeast_pct = args.yeast_pct.or(p.yeast_pct);
+        if args.yeast_viability.is_none() && args.yeast_opened.is_none() {
+            (args.yeast_viability, args.yeast_opened) = (p.yeast_viability, p.yeast_opened);
+        }
+        args.malt |= p.malt;
+        args.expert |= p.expert;
+        args.salt_type = args.salt_type.or(p.salt_type);
+        args.salt_after = args.salt_after.or(p.salt_after);
+        if args.levain.is_none() {
+            (args.levain, args.levain_seed) = (p.levain, p.levain_seed);
+        }
+        args.humidity = args.humidity.or(p.humidity);
+        args.oven = args.oven.or(p.oven);
+        args.preheat = args.preheat.or(p.preheat);
+        args.bake_minutes = args.bake_minutes.or(p.bake_minutes);
+        args.recovery_minutes = args.recovery_minutes.or(p.recovery_minutes);
+        args.mixer = args.mixer.or(p.mixer);
+        args.friction = args.friction.or(p.friction);
+        args.ddt = args.ddt.or(p.ddt);
+        args.flour_temp = args.flour_temp.or(p.flour_temp);
+    }
+    args.salt_type = args.salt_type.or(Some(SaltType::default()));
+    let emergency = args.preset == Some(PresetFlag::Emergency);
+    if emergency {
+        args.ddt = args.ddt.or(Some(Temperature::celsius(EMERGENCY_DDT_C)));
+    }
+    if args.blend.is_some() && args.pinsa().is_none() {
+        return Err("--blend only applies to --style pinsa".to_string());
+    }
+    let gluten_free = args.gluten_free().is_some();
+    if gluten_free && (args.flour_name.is_some() || args.protein.is_some()) {
+        return Err(
+            "--flour-name and --protein describe wheat flour, not a gluten-free blend".to_string(),
+        );
+    }
+    if !gluten_free && args.binder.is_some() {
+        return Err("--binder only applies to --style gluten-free".to_string());
+    }
+    if gluten_free && args.no_knead {
+        return Err("--style gluten-free is never kneaded; drop --no-knead".to_string());
+    }
+    // A Detroit dough is one piece per pan, weighed by the pan's area; a
+    // ball weight given here still wins over it.
+    if let Some(pan) = args.detroit_pan() {
+        if !args.ball_mix.is_empty() {
+            return Err(
+                "--ball mixes ball sizes; --style detroit makes one dough per --pan".to_string(),
+            );
+        }
+        if args.toppings.is_some() {
+            return Err(
+                "--toppings is for round pizzas; --style detroit prints its own cheese and sauce"
+                    .to_string(),
+            );
+        }
+        args.ball_weight = args.ball_weight.or(Some(pans::detroit(pan).dough_g));
+    } else if args.pan.is_some() {
+        return Err("--pan only applies to --style detroit".to_string());
+    }
+    // A blend has no W: the yeast estimate uses the reference flour.
+    if gluten_free {
+        args.w = args.w.or(Some(gluten_free::W));
+    }
 
-        let proof_end = dt + chrono::Duration::minutes(to_min(tl.proof_h));
-        (
-            Some(bulk_end.time()),
-            fridge_end.map(|x| x.time()),
-            warmup_end.map(|x| x.time()),
-            Some(proof_end.time()),
-        )
-    } else {
-        (None, None, None, None)
-    };
+    // A flour named here sets W (unless given), beating the profile's W.
+    if let Some(name) = &args.flour_name {
+        let flour = flours::find(name).ok_or_else(|| {
+            let names: Vec<_> = flours::FLOURS.iter().map(|f| f.name).collect();
+            format!("Unknown flour '{name}'. Known: {}", names.join(", "))
+        })?;
+        args.w = args.w.or(Some(flour.w));
+    } else if let Some(p) = &profile {
+        args.flour_name = p.flour.clone();
+    }
+    // So does the protein on the bag, through the estimate.
+    if let Some(protein) = args.protein {
+        if !(5.0..=20.0).contains(&protein) {
+            return Err("protein must be between 5 and 20 (percent)".to_string());
+        }
+        let w = flours::w_from_protein(protein, args.flour_type.unwrap_or_default().into());
+        args.w = args.w.or(Some(w.clamp(200, 450)));
+    }
 
-    // Ingredients table
-    let mut table = Table::new();
-    table
-        .load_preset(UTF8_FULL)
-        .set_content_arrangement(ContentArrangement::Dynamic)
-        .set_header(vec![
-            Cell::new("Ingredient").add_attribute(Attribute::Bold),
-            Cell::new("Amount").add_attribute(Attribute::Bold),
-            Cell::new("Notes").add_attribute(Attribute::Bold),
-        ]);
-
-    table.add_row(vec![
-        Cell::new("Balls"),
-        Cell::new(format!("{} × {:.0} g", args.balls, args.ball_weight)),
-        Cell::new(""),
-    ]);
-    table.add_row(vec![
-        Cell::new("Flour"),
-        Cell::new(fmt_g(ing.flour_g)),
-        Cell::new(format!("W={} | H={:.0}%", args.w, args.hydration * 100.0)),
-    ]);
-    table.add_row(vec![Cell::new("Water"), Cell::new(fmt_g(ing.water_g)), Cell::new("")]);
-    table.add_row(vec![
-        Cell::new("Salt"),
-        Cell::new(fmt_g(ing.salt_g)),
-        Cell::new(format!("{:.1} g/kg", args.salt_per_kg)),
-    ]);
-
-    match args.yeast {
-        YeastFlag::Dry => table.add_row(vec![
-            Cell::new("Dry yeast"),
-            Cell::new(fmt_g(ing.yeast_g)),
-            Cell::new("~% of flour (estimate)"),
-        ]),
-        YeastFlag::Fresh => table.add_row(vec![
-            Cell::new("Fresh yeast"),
-            Cell::new(fmt_g(ing.yeast_g)),
-            Cell::new("~3× dry yeast"),
-        ]),
-    };
+    // A size mix from the profile only applies when no size was given here.
+    let sizes_given =
+        !args.ball_mix.is_empty() || args.balls.is_some() || args.ball_weight.is_some();
 
-    println!("\n=== Ingredients summary ===");
-    println!("{}", table);
+    merge!(w, None);
+    if let Some(s) = args.schedule {
+        args.w = args.w.or(Some(s.min_w()));
+        let (total, fridge, warmup) = s.hours();
+        args.total_hours = args.total_hours.or(Some(total));
+        args.fridge_hours = args.fridge_hours.or(Some(fridge));
+        args.warmup_hours = args.warmup_hours.or(Some(warmup));
+    }
+    if let Some(f) = args.temp_f.take() {
+        args.temp = Some(Temperature::fahrenheit(f));
+    }
+    args.temp = args
+        .temp
+        .or(profile.as_ref().map(|p| Temperature::celsius(p.temp)))
+        .or(cfg.temp.map(Temperature::celsius))
+        .or(Some(Temperature::celsius(25.0)));
+    merge!(yeast, Some(YeastFlag::Dry));
+    merge!(
+        hydration,
+        Some(match args.style.unwrap_or_default() {
+            DoughFlag::GlutenFree => gluten_free::DEFAULT_HYDRATION,
+            DoughFlag::NewYork => 0.62,
+            DoughFlag::Pinsa => pinsa::DEFAULT_HYDRATION,
+            _ => 0.75,
+        })
+    );
+    // 75 means 75%: whole percents (from any source) become fractions.
+    if let Some(h) = args.hydration
+        && h > 1.5
+    {
+        args.hydration = Some(h / 100.0);
+    }
+    if let Some(pct) = args.salt_pct.take() {
+        args.salt_per_kg = Some(pct * 10.0);
+    }
+    merge!(salt_per_kg, Some(20.0));
+    merge!(ball_weight, Some(280.0));
+    merge!(balls, Some(2));
+    let cold_ferment = matches!(args.style, Some(DoughFlag::NewYork | DoughFlag::Pinsa));
+    merge!(
+        total_hours,
+        Some(match args.style.unwrap_or_default() {
+            DoughFlag::GlutenFree => gluten_free::DEFAULT_TOTAL_HOURS,
+            DoughFlag::NewYork => 48.0,
+            DoughFlag::Pinsa => pinsa::DEFAULT_TOTAL_HOURS,
+            _ if args.no_knead => no_knead::DEFAULT_TOTAL_HOURS,
+            _ if emergency => EMERGENCY_DEFAULT_HOURS,
+            _ => 11.0,
+        })
+    );
+    // New York and pinsa doughs spend all but a few hours at room
+    // temperature in the fridge, whatever the total.
+    merge!(
+        fridge_hours,
+        Some(match cold_ferment {
+            true => (args.total_hours() - COLD_FERMENT_ROOM_HOURS).max(0.0),
+            false => 0.0,
+        })
+    );
+    merge!(warmup_hours, Some(3.0));
+    merge!(fridge_factor, Some(0.25));
+    args.fresh_factor = args
+        .fresh_factor
+        .or(profile.as_ref().and_then(|p| p.fresh_factor))
+        .or(cfg.fresh_factor)
+        .or(Some(FRESH_PER_DRY));
+    // As much yeast as the estimates go to, in percent of the chosen kind.
+    if emergency && args.yeast_pct.is_none() {
+        let dry = YEAST_PERCENT_DRY_RANGE.end() * 100.0;
+        args.yeast_pct = Some(match args.yeast() {
+            YeastFlag::Dry => dry,
+            YeastFlag::Fresh => dry * args.fresh_factor(),
+        });
+    }
+    if let Some(p) = profile {
+        if args.start.is_none() {
+            args.start = p.start;
+        }
+        if !sizes_given {
+            args.ball_mix = p.ball_mix;
+        }
+        // Profile notes come first; notes given on the command line are added to them.
+        let cli_notes = std::mem::take(&mut args.notes);
+        args.notes = p.notes;
+        args.notes.extend(cli_notes);
+    }
 
-    // Timeline
-    println!("\n=== Timeline ===");
-    println!(
-        "- Bulk rise (whole dough): {:.1} h{}",
-        tl.bulk_h,
-        match t_bulk_end {
-            Some(t) => format!(" → ~end at {:02}:{:02}", t.hour(), t.minute()),
-            None => "".to_string(),
+    if args.w.is_none() {
+        return Err("--w is required (or set it in a profile or config.toml)".to_string());
+    }
+    if let Some(s) = args.schedule
+        && args.w() < s.min_w()
+        && args.gluten_free().is_none()
+        && !args.expert
+    {
+        return Err(format!(
+            "W {} does not last {} h; this schedule needs W {} or more (or --expert)",
+            args.w(),
+            s.hours().0,
+            s.min_w()
+        ));
+    }
+    if emergency {
+        if args.fridge_hours() > 0.0 {
+            return Err(
+                "--preset emergency has no time for the fridge; drop --fridge-hours".to_string(),
+            );
         }
-    );
+        if !EMERGENCY_HOURS.contains(&args.total_hours()) {
+            return Err(format!(
+                "--preset emergency is planned for {}–{} h (--total-hours), got {}",
+                EMERGENCY_HOURS.start(),
+                EMERGENCY_HOURS.end(),
+                args.total_hours()
+            ));
+        }
+    }
+    if args.no_knead {
+        if args.fridge_hours() > 0.0 {
+            return Err("--no-knead ferments at room temperature; drop --fridge-hours".to_string());
+        }
+        let range = no_knead::TOTAL_HOURS;
+        if !range.contains(&args.total_hours()) {
+            return Err(format!(
+                "--no-knead is planned for {}–{} h (--total-hours), got {}",
+                range.start(),
+                range.end(),
+                args.total_hours()
+            ));
+        }
+    }
+
+    if let Some(sched) = &args.temp_schedule {
+        args.temp = Some(Temperature::celsius(schedule_temp(&args, sched)));
+    }
+    if let Some(path) = &args.temp_log {
+        let log = templog::read(path)?;
+        args.temp = Some(Temperature::celsius(log.overall(&*args.model().model())));
+    }
+    if let Some(fridge) = args.fridge_temp {
+        let model = args.model().model();
+        args.fridge_factor = Some(model.relative(args.temp(), fridge.celsius));
+    }
+    if args.auto_fridge && args.too_long_at_room() && args.fridge_fits() {
+        args.fridge_hours = Some(args.total_hours() - COLD_FERMENT_ROOM_HOURS);
+    }
+
+    // Save profile if requested (using the effective arguments).
+    if let Some(spec) = &args.save_profile {
+        save_profile(spec, &args)?;
+    }
 
-    if tl.fridge_h > 0.0 {
-        println!(
-            "- Fridge (covered):        {:.1} h{}",
-            tl.fridge_h,
-            match t_fridge_end {
-                Some(t) => format!(" → ~end at {:02}:{:02}", t.hour(), t.minute()),
-                None => "".to_string(),
+    // Validations; --expert only warns about the hydration.
+    let input = recipe::plan_input(&args);
+    match args.expert {
+        true => input.validate_lenient(),
+        false => input.validate(),
+    }
+    .map_err(|e| match e {
+        RecipeError::Hydration(h)
+        | RecipeError::GlutenFreeHydration(h)
+        | RecipeError::PinsaHydration(h)
+            if h > 0.0 =>
+        {
+            format!("{e} (--expert accepts it with a warning)")
+        }
+        _ => e.to_string(),
+    })?;
+    if !(2.0..=4.0).contains(&args.fresh_factor()) {
+        return Err("fresh-factor must be between 2 and 4".to_string());
+    }
+    if [args.bake_minutes, args.recovery_minutes]
+        .iter()
+        .flatten()
+        .any(|m| m.is_nan() || *m < 0.0)
+    {
+        return Err("bake and recovery minutes must be >= 0".to_string());
+    }
+    if args.milk.is_some_and(|p| !(0.0..=100.0).contains(&p)) {
+        return Err("milk must be between 0 and 100 (percent of the water)".to_string());
+    }
+    if [args.oil, args.sugar]
+        .iter()
+        .flatten()
+        .any(|p| !(0.0..=15.0).contains(p))
+    {
+        return Err("oil and sugar must be between 0 and 15 (percent of flour)".to_string());
+    }
+    if args.salt_after.is_some_and(|m| !(1..=120).contains(&m)) {
+        return Err("salt-after must be between 1 and 120 minutes".to_string());
+    }
+    if let (Some(target), Some(seed)) = (args.levain, args.levain_seed) {
+        if target.is_nan() || target <= 0.0 || seed.is_nan() || seed <= 0.0 {
+            return Err("levain and levain-seed grams must be > 0".to_string());
+        }
+        if seed >= target {
+            return Err("levain-seed already covers the levain; no build required".to_string());
+        }
+    }
+    if let Some(rh) = args.humidity
+        && !(0.0..=100.0).contains(&rh)
+    {
+        return Err("humidity must be between 0 and 100 (percent)".to_string());
+    }
+    if let Some(d) = args.diameter
+        && (d.is_nan() || d <= 0.0)
+    {
+        return Err("diameter must be > 0 (cm)".to_string());
+    }
+    if let Some(pct) = args.yeast_pct
+        && !(pct > 0.0 && pct <= 5.0)
+    {
+        return Err("yeast-pct must be > 0 and at most 5 (percent of flour)".to_string());
+    }
+    if let Some(v) = args.yeast_viability
+        && !(0.1..=1.0).contains(&v)
+    {
+        return Err("yeast-viability must be between 0.1 and 1".to_string());
+    }
+    if args
+        .yeast_opened
+        .is_some_and(|d| d > Local::now().date_naive())
+    {
+        return Err("yeast-opened is in the future".to_string());
+    }
+
+    if args.fridge_hours() <= 0.0 {
+        for n in &args.notes {
+            if matches!(n.phase, PhaseKind::Fridge | PhaseKind::Warmup) {
+                eprintln!("Note ignored (no fridge phase): {}", n.text);
             }
-        );
-        println!(
-            "- Warmup (bench rest):     {:.1} h{}",
-            tl.warmup_h,
-            match t_warmup_end {
-                Some(t) => format!(" → ~end at {:02}:{:02}", t.hour(), t.minute()),
-                None => "".to_string(),
+        }
+    }
+
+    Ok(args)
+}
+
+fn main() {
+    let cli = Cli::parse();
+    theme::init(cli.theme, cli.no_color, cli.ascii);
+    match cli.command {
+        None => match cli.args {
+            Some(args) => calc(args),
+            None => {
+                Cli::command().print_help().ok();
+                std::process::exit(2);
             }
-        );
+        },
+        Some(Command::Calc(args)) => calc(args),
+        Some(Command::Scale { factor, args }) => scale(factor, *args),
+        Some(Command::Compare {
+            first,
+            second,
+            flags,
+        }) => {
+            let a = or_exit(compare::args(&first, &flags));
+            let b = or_exit(compare::args(&second, &flags));
+            let (ra, rb) = (or_exit(plan(&a)), or_exit(plan(&b)));
+            let header = compare::header(&a, &first, &second);
+            text::print_compare(header, compare::rows(&ra, &rb));
+        }
+        Some(Command::Sweep { vary, mut args }) => {
+            // The base recipe must plan too: a fridge phase needs the longest total.
+            if vary.param == sweep::Param::Hours {
+                args.total_hours = Some(vary.to);
+            }
+            let args = or_exit(prepare(*args));
+            text::print_sweep(&args, &vary);
+        }
+        Some(Command::Flours) => text::print_flours(),
+        Some(Command::Starter(StarterCommand::Feed {
+            grams,
+            ratio,
+            temp,
+            at,
+            mix,
+        })) => or_exit(starter::feed(
+            grams,
+            ratio,
+            temp,
+            at.as_deref(),
+            mix.as_deref(),
+        )),
+        Some(Command::Starter(StarterCommand::Build {
+            grams,
+            seed,
+            temp,
+            mix,
+        })) => or_exit(starter::build(grams, seed, temp, mix.as_deref())),
+        Some(Command::Share(args)) => {
+            let args = or_exit(prepare(args));
+            let code = share::encode(&Profile::from(&args));
+            println!("{code}");
+            eprintln!("Open it with: pizza-cli open {code}");
+        }
+        Some(Command::Open { code, args }) => {
+            let shared = or_exit(share::decode(&code));
+            show(or_exit(prepare_with(*args, Some(shared))));
+        }
+        Some(Command::Import {
+            file,
+            path,
+            mut args,
+        }) => {
+            match or_exit(import::read(&file)) {
+                import::Source::Recipe(imported) => {
+                    for line in &imported.skipped {
+                        eprintln!("Skipped: {line}");
+                    }
+                    imported.apply(&mut args);
+                }
+                import::Source::Profile => args.profile = Some(file),
+            }
+            args.save_profile = Some(path.clone());
+            or_exit(prepare(*args));
+            let p = or_exit(profile::read(&path));
+            text::print_profile(&serde_json::to_value(&p).unwrap());
+        }
+        Some(Command::Journal(JournalCommand::Add {
+            tags,
+            profile,
+            note,
+        })) => or_exit(journal::add(profile.as_deref(), &tags, note)),
+        Some(Command::Journal(JournalCommand::List)) => or_exit(journal::list()),
+        Some(Command::Suggest { profile, last }) => or_exit(journal::suggest(&profile, last)),
+        Some(Command::Timeline(args)) => {
+            let args = or_exit(prepare(args));
+            text::print_timeline(&or_exit(plan(&args)));
+            history::remember(&args);
+        }
+        Some(Command::Last(mut args)) => {
+            let last = history::last_path().filter(|p| p.is_file());
+            let Some(last) = last else {
+                eprintln!("No previous calculation to recall");
+                std::process::exit(1);
+            };
+            if args.profile.is_none() {
+                args.profile = Some(last);
+            }
+            calc(args);
+        }
+        Some(Command::Profile(ProfileCommand::Save { path, mut args })) => {
+            args.save_profile = Some(path);
+            or_exit(prepare(*args));
+        }
+        Some(Command::Profile(ProfileCommand::Show { path })) => {
+            let p = or_exit(profile::read(&path));
+            text::print_profile(&serde_json::to_value(&p).unwrap());
+        }
+        Some(Command::Profile(ProfileCommand::List)) => match profile::list() {
+            Ok(names) if names.is_empty() => match profile::dir() {
+                Some(d) => eprintln!("No profiles in {}", d.display()),
+                None => eprintln!("No profiles (config directory unavailable)"),
+            },
+            Ok(names) => {
+                for name in names {
+                    println!("{name}");
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to list profiles: {e}");
+                std::process::exit(1);
+            }
+        },
+        Some(Command::Profile(ProfileCommand::Delete { path })) => match profile::delete(&path) {
+            Ok(p) => eprintln!("Deleted {}", p.display()),
+            Err(e) => {
+                eprintln!("Failed to delete profile: {e}");
+                std::process::exit(1);
+            }
+        },
+        Some(Command::Profile(ProfileCommand::Rename { from, to })) => {
+            match profile::rename(&from, &to) {
+                Ok(p) => eprintln!("Renamed to {}", p.display()),
+                Err(e) => {
+                    eprintln!("Failed to rename profile: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Some(Command::Convert(ConvertCommand::Yeast {
+            grams,
+            from,
+            to,
+            fresh_factor,
+        })) => {
+            if grams < 0.0 {
+                eprintln!("grams must be >= 0");
+                std::process::exit(1);
+            }
+            if fresh_factor.is_nan() || fresh_factor <= 0.0 {
+                eprintln!("--fresh-factor must be > 0");
+                std::process::exit(1);
+            }
+            let out = convert_yeast_with(grams, from.into(), to.into(), fresh_factor);
+            println!("{}", fmt_g(out));
+        }
+        Some(Command::Repl { args }) => repl::run(args),
+        Some(Command::Serve { listen }) => or_exit(serve::run(&listen)),
+        Some(Command::Mcp) => or_exit(mcp::run()),
+        #[cfg(feature = "telegram")]
+        Some(Command::Bot {
+            telegram_token,
+            args,
+        }) => or_exit(bot::run(&telegram_token, args)),
+        Some(Command::Tui(args)) => {
+            let args = or_exit(prepare(args));
+            let recipe = or_exit(plan(&args));
+            history::remember(&args);
+            if let Err(e) = tui::run(&recipe) {
+                eprintln!("TUI error: {e}");
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Start(args)) => {
+            let args = or_exit(prepare(args));
+            let recipe = or_exit(plan(&args));
+            history::remember(&args);
+            let bake = or_exit(bake::Bake::new(&args, &recipe));
+            or_exit(bake.save());
+            let status = bake.status(Local::now().naive_local(), theme::current());
+            print!("{}", theme::glyphs(&status));
+        }
+        Some(Command::Status) => {
+            let bake = or_exit(bake::Bake::load());
+            let status = bake.status(Local::now().naive_local(), theme::current());
+            print!("{}", theme::glyphs(&status));
+        }
+        Some(Command::Adjust {
+            temp,
+            elapsed,
+            temp_log,
+        }) => {
+            let mut bake = or_exit(bake::Bake::load());
+            let model = bake.model();
+            let now = Local::now().naive_local();
+            let mut since = elapsed.map_or(bake.steps[0].at, |e| now - e);
+            let temp = match temp_log {
+                Some(path) => {
+                    // The recorded temperatures since the log (or --elapsed) began.
+                    let log = or_exit(templog::read(&path));
+                    since = since.max(log.first());
+                    let t = log.equivalent_temp(&*model, since, now).or(log.at(now));
+                    let t = or_exit(t.ok_or(format!("{}: no readings before now", path.display())));
+                    Temperature::celsius(t)
+                }
+                None => temp.expect("required without --temp-log"),
+            };
+            let was = bake.steps.last().map(|s| s.at);
+            let speed = model.relative(bake.params.temp, temp.celsius);
+            bake.adjust(since, speed);
+            bake.params.temp = temp.celsius;
+            or_exit(bake.save());
+            if let (Some(was), Some(bake_at)) = (was, bake.steps.last().map(|s| s.at)) {
+                let by = (bake_at - was).num_minutes();
+                println!(
+                    "Replanned for {}: bake at {} ({} {})",
+                    units::fmt_temp(temp.celsius, temp.unit),
+                    recipe::fmt_hhmm(bake_at),
+                    timer::fmt_remaining(by.abs()),
+                    if by <= 0 { "earlier" } else { "later" }
+                );
+            }
+            let status = bake.status(now, theme::current());
+            print!("{}", theme::glyphs(&status));
+        }
+        Some(Command::Next { done, late }) => {
+            let mut bake = or_exit(bake::Bake::load());
+            let now = Local::now().naive_local();
+            if done && let Some(action) = bake.advance(now) {
+                println!("Done: {action}");
+            }
+            if let Some(m) = late {
+                bake.delay(chrono::Duration::minutes(m));
+            }
+            if done || late.is_some() {
+                or_exit(bake.save());
+            }
+            println!("{}", bake.next_line(now, theme::current()));
+        }
+        Some(Command::Timer(args)) => {
+            let args = or_exit(prepare(args));
+            let recipe = or_exit(plan(&args));
+            history::remember(&args);
+            or_exit(timer::run(&recipe).map_err(|e| e.to_string()));
+        }
     }
+}
 
-    println!(
-        "- Final proof (balls):     {:.1} h{}",
-        tl.proof_h,
-        match t_proof_end {
-            Some(t) => format!(" → ~end at {:02}:{:02}", t.hour(), t.minute()),
-            None => "".to_string(),
+/// Compute the recipe and, with --busy, move its start out of busy times.
+fn plan(args: &Args) -> Result<recipe::Recipe<'_>, String> {
+    let mut r = recipe::compute(args);
+    if args.cost {
+        let prices = config::load()?.prices;
+        let path = config::path().map(|p| p.display().to_string());
+        r.prices = Some(prices.ok_or_else(|| {
+            format!(
+                "--cost needs a [prices] section in {}",
+                path.as_deref().unwrap_or("config.toml")
+            )
+        })?);
+    }
+    if args.busy.is_empty() {
+        return Ok(r);
+    }
+    let busy = calendar::load(&args.busy)?;
+    match calendar::plan_around(&mut r, &busy) {
+        Ok(Some(start)) => eprintln!(
+            "Start moved to {} to keep hands-on steps out of busy times",
+            start.format("%a %H:%M")
+        ),
+        Ok(None) => {}
+        Err(()) => {
+            for (action, b) in calendar::conflicts(&r, &busy) {
+                let warning = format!(
+                    "Warning: \"{action}\" overlaps busy time \"{}\" ({} → {})",
+                    b.summary,
+                    b.start.format("%a %H:%M"),
+                    b.end.format("%a %H:%M")
+                );
+                eprintln!("{}", theme::stderr().paint(Role::Warning, &warning));
+            }
         }
-    );
+    }
+    Ok(r)
+}
 
-    println!(
-        "- Total:                   {:.1} h",
-        tl.bulk_h + tl.fridge_h + tl.warmup_h + tl.proof_h
-    );
+/// Write the effective arguments to a profile.
+fn save_profile(spec: &Path, args: &Args) -> Result<(), String> {
+    let path = profile::write(spec, &Profile::from(args))
+        .map_err(|e| format!("Failed to save profile: {e}"))?;
+    eprintln!("Profile saved to {}", path.display());
+    Ok(())
+}
 
-    println!("\nNotes:");
-    println!("• Yeast amounts are heuristic (Q10≈2/10°C; mild W effect). Fridge counted at configurable factor.");
-    println!("• If dough rises too fast in warm conditions (>27°C), shorten bulk or reduce yeast slightly.");
+/// Full recipe: ingredients, timeline and notes (table or HTML).
+fn calc(args: Args) {
+    show(or_exit(prepare(args)));
+}
 
-    // Save profile at the end if requested (again, to reflect any defaults resolved)
-    if let Some(path) = &args.save_profile {
-        let prof = Profile::from(&args);
-        let _ = fs::write(path, serde_json::to_string_pretty(&prof).unwrap());
+/// Scale the merged recipe by `factor` (if given) and show it; `--save-profile`
+/// stores the scaled recipe.
+fn scale(factor: Option<f64>, mut args: Args) {
+    let save = args.save_profile.take();
+    let mut args = or_exit(prepare(args));
+    if let Some(f) = factor {
+        if f.is_nan() || f <= 0.0 {
+            eprintln!("--factor must be > 0");
+            std::process::exit(1);
+        }
+        let before = args.sizes();
+        let whole = |x: f64| x >= 1.0 && (x - x.round()).abs() < 1e-9;
+        // Scale the counts when they stay whole, otherwise the weights.
+        let scale_counts = before.iter().all(|b| whole(b.count as f64 * f));
+        let after: Vec<BallSpec> = before
+            .iter()
+            .map(|b| match scale_counts {
+                true => BallSpec {
+                    count: (b.count as f64 * f).round() as u32,
+                    ..*b
+                },
+                false => BallSpec {
+                    weight: b.weight * f,
+                    ..*b
+                },
+            })
+            .collect();
+        if args.ball_mix.is_empty() {
+            args.balls = Some(after[0].count);
+            args.ball_weight = Some(after[0].weight);
+        } else {
+            args.ball_mix = after.clone();
+        }
+        let fmt = |sizes: &[BallSpec]| {
+            sizes
+                .iter()
+                .map(|b| format!("{} × {:.0} g", b.count, b.weight))
+                .collect::<Vec<_>>()
+                .join(" + ")
+        };
+        eprintln!("Scaled ×{f}: {} → {}", fmt(&before), fmt(&after));
+    }
+    if let Some(spec) = save {
+        or_exit(save_profile(&spec, &args));
+    }
+    show(args);
+}
+
+/// Write the printable card for `--export`.
+fn export(recipe: &recipe::Recipe, format: &str, path: &Path) -> Result<(), String> {
+    let bytes = match format {
+        "pdf" => pdf::render(recipe),
+        "html" => html::render(recipe).into_bytes(),
+        other => return Err(format!("Unknown export format '{other}' (use pdf or html)")),
+    };
+    std::fs::write(path, bytes).map_err(|e| format!("Cannot write {}: {e}", path.display()))
+}
+
+/// Print the recipe for prepared arguments in the requested format.
+fn show(args: Args) {
+    let recipe = or_exit(plan(&args));
+    if args.check {
+        std::process::exit(check::report(&recipe));
+    }
+    if !args.fields.is_empty() {
+        or_exit(fields::print(&recipe, &args.fields, args.values));
+        history::remember(&args);
+        return;
+    }
+    match args.output {
+        OutputFormat::Table => text::print_recipe(&recipe),
+        OutputFormat::Html => print!("{}", html::render(&recipe)),
+    }
+    if args.qr {
+        println!("{}", or_exit(qr::terminal(&recipe)));
+    }
+    if let Some(path) = &args.qr_png {
+        or_exit(qr::write_png(&recipe, path));
+        eprintln!("QR code saved to {}", path.display());
+    }
+    if let [format, file] = args.export.as_slice() {
+        or_exit(export(&recipe, format, Path::new(file)));
+        eprintln!("Recipe card saved to {file}");
     }
+    history::remember(&args);
 }
@@ -0,0 +1,169 @@
+//! `mcp`: the calculator as a Model Context Protocol server on stdin/stdout
+//! (JSON-RPC 2.0, one message per line), so assistants and editors can call
+//! it as tools. The tools take the recipe flags as arguments
+//! (`{"w": 270, "balls": 4, "malt": true}`) and return the same objects as
+//! `serve`.
+
+use crate::{
+    repl,
+    serve::{self, View},
+};
+use serde_json::{Map, Value, json};
+use std::io::{self, BufRead, Write};
+
+const PROTOCOL_VERSION: &str = "2025-06-18";
+
+const TOOLS: &[(&str, View, &str)] = &[
+    (
+        "ingredients",
+        View::Ingredients,
+        "Pizza dough ingredients in grams (flour, water, salt, yeast) for the given recipe flags",
+    ),
+    (
+        "timeline",
+        View::Timeline,
+        "Fermentation phases in hours and clock times for the given recipe flags",
+    ),
+];
+
+/// JSON schema of the recipe flags, from the clap definitions.
+fn input_schema() -> Value {
+    let mut props = Map::new();
+    for arg in repl::command().get_arguments() {
+        let Some(long) = arg.get_long() else { continue };
+        if long == "help" || serve::refused(long) {
+            continue;
+        }
+        let ty = match arg.get_action().takes_values() {
+            true => json!(["number", "string"]),
+            false => json!("boolean"),
+        };
+        let help = arg.get_help().map(|h| h.to_string()).unwrap_or_default();
+        props.insert(
+            long.replace('-', "_"),
+            json!({ "type": ty, "description": help }),
+        );
+    }
+    json!({ "type": "object", "properties": props })
+}
+
+/// Flags from tool arguments: `true` switches are set, `false` ones left out.
+fn arg_tokens(args: &Map<String, Value>) -> Result<Vec<String>, String> {
+    let pairs = args.iter().filter_map(|(k, v)| match v {
+        Value::Bool(false) | Value::Null => None,
+        Value::String(s) => Some((k.clone(), s.clone())),
+        other => Some((k.clone(), other.to_string())),
+    });
+    serve::flag_tokens(pairs)
+}
+
+fn call_tool(params: &Value) -> Result<Value, (i64, String)> {
+    let name = params["name"].as_str().unwrap_or_default();
+    let Some(&(_, view, _)) = TOOLS.iter().find(|(n, _, _)| *n == name) else {
+        return Err((-32602, format!("unknown tool '{name}'")));
+    };
+    let empty = Map::new();
+    let args = params["arguments"].as_object().unwrap_or(&empty);
+    // Recipe errors are tool results the model can read and correct.
+    Ok(
+        match arg_tokens(args).and_then(|t| serve::compute(view, &t)) {
+            Ok(v) => json!({
+                "content": [{ "type": "text", "text": v.to_string() }],
+                "structuredContent": v,
+                "isError": false,
+            }),
+            Err(e) => json!({
+                "content": [{ "type": "text", "text": e }],
+                "isError": true,
+            }),
+        },
+    )
+}
+
+/// The response to one message, `None` for notifications.
+fn handle(msg: &Value) -> Option<Value> {
+    let id = msg.get("id")?.clone();
+    let params = &msg["params"];
+    let result = match msg["method"].as_str().unwrap_or_default() {
+        "initialize" => Ok(json!({
+            "protocolVersion": params["protocolVersion"].as_str().unwrap_or(PROTOCOL_VERSION),
+            "capabilities": { "tools": {} },
+            "serverInfo": { "name": "pizza-cli", "version": env!("CARGO_PKG_VERSION") },
+        })),
+        "ping" => Ok(json!({})),
+        "tools/list" => {
+            let schema = input_schema();
+            let tools: Vec<Value> = TOOLS
+                .iter()
+                .map(|(name, _, description)| {
+                    json!({ "name": name, "description": description, "inputSchema": schema })
+                })
+                .collect();
+            Ok(json!({ "tools": tools }))
+        }
+        "tools/call" => call_tool(params),
+        other => Err((-32601, format!("method not found: {other}"))),
+    };
+    Some(match result {
+        Ok(r) => json!({ "jsonrpc": "2.0", "id": id, "result": r }),
+        Err((code, message)) => {
+            json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+        }
+    })
+}
+
+/// Answer messages from stdin until it closes.
+pub fn run() -> Result<(), String> {
+    let stdout = io::stdout();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(msg) => handle(&msg),
+            Err(e) => Some(json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": -32700, "message": format!("parse error: {e}") },
+            })),
+        };
+        if let Some(r) = response {
+            let mut out = stdout.lock();
+            writeln!(out, "{r}")
+                .and_then(|_| out.flush())
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_and_calls_tools() {
+        let list = handle(&json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"})).unwrap();
+        let tools = list["result"]["tools"].as_array().unwrap();
+        assert_eq!(tools.len(), 2);
+        let props = &tools[0]["inputSchema"]["properties"];
+        assert_eq!(props["malt"]["type"], "boolean");
+        assert!(props.get("fridge_hours").is_some());
+        assert!(props.get("save_profile").is_none() && props.get("busy").is_none());
+
+        assert!(
+            handle(&json!({"jsonrpc": "2.0", "method": "notifications/initialized"})).is_none()
+        );
+        let err = handle(&json!({"jsonrpc": "2.0", "id": 2, "method": "nope"})).unwrap();
+        assert_eq!(err["error"]["code"], -32601);
+
+        let tokens = arg_tokens(
+            json!({"w": 270, "fridge_hours": 16.5, "malt": true, "per_ball": false})
+                .as_object()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(tokens, ["--w", "270", "--fridge-hours", "16.5", "--malt"]);
+    }
+}
@@ -0,0 +1,363 @@
+//! `--export pdf`: the printable card as an A5 PDF, with the same sections as
+//! the HTML page. Text uses the built-in Helvetica fonts, so nothing is
+//! embedded; characters outside WinAnsi are replaced.
+
+use crate::i18n::tr;
+use crate::recipe::{
+    Recipe, cost_rows, detroit_rows, detroit_title, fmt_effort, fmt_hhmm, fmt_room,
+    ingredient_rows, launch_rows, launches_title, mixing_rows, mixing_title, notes, nutrition_rows,
+    per_ball_rows, phase_rows, sauce_rows, sauce_title, topping_rows, toppings_title, total_hours,
+};
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+
+/// A5 in points.
+const WIDTH: f32 = 419.5;
+const HEIGHT: f32 = 595.3;
+/// 12 mm, as in the HTML `@page` rule.
+const MARGIN: f32 = 34.0;
+/// Accent colour of the headings (#b22).
+const RED: (f32, f32, f32) = (0.73, 0.13, 0.13);
+
+const REGULAR: Name = Name(b"F1");
+const BOLD: Name = Name(b"F2");
+const ITALIC: Name = Name(b"F3");
+
+/// `s` in WinAnsiEncoding: Latin-1 maps directly, a few symbols get an
+/// ASCII stand-in and anything else becomes `?`.
+fn win_ansi(s: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            ' '..='~' | '\u{a0}'..='ÿ' => out.push(c as u8),
+            '€' => out.push(0x80),
+            '…' => out.push(0x85),
+            '‘' => out.push(0x91),
+            '’' => out.push(0x92),
+            '“' => out.push(0x93),
+            '”' => out.push(0x94),
+            '•' => out.push(0x95),
+            '–' => out.push(0x96),
+            '—' => out.push(0x97),
+            '≈' => out.push(b'~'),
+            '→' => out.extend_from_slice(b"->"),
+            '↳' => out.push(b'-'),
+            _ => out.push(b'?'),
+        }
+    }
+    out
+}
+
+/// Greedy word wrap for text of `size` points in `width` points. Helvetica
+/// averages about half an em per character, which is close enough here.
+fn wrap(s: &str, size: f32, width: f32) -> Vec<String> {
+    let max = (width / (size * 0.5)) as usize;
+    let mut lines = Vec::new();
+    let mut cur = String::new();
+    for word in s.split_whitespace() {
+        if !cur.is_empty() && cur.chars().count() + 1 + word.chars().count() > max {
+            lines.push(std::mem::take(&mut cur));
+        }
+        if !cur.is_empty() {
+            cur.push(' ');
+        }
+        cur.push_str(word);
+    }
+    if !cur.is_empty() {
+        lines.push(cur);
+    }
+    lines
+}
+
+/// Pages being laid out top to bottom; `y` is the baseline of the next line.
+struct Card {
+    pages: Vec<Content>,
+    page: Content,
+    y: f32,
+}
+
+impl Card {
+    fn new() -> Self {
+        Card {
+            pages: Vec::new(),
+            page: Content::new(),
+            y: HEIGHT - MARGIN,
+        }
+    }
+
+    /// Start a new page unless `height` more points fit on this one.
+    fn room(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.pages
+                .push(std::mem::replace(&mut self.page, Content::new()));
+            self.y = HEIGHT - MARGIN;
+        }
+    }
+
+    fn text(&mut self, x: f32, font: Name, size: f32, s: &str) {
+        self.page
+            .begin_text()
+            .set_font(font, size)
+            .next_line(x, self.y)
+            .show(Str(&win_ansi(s)))
+            .end_text();
+    }
+
+    /// Like `text`, but ending at `x`.
+    fn text_right(&mut self, x: f32, font: Name, size: f32, s: &str) {
+        let width = s.chars().count() as f32 * size * 0.55;
+        self.text(x - width, font, size, s);
+    }
+
+    fn rule(&mut self, y: f32, width: f32, gray: f32) {
+        self.page
+            .set_stroke_rgb(gray, gray, gray)
+            .set_line_width(width)
+            .move_to(MARGIN, y)
+            .line_to(WIDTH - MARGIN, y)
+            .stroke();
+    }
+
+    fn title(&mut self, s: &str) {
+        self.text(MARGIN, BOLD, 16.0, s);
+        let (r, g, b) = RED;
+        self.page
+            .set_stroke_rgb(r, g, b)
+            .set_line_width(1.5)
+            .move_to(MARGIN, self.y - 6.0)
+            .line_to(WIDTH - MARGIN, self.y - 6.0)
+            .stroke();
+        self.y -= 20.0;
+    }
+
+    fn heading(&mut self, s: &str) {
+        self.room(40.0);
+        self.y -= 10.0;
+        let (r, g, b) = RED;
+        self.page.set_fill_rgb(r, g, b);
+        self.text(MARGIN, BOLD, 12.0, s);
+        self.page.set_fill_gray(0.13);
+        self.y -= 16.0;
+    }
+
+    /// One table row: the first cell at the left, then cells right-aligned
+    /// at `stops`, with room for `extra` lines below it.
+    fn row(&mut self, font: Name, cells: &[&str], stops: &[f32], extra: usize) {
+        self.room(14.0 + 11.0 * extra as f32);
+        self.text(MARGIN + 4.0, font, 10.0, cells[0]);
+        for (cell, x) in cells[1..].iter().zip(stops) {
+            self.text_right(*x, font, 10.0, cell);
+        }
+    }
+
+    fn end_row(&mut self) {
+        self.rule(self.y - 4.0, 0.5, 0.8);
+        self.y -= 14.0;
+    }
+
+    /// A titled table whose first column is a label and the rest amounts.
+    fn grid(&mut self, title: &str, (header, rows): (Vec<String>, Vec<Vec<String>>)) {
+        self.heading(title);
+        let cols = header.len().max(2) - 1;
+        let step = (WIDTH - 2.0 * MARGIN - 130.0) / cols as f32;
+        let stops: Vec<f32> = (1..=cols)
+            .map(|i| MARGIN + 126.0 + step * i as f32)
+            .collect();
+        let header: Vec<&str> = header.iter().map(String::as_str).collect();
+        self.row(BOLD, &header, &stops, 0);
+        self.end_row();
+        for r in &rows {
+            let cells: Vec<&str> = r.iter().map(String::as_str).collect();
+            self.row(REGULAR, &cells, &stops, 0);
+            self.end_row();
+        }
+    }
+
+    fn finish(mut self) -> Vec<Content> {
+        self.pages.push(self.page);
+        self.pages
+    }
+}
+
+/// Render the recipe as a complete PDF document.
+pub fn render(r: &Recipe) -> Vec<u8> {
+    let lang = r.args.lang();
+    let t = |id| lang.text(id);
+    let mut card = Card::new();
+    card.page.set_fill_gray(0.13);
+    card.title(t("recipe-title"));
+
+    card.heading(t("ingredients"));
+    let amount = MARGIN + 190.0;
+    let notes_x = amount + 10.0;
+    let notes_width = WIDTH - MARGIN - notes_x;
+    card.row(BOLD, &[t("col-ingredient"), t("col-amount")], &[amount], 0);
+    card.text(notes_x, BOLD, 10.0, t("col-notes"));
+    card.end_row();
+    for [name, value, note] in ingredient_rows(r) {
+        let lines = wrap(&note, 8.0, notes_width);
+        card.row(
+            REGULAR,
+            &[&name, &value],
+            &[amount],
+            lines.len().saturating_sub(1),
+        );
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                card.y -= 10.0;
+            }
+            card.text(notes_x, REGULAR, 8.0, line);
+        }
+        card.end_row();
+    }
+
+    if r.args.per_ball {
+        card.grid(t("per-ball"), per_ball_rows(r));
+    }
+    if r.args.nutrition {
+        card.grid(t("nutrition-estimate"), nutrition_rows(r));
+    }
+    if let Some(p) = &r.prices {
+        let header = ["cost", "col-amount", "col-price"]
+            .map(|id| t(id).to_string())
+            .to_vec();
+        let rows = cost_rows(r, p).into_iter().map(Vec::from).collect();
+        card.grid(t("cost"), (header, rows));
+    }
+    if r.args.toppings.is_some() {
+        card.grid(&toppings_title(r), topping_rows(r));
+    }
+    if r.args.detroit_pan().is_some() {
+        card.grid(&detroit_title(r), detroit_rows(r));
+    }
+    if r.args.sauce {
+        let header = ["col-ingredient", "col-amount", "col-notes"]
+            .map(|id| lang.text(id).to_string())
+            .to_vec();
+        let rows = sauce_rows(r).into_iter().map(Vec::from).collect();
+        card.grid(&sauce_title(r), (header, rows));
+    }
+    if r.args.kneading().is_some() {
+        card.grid(&mixing_title(r), mixing_rows(r));
+    }
+
+    card.heading(&tr!(lang, "timeline", room = fmt_room(r)));
+    let (hours, ends) = (MARGIN + 250.0, WIDTH - MARGIN - 4.0);
+    card.row(
+        BOLD,
+        &[t("col-phase"), t("col-duration"), t("col-ends")],
+        &[hours, ends],
+        0,
+    );
+    card.end_row();
+    for row in phase_rows(r) {
+        let end = row
+            .end
+            .map(|t| format!("~{}", fmt_hhmm(t)))
+            .unwrap_or_default();
+        let duration = format!("{:.1} h", row.hours);
+        card.row(
+            REGULAR,
+            &[row.label, &duration, &end],
+            &[hours, ends],
+            row.notes.len(),
+        );
+        for n in &row.notes {
+            card.y -= 11.0;
+            card.text(MARGIN + 10.0, ITALIC, 8.5, &format!("↳ {n}"));
+        }
+        card.end_row();
+    }
+    let total = format!("{:.1} h", total_hours(&r.tl));
+    card.row(BOLD, &[t("total"), &total], &[hours], 0);
+    card.end_row();
+    card.row(
+        REGULAR,
+        &[&format!("{}: {}", t("hands-on"), fmt_effort(r))],
+        &[],
+        0,
+    );
+    card.y -= 14.0;
+
+    if r.args.launches {
+        let header = ["col-pizza", "col-in", "col-out"]
+            .map(|id| lang.text(id).to_string())
+            .to_vec();
+        let rows = launch_rows(r).into_iter().map(Vec::from).collect();
+        card.grid(&launches_title(r), (header, rows));
+    }
+
+    card.heading(t("notes"));
+    for note in notes(r) {
+        let lines = wrap(&note, 9.0, WIDTH - 2.0 * MARGIN - 12.0);
+        card.room(11.0 * lines.len() as f32);
+        card.text(MARGIN + 2.0, REGULAR, 9.0, "•");
+        for line in &lines {
+            card.text(MARGIN + 12.0, REGULAR, 9.0, line);
+            card.y -= 11.0;
+        }
+        card.y -= 2.0;
+    }
+
+    let pages = card.finish();
+    let catalog = Ref::new(1);
+    let tree = Ref::new(2);
+    let fonts = [
+        (REGULAR, Ref::new(3), "Helvetica"),
+        (BOLD, Ref::new(4), "Helvetica-Bold"),
+        (ITALIC, Ref::new(5), "Helvetica-Oblique"),
+    ];
+    let page_ids: Vec<Ref> = (0..pages.len() as i32)
+        .map(|i| Ref::new(6 + 2 * i))
+        .collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog).pages(tree);
+    pdf.pages(tree)
+        .kids(page_ids.iter().copied())
+        .count(pages.len() as i32);
+    for (_, id, base) in fonts {
+        pdf.type1_font(id)
+            .base_font(Name(base.as_bytes()))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+    }
+    for (content, id) in pages.into_iter().zip(&page_ids) {
+        let content_id = Ref::new(id.get() + 1);
+        let mut page = pdf.page(*id);
+        page.media_box(Rect::new(0.0, 0.0, WIDTH, HEIGHT))
+            .parent(tree)
+            .contents(content_id);
+        let mut resources = page.resources();
+        let mut pairs = resources.fonts();
+        for (name, font, _) in fonts {
+            pairs.pair(name, font);
+        }
+        pairs.finish();
+        resources.finish();
+        page.finish();
+        pdf.stream(content_id, &content.finish());
+    }
+    pdf.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plan, prepare, repl};
+
+    #[test]
+    fn renders_a_pdf_card() {
+        assert_eq!(win_ansi("≈24 °C → 1½ h"), b"~24 \xb0C -> 1\xbd h");
+        assert_eq!(
+            wrap("one two three four", 10.0, 50.0),
+            ["one two", "three four"]
+        );
+
+        let tokens: Vec<String> = ["--w", "270", "--fridge-hours", "16", "--total-hours", "24"]
+            .map(String::from)
+            .into();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let bytes = render(&plan(&args).unwrap());
+        assert!(bytes.starts_with(b"%PDF-"));
+        assert!(bytes.ends_with(b"%%EOF\n") || bytes.ends_with(b"%%EOF"));
+    }
+}
@@ -0,0 +1,264 @@
+//! Saved parameter profiles: JSON files, addressed by path or by name.
+//!
+//! A bare name (`napoletana-weekend`) refers to
+//! `<config dir>/pizza/profiles/napoletana-weekend.json`, where the config dir
+//! is platform specific (`~/.config` on Linux, `~/Library/Application Support`
+//! on macOS, `%APPDATA%` on Windows). Anything that looks like a path is used as is.
+//!
+//! A profile may start from another one with `"extends": "base"`: keys set in
+//! the profile override the base, notes are appended to the base's notes, and
+//! bases may themselves extend further profiles.
+
+use crate::{
+    Args, BallSpec, BinderFlag, DoughFlag, MixerFlag, ModelFlag, OvenFlag, PhaseNote, PresetFlag,
+    YeastFlag,
+    units::{SaltType, Temperature},
+};
+use chrono::NaiveDate;
+use pizza_core::{FRESH_PER_DRY, pans::Pan, pinsa::FlourBlend};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Profile {
+    pub w: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flour: Option<String>,
+    pub temp: f64,
+    pub yeast: YeastFlag,
+    pub hydration: f64,
+    pub salt_per_kg: f64,
+    pub ball_weight: f64,
+    pub balls: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ball_mix: Vec<BallSpec>,
+    pub total_hours: f64,
+    pub fridge_hours: f64,
+    pub warmup_hours: f64,
+    pub fridge_factor: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fresh_factor: Option<f64>,
+    pub start: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<PhaseNote>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<DoughFlag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binder: Option<BinderFlag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milk: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oil: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sugar: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pan: Option<Pan>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blend: Option<FlourBlend>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub no_knead: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<PresetFlag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<ModelFlag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yeast_pct: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yeast_viability: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yeast_opened: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub malt: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub expert: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt_type: Option<SaltType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt_after: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub levain: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub levain_seed: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub humidity: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oven: Option<OvenFlag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preheat: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bake_minutes: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_minutes: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mixer: Option<MixerFlag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub friction: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ddt: Option<Temperature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flour_temp: Option<Temperature>,
+}
+
+impl From<&Args> for Profile {
+    fn from(a: &Args) -> Self {
+        Profile {
+            w: a.w(),
+            flour: a.flour().map(|f| f.name.to_string()),
+            temp: a.temp(),
+            yeast: a.yeast(),
+            hydration: a.hydration(),
+            salt_per_kg: a.salt_per_kg(),
+            ball_weight: a.ball_weight(),
+            balls: a.balls(),
+            ball_mix: a.ball_mix.clone(),
+            total_hours: a.total_hours(),
+            fridge_hours: a.fridge_hours(),
+            warmup_hours: a.warmup_hours(),
+            fridge_factor: a.fridge_factor(),
+            fresh_factor: (a.fresh_factor() != FRESH_PER_DRY).then(|| a.fresh_factor()),
+            start: a.start.clone(),
+            notes: a.notes.clone(),
+            style: a.style,
+            binder: a.binder,
+            milk: a.milk,
+            oil: a.oil,
+            sugar: a.sugar,
+            pan: a.pan,
+            blend: a.blend,
+            no_knead: a.no_knead,
+            preset: a.preset,
+            model: (a.model() != ModelFlag::default()).then_some(a.model()),
+            yeast_pct: a.yeast_pct,
+            yeast_viability: a.yeast_viability,
+            yeast_opened: a.yeast_opened,
+            malt: a.malt,
+            expert: a.expert,
+            salt_type: (a.salt_type() != SaltType::default()).then_some(a.salt_type()),
+            salt_after: a.salt_after,
+            levain: a.levain,
+            levain_seed: a.levain_seed,
+            humidity: a.humidity,
+            oven: a.oven,
+            preheat: a.preheat,
+            bake_minutes: a.bake_minutes,
+            recovery_minutes: a.recovery_minutes,
+            mixer: a.mixer,
+            friction: a.friction,
+            ddt: a.ddt,
+            flour_temp: a.flour_temp,
+        }
+    }
+}
+
+/// Directory holding named profiles.
+pub fn dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("pizza").join("profiles"))
+}
+
+/// Resolve a profile argument: bare names live in [`dir`], anything else is a path.
+pub fn resolve(spec: &Path) -> PathBuf {
+    let is_name = spec.components().count() == 1 && spec.extension().is_none();
+    match dir() {
+        Some(d) if is_name => d.join(spec).with_extension("json"),
+        _ => spec.to_path_buf(),
+    }
+}
+
+/// Read a profile file as JSON with its `extends` chain merged in.
+fn read_merged(path: &Path, seen: &mut Vec<PathBuf>) -> Result<Map<String, Value>, String> {
+    if seen.iter().any(|p| p == path) {
+        return Err(format!("Profile extends itself: {}", path.display()));
+    }
+    seen.push(path.to_path_buf());
+    let txt = fs::read_to_string(path)
+        .map_err(|_| format!("Failed to read profile: {}", path.display()))?;
+    let Ok(Value::Object(mut own)) = serde_json::from_str(&txt) else {
+        return Err(format!("Invalid profile JSON: {}", path.display()));
+    };
+    let Some(base) = own.remove("extends") else {
+        return Ok(own);
+    };
+    let Value::String(base) = base else {
+        return Err(format!(
+            "`extends` must be a profile name: {}",
+            path.display()
+        ));
+    };
+    // Relative paths are relative to the extending profile.
+    let mut base_path = resolve(Path::new(&base));
+    if base_path.is_relative()
+        && let Some(dir) = path.parent()
+    {
+        base_path = dir.join(base_path);
+    }
+    let mut merged = read_merged(&base_path, seen)?;
+    for (key, value) in own {
+        match (key.as_str(), merged.get_mut(&key), value) {
+            ("notes", Some(Value::Array(notes)), Value::Array(more)) => notes.extend(more),
+            (_, _, value) => {
+                merged.insert(key, value);
+            }
+        }
+    }
+    Ok(merged)
+}
+
+/// Load a profile, resolving `extends`.
+pub fn read(spec: &Path) -> Result<Profile, String> {
+    let path = resolve(spec);
+    let merged = read_merged(&path, &mut Vec::new())?;
+    serde_json::from_value(Value::Object(merged))
+        .map_err(|e| format!("Invalid profile {}: {e}", path.display()))
+}
+
+/// Write a profile, creating the profiles directory for named profiles.
+/// Returns the path written.
+pub fn write(spec: &Path, p: &Profile) -> io::Result<PathBuf> {
+    let path = resolve(spec);
+    if let Some(parent) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(p).unwrap())?;
+    Ok(path)
+}
+
+/// Names of the profiles stored in [`dir`], sorted.
+pub fn list() -> io::Result<Vec<String>> {
+    let Some(d) = dir().filter(|d| d.is_dir()) else {
+        return Ok(Vec::new());
+    };
+    let mut names: Vec<String> = fs::read_dir(d)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|x| x == "json"))
+        .filter_map(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Delete a profile. Returns the path removed.
+pub fn delete(spec: &Path) -> io::Result<PathBuf> {
+    let path = resolve(spec);
+    fs::remove_file(&path)?;
+    Ok(path)
+}
+
+/// Rename a profile, refusing to overwrite an existing one. Returns the new path.
+pub fn rename(from: &Path, to: &Path) -> io::Result<PathBuf> {
+    let (src, dst) = (resolve(from), resolve(to));
+    if dst.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dst.display()),
+        ));
+    }
+    if let Some(parent) = dst.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&src, &dst)?;
+    Ok(dst)
+}
@@ -0,0 +1,136 @@
+//! `--qr` / `--qr-png`: the plan as a QR code to scan with a phone. It holds
+//! plain text (ingredients, clock times and the flags to recompute it), so
+//! any camera app shows it without pizza-cli installed.
+
+use crate::recipe::{Recipe, fmt_hhmm, ingredient_rows, phase_rows};
+use clap::ValueEnum;
+use qrcode::{Color, EcLevel, QrCode, render::unicode::Dense1x2};
+use std::{fs::File, io::BufWriter, path::Path};
+
+/// Pixels per module in the PNG.
+const PNG_SCALE: usize = 8;
+/// Light border around the code, in modules (the standard asks for 4).
+const QUIET_ZONE: usize = 4;
+
+/// The flags that reproduce the recipe with `pizza-cli`.
+fn flags(r: &Recipe) -> String {
+    let a = r.args;
+    let mut out = format!(
+        "pizza-cli --w {} --temp {} --yeast {} --hydration {} --salt-per-kg {}",
+        a.w(),
+        a.temp(),
+        a.yeast().to_possible_value().unwrap().get_name(),
+        a.hydration(),
+        a.salt_per_kg()
+    );
+    match a.ball_mix.as_slice() {
+        [] => out.push_str(&format!(
+            " --ball-weight {} --balls {}",
+            a.ball_weight(),
+            a.balls()
+        )),
+        mix => {
+            for b in mix {
+                out.push_str(&format!(" --ball {}x{}", b.weight, b.count));
+            }
+        }
+    }
+    out.push_str(&format!(" --total-hours {}", a.total_hours()));
+    if a.fridge_hours() > 0.0 {
+        out.push_str(&format!(
+            " --fridge-hours {} --warmup-hours {}",
+            a.fridge_hours(),
+            a.warmup_hours()
+        ));
+    }
+    out
+}
+
+/// What the code says when scanned.
+pub fn text(r: &Recipe) -> String {
+    let mut out = format!("{}\n", r.args.lang().text("recipe-title"));
+    for [name, amount, _] in ingredient_rows(r) {
+        out.push_str(&format!("{name}: {amount}\n"));
+    }
+    if let Some(start) = r.start {
+        let mix = r.args.lang().text("action-mix");
+        out.push_str(&format!("{} {mix}\n", fmt_hhmm(start)));
+    }
+    for row in phase_rows(r) {
+        if let Some(end) = row.end {
+            out.push_str(&format!("{} {}\n", fmt_hhmm(end), row.action));
+        }
+    }
+    out.push_str(&flags(r));
+    out
+}
+
+fn code(r: &Recipe) -> Result<QrCode, String> {
+    QrCode::with_error_correction_level(text(r), EcLevel::L)
+        .map_err(|e| format!("Cannot make a QR code: {e}"))
+}
+
+/// The code in half-block characters, light on dark so it scans from a
+/// dark terminal.
+pub fn terminal(r: &Recipe) -> Result<String, String> {
+    Ok(code(r)?
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .quiet_zone(true)
+        .build())
+}
+
+/// Write the code as a black-on-white PNG.
+pub fn write_png(r: &Recipe, path: &Path) -> Result<(), String> {
+    let code = code(r)?;
+    let modules = code.width();
+    let colors = code.to_colors();
+    let side = (modules + 2 * QUIET_ZONE) * PNG_SCALE;
+    let mut pixels = vec![255u8; side * side];
+    for (i, c) in colors.iter().enumerate() {
+        if *c == Color::Light {
+            continue;
+        }
+        let (x0, y0) = (
+            (i % modules + QUIET_ZONE) * PNG_SCALE,
+            (i / modules + QUIET_ZONE) * PNG_SCALE,
+        );
+        for y in y0..y0 + PNG_SCALE {
+            pixels[y * side + x0..y * side + x0 + PNG_SCALE].fill(0);
+        }
+    }
+
+    let err = |e: &dyn std::fmt::Display| format!("Cannot write {}: {e}", path.display());
+    let file = File::create(path).map_err(|e| err(&e))?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), side as u32, side as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut w| w.write_image_data(&pixels))
+        .map_err(|e| err(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plan, prepare, repl};
+
+    #[test]
+    fn flags_reproduce_the_recipe() {
+        let tokens: Vec<String> =
+            "--w 270 --ball 280x2 --ball 450x1 --total-hours 24 --fridge-hours 16"
+                .split(' ')
+                .map(String::from)
+                .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let r = plan(&args).unwrap();
+        let text = text(&r);
+        let last = text.lines().last().unwrap();
+        let again: Vec<String> = last.split(' ').skip(1).map(String::from).collect();
+        let args2 = prepare(repl::parse(&again).unwrap()).unwrap();
+        assert_eq!(plan(&args2).unwrap().ing, r.ing);
+        assert!(terminal(&r).is_ok());
+    }
+}
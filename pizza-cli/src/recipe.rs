@@ -0,0 +1,1572 @@
+//! Computed recipe (ingredients + timeline + clock times) shared by all renderers.
+
+use crate::{
+    Args, COLD_FERMENT_ROOM_HOURS, DoughFlag, ModelFlag, PhaseKind, PresetFlag, StyleFlag,
+    YeastFlag,
+    config::Prices,
+    i18n::tr,
+    units::{
+        DRY_YEAST_G_PER_TSP, FRESH_YEAST_G_PER_TSP, MALT_G_PER_TSP, SaltType, fmt_length,
+        fmt_rough_range, fmt_rough_weight, fmt_spoons, fmt_temp, fmt_temp_delta, fmt_weight,
+    },
+};
+use chrono::{Local, NaiveDateTime, NaiveTime, Timelike};
+use pizza_core::{
+    Ingredients, MALT_MIN_FRIDGE_HOURS, PlanInput, PlanWarning, SmallYeastAdvice, Timeline,
+    ddt::{self, Mixer, WaterPrep},
+    gluten_free::{self, Binder},
+    humidity_hydration_delta,
+    kneading::{self, Stage},
+    max_room_hours,
+    model::{
+        DEFAULT_ACTIVATION_ENERGY_KJ, REFERENCE_TEMP_C, YEAST_PERCENT_DRY_RANGE, YeastFactors,
+        yeast_factors,
+    },
+    no_knead,
+    nutrition::{Nutrients, dough_nutrition},
+    oven::launch_offsets,
+    pans::{self, PanLoad},
+    small_yeast_advice,
+    starter::{BuildStage, levain_build},
+    suggested_malt_pct, timeline_effective_hours,
+    toppings::{Style, TOMATO_TIN_G, Toppings, sauce, toppings},
+};
+use std::borrow::Cow;
+
+/// Clock time at which each phase ends (if a start time is known).
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PhaseEnds {
+    pub bulk: Option<NaiveDateTime>,
+    pub fridge: Option<NaiveDateTime>,
+    pub warmup: Option<NaiveDateTime>,
+    pub proof: Option<NaiveDateTime>,
+}
+
+/// A fully computed recipe, ready to be rendered.
+pub struct Recipe<'a> {
+    pub args: &'a Args,
+    pub ing: Ingredients,
+    pub tl: Timeline,
+    pub start: Option<NaiveDateTime>,
+    pub ends: PhaseEnds,
+    /// Set when the yeast is below `--min-yeast`.
+    pub small_yeast: Option<SmallYeastAdvice>,
+    /// Water temperature (°C) and how to prepare it, when requested or in hot kitchens.
+    pub water: Option<(f64, WaterPrep)>,
+    /// The model's yeast (grams) when `--yeast-pct` overrides it.
+    pub model_yeast_g: Option<f64>,
+    /// Ingredient prices from config.toml (only loaded for `--cost`).
+    pub prices: Option<Prices>,
+    /// What may not turn out well, e.g. hours that do not suit the flour.
+    pub plan_warnings: Vec<PlanWarning>,
+}
+
+/// One line of the timeline: label, duration in hours, optional end time and notes.
+pub struct PhaseRow<'a> {
+    pub label: &'static str,
+    /// What to do when the phase ends.
+    pub action: &'static str,
+    pub hours: f64,
+    pub end: Option<NaiveDateTime>,
+    /// `--note`s for the phase, then topping prep falling within it.
+    pub notes: Vec<Cow<'a, str>>,
+}
+
+/// Minutes of hands-on work at the start (mixing/kneading).
+pub const MIX_MIN: i64 = 20;
+/// Minutes of hands-on work for a no-knead dough: stirring and the folds.
+pub const NO_KNEAD_MIX_MIN: i64 = 10;
+/// Minutes of hands-on work at each phase boundary.
+pub const ACTION_MIN: i64 = 15;
+
+/// Whether a step needs the baker or only time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Activity {
+    HandsOn,
+    Passive,
+}
+
+/// One step of the process: mixing, each phase, and the action ending it.
+pub struct Step {
+    pub label: &'static str,
+    pub activity: Activity,
+    pub minutes: i64,
+    pub start: Option<NaiveDateTime>,
+}
+
+/// Room temperature in the unit the user gave it (marked when from a
+/// schedule or a log).
+pub fn fmt_room(r: &Recipe) -> String {
+    let t = fmt_temp(r.args.temp(), r.args.temp_units());
+    match (&r.args.temp_schedule, &r.args.temp_log) {
+        (Some(_), _) => tr!(r.args.lang(), "room-by-schedule", temp = t),
+        (_, Some(_)) => tr!(r.args.lang(), "room-by-log", temp = t),
+        _ => t,
+    }
+}
+
+/// Notes that need acting on before mixing: yeast too small to weigh, a
+/// hot kitchen. They come first in [`notes`].
+pub fn warnings(r: &Recipe) -> Vec<String> {
+    let lang = r.args.lang();
+    let mut notes = Vec::new();
+    for w in &r.plan_warnings {
+        let pct = |x: f64| format!("{:.0}", x * 100.0);
+        notes.push(match *w {
+            PlanWarning::Hydration {
+                hydration,
+                min,
+                max,
+            } => tr!(
+                lang,
+                "warn-hydration",
+                hydration = pct(hydration),
+                min = pct(min),
+                max = pct(max)
+            ),
+            PlanWarning::ShortForW {
+                w,
+                total_h,
+                min_h,
+                max_h,
+            } => tr!(
+                lang,
+                "warn-short-for-w",
+                hours = total_h,
+                w = w,
+                min = min_h,
+                max = max_h
+            ),
+            PlanWarning::LongForW {
+                w,
+                total_h,
+                min_h,
+                max_h,
+            } => tr!(
+                lang,
+                "warn-long-for-w",
+                hours = total_h,
+                w = w,
+                min = min_h,
+                max = max_h
+            ),
+        });
+    }
+    if r.args.too_long_at_room() {
+        let (total, room) = (r.args.total_hours(), COLD_FERMENT_ROOM_HOURS);
+        let max = max_room_hours(r.args.model().model().as_ref(), r.args.temp());
+        // Too short to take a fridge block: shorten it instead.
+        notes.push(match r.args.fridge_fits() {
+            true => tr!(
+                lang,
+                "note-fridge-suggested",
+                hours = total,
+                room = fmt_room(r),
+                max = format!("{max:.0}"),
+                fridge = total - room,
+                warmup = r.args.warmup_hours()
+            ),
+            false => tr!(
+                lang,
+                "note-shorter-suggested",
+                hours = total,
+                room = fmt_room(r),
+                max = format!("{:.0}", max.floor())
+            ),
+        });
+    }
+    if let Some(a) = &r.small_yeast {
+        let d = a.dilution;
+        notes.push(tr!(
+            lang,
+            "note-small-yeast",
+            yeast = fmt_g(a.yeast_g),
+            min = fmt_g(r.args.min_yeast),
+            dissolve = fmt_g(d.dissolve_g),
+            water = format!("{:.0}", d.water_ml),
+            use = format!("{:.0}", d.use_ml)
+        ));
+        if let Some((hours, grams)) = a.reschedule {
+            notes.push(tr!(
+                lang,
+                "note-reschedule",
+                hours = hours,
+                yeast = fmt_g(grams)
+            ));
+        }
+    }
+    if r.args.temp() >= ddt::HOT_ROOM_C
+        && let Some((_, prep)) = r.water
+    {
+        let (u, t) = (r.args.units, r.args.temp_units());
+        let how = match prep {
+            WaterPrep::Tap => None,
+            WaterPrep::Chilled { chilled_g, .. } => {
+                Some(tr!(lang, "hot-chilled", chilled = fmt_weight(chilled_g, u)))
+            }
+            WaterPrep::Iced { ice_g, .. } => {
+                Some(tr!(lang, "hot-iced", ice = fmt_weight(ice_g, u)))
+            }
+            WaterPrep::AllIce { ice_g } => {
+                Some(tr!(lang, "hot-all-ice", ice = fmt_weight(ice_g, u)))
+            }
+        };
+        if let Some(how) = how {
+            notes.push(tr!(
+                lang,
+                "note-hot-kitchen",
+                room = fmt_room(r),
+                how = how,
+                ddt = fmt_temp(r.args.ddt.map_or(ddt::DEFAULT_DDT_C, |d| d.celsius), t)
+            ));
+        }
+    }
+    notes
+}
+
+/// General notes printed under every recipe.
+pub fn notes(r: &Recipe) -> Vec<String> {
+    let u = r.args.temp_units();
+    let lang = r.args.lang();
+    let mut notes = warnings(r);
+    let emergency = r.args.preset == Some(PresetFlag::Emergency);
+    if emergency {
+        notes.push(tr!(
+            lang,
+            "note-emergency",
+            pct = r.args.yeast_pct.unwrap_or_default(),
+            ddt = fmt_temp(r.args.ddt.map_or(ddt::DEFAULT_DDT_C, |d| d.celsius), u)
+        ));
+    }
+    if let (Some(pct), Some(model_g), false) = (r.args.yeast_pct, r.model_yeast_g, emergency) {
+        notes.push(tr!(
+            lang,
+            "note-yeast-fixed",
+            pct = pct,
+            model = format!("{:.2}", model_g / r.ing.flour_g * 100.0),
+            grams = fmt_g(model_g)
+        ));
+    }
+    let malt_pct = suggested_malt_pct(r.args.fridge_hours());
+    match (r.args.malt, malt_pct) {
+        (true, None) => notes.push(tr!(lang, "note-no-malt", hours = MALT_MIN_FRIDGE_HOURS)),
+        (false, Some(pct)) => {
+            notes.push(tr!(lang, "note-malt", pct = format!("{:.1}", pct * 100.0)))
+        }
+        _ => {}
+    }
+    if let Some(g) = r.args.salt_type().guidance(lang) {
+        notes.push(g.to_string());
+    }
+    if r.args.gluten_free().is_some() {
+        notes.push(lang.text("note-gluten-free").to_string());
+    }
+    if r.args.no_knead {
+        notes.push(lang.text("note-no-knead").to_string());
+    }
+    match r.args.style {
+        Some(DoughFlag::Pan) => notes.push(lang.text("note-pan").to_string()),
+        Some(DoughFlag::Detroit) => notes.push(lang.text("note-detroit").to_string()),
+        Some(DoughFlag::Pinsa) => notes.push(lang.text("note-pinsa").to_string()),
+        Some(DoughFlag::NewYork) => notes.push(tr!(
+            lang,
+            "note-new-york",
+            low = fmt_temp(280.0, r.args.temp_units()),
+            high = fmt_temp(300.0, r.args.temp_units()),
+            minutes = r.args.bake_cycle().0,
+            room = COLD_FERMENT_ROOM_HOURS
+        )),
+        _ => {}
+    }
+    if let Some(rh) = r.args.humidity {
+        let delta = humidity_hydration_delta(rh);
+        let range = plan_input(r.args).hydration_range();
+        let suggested = (r.args.hydration() + delta).clamp(*range.start(), *range.end());
+        let suggested = format!("{suggested:.2}");
+        let (rh, pct) = (format!("{rh:.0}"), format!("{:.0}", delta.abs() * 100.0));
+        if delta > 0.0 {
+            notes.push(tr!(
+                lang,
+                "note-dry-air",
+                rh = rh,
+                pct = pct,
+                hydration = suggested
+            ));
+        } else if delta < 0.0 {
+            notes.push(tr!(
+                lang,
+                "note-humid-air",
+                rh = rh,
+                pct = pct,
+                hydration = suggested
+            ));
+        }
+    }
+    notes.extend([
+        match r.args.model() {
+            ModelFlag::Q10 => tr!(lang, "note-model-q10", delta = fmt_temp_delta(10.0, u)),
+            ModelFlag::Arrhenius => tr!(
+                lang,
+                "note-model-arrhenius",
+                ea = DEFAULT_ACTIVATION_ENERGY_KJ
+            ),
+        },
+        tr!(lang, "note-warm", temp = fmt_temp(27.0, u)),
+    ]);
+    notes
+}
+
+pub fn fmt_g(x: f64) -> String {
+    let v = (x * 10.0).round() / 10.0;
+    if (v - v.round()).abs() < 1e-9 {
+        format!("{:.0} g", v)
+    } else {
+        format!("{:.1} g", v)
+    }
+}
+
+pub fn fmt_hhmm(t: NaiveDateTime) -> String {
+    format!("{:02}:{:02}", t.hour(), t.minute())
+}
+
+pub fn total_hours(tl: &Timeline) -> f64 {
+    tl.bulk_h + tl.fridge_h + tl.warmup_h + tl.proof_h
+}
+
+/// Core input for merged arguments.
+pub fn plan_input(args: &Args) -> PlanInput {
+    PlanInput {
+        total_dough_g: args.total_dough(),
+        hydration: args.hydration(),
+        salt_per_kg: args.salt_per_kg(),
+        yeast: args.yeast().into(),
+        temp_c: args.temp(),
+        w: args.w(),
+        total_hours: args.total_hours(),
+        fridge_hours: args.fridge_hours(),
+        warmup_hours: args.warmup_hours(),
+        fridge_factor: args.fridge_factor(),
+        viability: args.viability(),
+        fresh_per_dry: args.fresh_factor(),
+        malt_pct: match args.malt {
+            true => suggested_malt_pct(args.fridge_hours()).unwrap_or(0.0),
+            false => 0.0,
+        },
+        model: args.model().model_for(args.no_knead),
+        yeast_pct: args.yeast_pct.map(|pct| pct / 100.0),
+        split: args.no_knead.then(no_knead::split_policy),
+        binder: args.gluten_free(),
+        blend: args.pinsa(),
+        enrichment: args.enrichment(),
+    }
+}
+
+/// Compute ingredients, timeline and phase end times for validated arguments.
+pub fn compute(args: &Args) -> Recipe<'_> {
+    // Phases first, then yeast from the realized phases
+    let input = plan_input(args);
+    let plan = pizza_core::plan(input.clone());
+    let small_yeast = small_yeast_advice(&input, &plan, args.min_yeast);
+    let plan_warnings = input.warnings();
+    let model_yeast_g = input.yeast_pct.map(|_| {
+        pizza_core::plan(PlanInput {
+            yeast_pct: None,
+            ..input.clone()
+        })
+        .ingredients
+        .yeast_g
+    });
+    let (ing, tl) = (plan.ingredients, plan.timeline);
+
+    // Water temperature for the desired dough temperature: on request, and
+    // always in a hot kitchen. Tap water is taken to be at room temperature.
+    let water = (args.ddt_requested() || args.temp() >= ddt::HOT_ROOM_C).then(|| {
+        let room = args.temp();
+        let target = ddt::water_temp(
+            args.ddt.map_or(ddt::DEFAULT_DDT_C, |t| t.celsius),
+            args.flour_temp.map_or(room, |t| t.celsius),
+            room,
+            args.friction(),
+        );
+        (target, ddt::water_prep(ing.water_g, room, target))
+    });
+
+    // Start time and phase ends
+    let start_time = if let Some(hhmm) = args.start.as_ref() {
+        NaiveTime::parse_from_str(hhmm, "%H:%M").ok()
+    } else {
+        Some(Local::now().naive_local().time())
+    };
+    let start = start_time.map(|st| Local::now().date_naive().and_time(st));
+
+    let ends = start.map(|s| schedule(&tl, s)).unwrap_or_default();
+
+    Recipe {
+        args,
+        ing,
+        tl,
+        start,
+        ends,
+        small_yeast,
+        water,
+        model_yeast_g,
+        plan_warnings,
+        prices: None,
+    }
+}
+
+/// Phase end times for a timeline starting at `start`.
+pub fn schedule(tl: &Timeline, start: NaiveDateTime) -> PhaseEnds {
+    let to_min = |h: f64| (h * 60.0).round() as i64;
+    let mut dt = start;
+
+    let bulk_end = dt + chrono::Duration::minutes(to_min(tl.bulk_h));
+    dt = bulk_end;
+
+    let fridge_end = if tl.fridge_h > 0.0 {
+        let e = dt + chrono::Duration::minutes(to_min(tl.fridge_h));
+        dt = e;
+        Some(e)
+    } else {
+        None
+    };
+
+    let warmup_end = if tl.warmup_h > 0.0 {
+        let e = dt + chrono::Duration::minutes(to_min(tl.warmup_h));
+        dt = e;
+        Some(e)
+    } else {
+        None
+    };
+
+    let proof_end = dt + chrono::Duration::minutes(to_min(tl.proof_h));
+    PhaseEnds {
+        bulk: Some(bulk_end),
+        fridge: fridge_end,
+        warmup: warmup_end,
+        proof: Some(proof_end),
+    }
+}
+
+impl Recipe<'_> {
+    /// Move the whole plan to a new start time.
+    pub fn reschedule(&mut self, start: NaiveDateTime) {
+        self.start = Some(start);
+        self.ends = schedule(&self.tl, start);
+    }
+}
+
+/// "Flour", or "Gluten-free flour blend".
+fn flour_label(a: &Args) -> &'static str {
+    a.lang().text(match (a.gluten_free(), a.pinsa()) {
+        (Some(_), _) => "ing-gf-blend",
+        (None, Some(_)) => "ing-wheat-flour",
+        (None, None) => "ing-flour",
+    })
+}
+
+/// Ingredient rows as (ingredient, amount, notes), shared by all renderers.
+pub fn ingredient_rows(r: &Recipe) -> Vec<[String; 3]> {
+    let a = r.args;
+    let u = a.units;
+    let lang = a.lang();
+    let mut rows = vec![
+        [
+            lang.text("ing-balls").to_string(),
+            a.sizes()
+                .iter()
+                .map(|b| format!("{} × {}", b.count, fmt_weight(b.weight, u)))
+                .collect::<Vec<_>>()
+                .join(" + "),
+            match a.sizes().len() {
+                1 => String::new(),
+                _ => tr!(lang, "balls-split", dough = fmt_weight(a.total_dough(), u)),
+            },
+        ],
+        [
+            flour_label(a).to_string(),
+            fmt_weight(r.ing.flour_g, u),
+            flour_note(r),
+        ],
+        [
+            lang.text("ing-water").to_string(),
+            fmt_weight(r.ing.water_g, u),
+            match a.gluten_free() {
+                Some(b) if b.water_per_g() > 0.0 => tr!(
+                    lang,
+                    "water-binder",
+                    water = fmt_weight(r.ing.binder_g * b.water_per_g(), u)
+                ),
+                _ => String::new(),
+            },
+        ],
+        [
+            lang.text("ing-salt").to_string(),
+            fmt_spoons(r.ing.salt_g, a.salt_type().g_per_tsp(), u),
+            {
+                let per_kg = a.salt_per_kg();
+                let base = format!("{per_kg:.1} g/kg ({:.2}%)", per_kg / 10.0);
+                let base = match a.salt_type() {
+                    SaltType::Fine => base,
+                    SaltType::Coarse => tr!(lang, "salt-coarse", base = base),
+                    SaltType::Flaky => tr!(lang, "salt-flaky", base = base),
+                };
+                match a.salt_after {
+                    Some(min) => tr!(lang, "salt-delayed", base = base, minutes = min),
+                    None => base,
+                }
+            },
+        ],
+    ];
+    rows.push(match a.yeast() {
+        YeastFlag::Dry => [
+            lang.text("ing-dry-yeast").to_string(),
+            fmt_spoons(r.ing.yeast_g, DRY_YEAST_G_PER_TSP, u),
+            yeast_note(a, lang.text("yeast-estimate")),
+        ],
+        YeastFlag::Fresh => [
+            lang.text("ing-fresh-yeast").to_string(),
+            fmt_spoons(r.ing.yeast_g, FRESH_YEAST_G_PER_TSP, u),
+            yeast_note(a, &tr!(lang, "yeast-per-dry", factor = a.fresh_factor())),
+        ],
+    });
+    if r.ing.malt_g > 0.0 {
+        rows.push([
+            lang.text("ing-malt").to_string(),
+            fmt_spoons(r.ing.malt_g, MALT_G_PER_TSP, u),
+            tr!(
+                lang,
+                "malt-share",
+                pct = format!("{:.1}", r.ing.malt_g / r.ing.flour_g * 100.0)
+            ),
+        ]);
+    }
+    // Baker's percentages are of all the flour, rice and soy included.
+    let flour = r.ing.flour_g + r.ing.rice_g + r.ing.soy_g;
+    let share = |g: f64| {
+        tr!(
+            lang,
+            "pct-of-flour",
+            pct = format!("{:.1}", g / flour * 100.0)
+        )
+    };
+
+    if r.ing.milk_g > 0.0 {
+        rows.insert(
+            3,
+            [
+                lang.text("ing-milk").to_string(),
+                fmt_weight(r.ing.milk_g, u),
+                tr!(
+                    lang,
+                    "milk-share",
+                    pct = format!("{:.0}", a.enrichment().milk_share * 100.0)
+                ),
+            ],
+        );
+    }
+    if r.ing.oil_g > 0.0 {
+        rows.push([
+            lang.text("ing-oil").to_string(),
+            fmt_weight(r.ing.oil_g, u),
+            share(r.ing.oil_g),
+        ]);
+    }
+    if r.ing.sugar_g > 0.0 {
+        rows.push([
+            lang.text("ing-sugar").to_string(),
+            fmt_weight(r.ing.sugar_g, u),
+            share(r.ing.sugar_g),
+        ]);
+    }
+    if let Some(b) = a.gluten_free() {
+        rows.push([
+            lang.text(match b {
+                Binder::Psyllium => "ing-psyllium",
+                Binder::Xanthan => "ing-xanthan",
+            })
+            .to_string(),
+            fmt_weight(r.ing.binder_g, u),
+            tr!(
+                lang,
+                "binder-share",
+                pct = format!("{:.0}", b.share() * 100.0)
+            ),
+        ]);
+    }
+    if let Some(row) = water_temp_row(r) {
+        rows.insert(3, row);
+    }
+    // The rest of a pinsa blend goes right under the wheat flour.
+    if a.pinsa().is_some() {
+        let blend = [
+            ("ing-rice-flour", r.ing.rice_g),
+            ("ing-soy-flour", r.ing.soy_g),
+        ];
+        for (i, (id, g)) in blend.into_iter().enumerate() {
+            rows.insert(
+                2 + i,
+                [lang.text(id).to_string(), fmt_weight(g, u), share(g)],
+            );
+        }
+    }
+    rows
+}
+
+/// Yeast row note; mentions a fixed percentage or the scaling for yeast
+/// that is not fully active.
+fn yeast_note(a: &Args, base: &str) -> String {
+    let v = a.viability();
+    let lang = a.lang();
+    if let Some(pct) = a.yeast_pct {
+        tr!(lang, "yeast-fixed", pct = pct)
+    } else if v < 1.0 {
+        tr!(
+            lang,
+            "yeast-viability",
+            base = base,
+            scale = format!("{:.2}", 1.0 / v),
+            viability = format!("{:.0}", v * 100.0)
+        )
+    } else {
+        base.to_string()
+    }
+}
+
+/// Per-ball breakdown: a header (one column per ball size) and rows of
+/// (ingredient, amount per ball of each size).
+pub fn per_ball_rows(r: &Recipe) -> (Vec<String>, Vec<Vec<String>>) {
+    let a = r.args;
+    let u = a.units;
+    let lang = a.lang();
+    let sizes = a.sizes();
+    let total = a.total_dough();
+    let mut header = vec![lang.text("per-ball").to_string()];
+    header.extend(
+        sizes
+            .iter()
+            .map(|b| tr!(lang, "ball-size", weight = fmt_weight(b.weight, u))),
+    );
+    let yeast_tsp = match a.yeast() {
+        YeastFlag::Dry => DRY_YEAST_G_PER_TSP,
+        YeastFlag::Fresh => FRESH_YEAST_G_PER_TSP,
+    };
+    let items: [(&str, f64, Option<f64>); 4] = [
+        (flour_label(a), r.ing.flour_g, None),
+        (lang.text("ing-water"), r.ing.water_g, None),
+        (
+            lang.text("ing-salt"),
+            r.ing.salt_g,
+            Some(a.salt_type().g_per_tsp()),
+        ),
+        (lang.text("ing-yeast"), r.ing.yeast_g, Some(yeast_tsp)),
+    ];
+    let rows = items
+        .iter()
+        .map(|&(name, grams, spoons)| {
+            let mut row = vec![name.to_string()];
+            row.extend(sizes.iter().map(|b| {
+                let g = grams * b.weight / total;
+                match spoons {
+                    Some(per_tsp) => fmt_spoons(g, per_tsp, u),
+                    None => fmt_weight(g, u),
+                }
+            }));
+            row
+        })
+        .collect();
+    (header, rows)
+}
+
+/// Nutrition table: a header (per ball of each size, per 100 g) and rows of
+/// (nutrient, values).
+pub fn nutrition_rows(r: &Recipe) -> (Vec<String>, Vec<Vec<String>>) {
+    let a = r.args;
+    let lang = a.lang();
+    let total = a.total_dough();
+    let batch = dough_nutrition(&r.ing, a.yeast().into());
+    let mut columns: Vec<(String, f64)> = a
+        .sizes()
+        .iter()
+        .map(|b| {
+            let weight = fmt_weight(b.weight, a.units);
+            (tr!(lang, "ball-size", weight = weight), b.weight)
+        })
+        .collect();
+    columns.push((lang.text("per-100g").to_string(), 100.0));
+
+    let mut header = vec![lang.text("nutrition").to_string()];
+    header.extend(columns.iter().map(|(name, _)| name.clone()));
+    const LABELS: [(&str, &str); 5] = [
+        ("nutrient-energy", "kcal"),
+        ("nutrient-carbs", "g"),
+        ("nutrient-protein", "g"),
+        ("nutrient-fat", "g"),
+        ("nutrient-salt", "g"),
+    ];
+    let values = |n: Nutrients| [n.kcal, n.carbs_g, n.protein_g, n.fat_g, n.salt_g];
+    let per_column: Vec<[f64; 5]> = columns
+        .iter()
+        .map(|(_, grams)| values(batch * (grams / total)))
+        .collect();
+    let rows = LABELS
+        .iter()
+        .enumerate()
+        .map(|(i, (name, unit))| {
+            let mut row = vec![lang.text(name).to_string()];
+            row.extend(per_column.iter().map(|v| format!("{:.1} {unit}", v[i])));
+            row
+        })
+        .collect();
+    (header, rows)
+}
+
+/// Diameter (cm) and count of the pizzas, one entry per ball size.
+fn pizzas(a: &Args) -> Vec<(f64, u32)> {
+    let style = Style::from(a.toppings.unwrap_or_default());
+    a.sizes()
+        .iter()
+        .map(|b| {
+            let d = a.diameter.unwrap_or_else(|| style.diameter_for(b.weight));
+            (d, b.count)
+        })
+        .collect()
+}
+
+/// Toppings for all the pizzas of the batch.
+fn batch_toppings(a: &Args) -> Toppings {
+    let style = Style::from(a.toppings.unwrap_or_default());
+    pizzas(a)
+        .into_iter()
+        .map(|(d, n)| toppings(style, d).times(n))
+        .reduce(|x, y| x + y)
+        .expect("a batch has at least one ball size")
+}
+
+/// `--sauce`: a San Marzano sauce for the batch, as (ingredient, amount,
+/// notes). Makes the generous end of the toppings' sauce range.
+pub fn sauce_rows(r: &Recipe) -> Vec<[String; 3]> {
+    let a = r.args;
+    let u = a.units;
+    let lang = a.lang();
+    let s = sauce(batch_toppings(a).sauce_max_g, a.sauce_oil);
+    let share = |g: f64| format!("{:.1}", g / s.tomatoes_g * 100.0);
+    let mut rows = vec![
+        [
+            lang.text("sauce-tomatoes").to_string(),
+            fmt_rough_weight(s.tomatoes_g, u),
+            tr!(
+                lang,
+                "sauce-tins",
+                tins = s.tins(),
+                tin = fmt_weight(TOMATO_TIN_G, u)
+            ),
+        ],
+        [
+            lang.text("ing-salt").to_string(),
+            fmt_spoons(s.salt_g, a.salt_type().g_per_tsp(), u),
+            tr!(lang, "sauce-share", pct = share(s.salt_g)),
+        ],
+        [
+            lang.text("top-basil").to_string(),
+            tr!(lang, "basil-leaves", count = s.basil_leaves),
+            lang.text("sauce-basil").to_string(),
+        ],
+    ];
+    if s.oil_g > 0.0 {
+        rows.push([
+            lang.text("sauce-oil").to_string(),
+            fmt_weight(s.oil_g, u),
+            tr!(lang, "sauce-share", pct = share(s.oil_g)),
+        ]);
+    }
+    rows
+}
+
+/// Title of the sauce table, with the number of pizzas.
+pub fn sauce_title(r: &Recipe) -> String {
+    tr!(r.args.lang(), "sauce", count = r.args.ball_count())
+}
+
+/// `--launches`: (pizza, in the oven, out) for each pizza baked one at a
+/// time from the end of the proof; clock times when the start is known.
+pub fn launch_rows(r: &Recipe) -> Vec<[String; 3]> {
+    let (bake, recovery) = r.args.bake_cycle();
+    let at = |min: f64| match r.ends.proof {
+        Some(end) => format!(
+            "~{}",
+            fmt_hhmm(end + chrono::Duration::seconds((min * 60.0).round() as i64))
+        ),
+        None => format!("+{min:.0} min"),
+    };
+    launch_offsets(r.args.ball_count(), bake, recovery)
+        .into_iter()
+        .enumerate()
+        .map(|(i, start)| [format!("{}", i + 1), at(start), at(start + bake)])
+        .collect()
+}
+
+/// Title of the launch table, with the bake and recovery minutes.
+pub fn launches_title(r: &Recipe) -> String {
+    let (bake, recovery) = r.args.bake_cycle();
+    tr!(r.args.lang(), "launches", bake = bake, recovery = recovery)
+}
+
+/// Title of the toppings table, with the style.
+pub fn toppings_title(r: &Recipe) -> String {
+    let lang = r.args.lang();
+    let style = match r.args.toppings.unwrap_or_default() {
+        StyleFlag::Neapolitan => lang.text("style-neapolitan"),
+        StyleFlag::Roman => lang.text("style-roman"),
+        StyleFlag::NewYork => lang.text("style-new-york"),
+    };
+    tr!(lang, "toppings", style = style)
+}
+
+/// Toppings per pizza for `--toppings`: a header (one column per ball size,
+/// then the total when there is more than one pizza) and rows of sauce,
+/// mozzarella and basil.
+pub fn topping_rows(r: &Recipe) -> (Vec<String>, Vec<Vec<String>>) {
+    let a = r.args;
+    let u = a.units;
+    let lang = a.lang();
+    let style = Style::from(a.toppings.unwrap_or_default());
+    let pizzas = pizzas(a);
+    let mut columns: Vec<Toppings> = pizzas.iter().map(|&(d, _)| toppings(style, d)).collect();
+    let mut header = vec![lang.text("per-pizza").to_string()];
+    header.extend(
+        pizzas
+            .iter()
+            .map(|&(d, _)| tr!(lang, "pizza-size", diameter = fmt_length(d, u))),
+    );
+    let count = a.ball_count();
+    if count > 1 {
+        header.push(tr!(lang, "toppings-total", count = count));
+        columns.push(batch_toppings(a));
+    }
+    let cheese = match style {
+        Style::NewYork => "top-mozzarella",
+        Style::Neapolitan | Style::Roman => "top-fior-di-latte",
+    };
+    let row = |id: &'static str, cell: &dyn Fn(&Toppings) -> String| {
+        let mut row = vec![lang.text(id).to_string()];
+        row.extend(columns.iter().map(cell));
+        row
+    };
+    let rows = vec![
+        row("top-sauce", &|t| {
+            fmt_rough_range(t.sauce_min_g, t.sauce_max_g, u)
+        }),
+        row(cheese, &|t| fmt_rough_weight(t.cheese_g, u)),
+        row("top-basil", &|t| {
+            tr!(lang, "basil-leaves", count = t.basil_leaves)
+        }),
+    ];
+    (header, rows)
+}
+
+/// Title of the Detroit pan table, with the pan size.
+pub fn detroit_title(r: &Recipe) -> String {
+    let pan = r
+        .args
+        .detroit_pan()
+        .expect("only called for --style detroit");
+    let side = |inches: f64| fmt_length(inches * 2.54, r.args.units);
+    let size = format!("{} × {}", side(pan.width_in), side(pan.length_in));
+    tr!(r.args.lang(), "detroit", size = size)
+}
+
+/// Title of the mixing plan, with the mixer.
+pub fn mixing_title(r: &Recipe) -> String {
+    let lang = r.args.lang();
+    let mixer = match r.args.kneading().expect("only called with --mixer") {
+        Mixer::Hand => "mixer-hand",
+        Mixer::Planetary => "mixer-planetary",
+        Mixer::Spiral => "mixer-spiral",
+        Mixer::FoodProcessor => "mixer-food-processor",
+    };
+    tr!(lang, "mixing", mixer = lang.text(mixer))
+}
+
+/// The mixing plan ahead of the bulk rise ([`kneading::schedule`]): each
+/// step with the mixer speed, how long it takes and, with a start time,
+/// when it ends.
+pub fn mixing_rows(r: &Recipe) -> (Vec<String>, Vec<Vec<String>>) {
+    let a = r.args;
+    let lang = a.lang();
+    let steps = kneading::schedule(
+        a.kneading().expect("only called with --mixer"),
+        a.hydration(),
+    );
+    let mut header = ["col-step", "col-speed", "col-duration"]
+        .map(|id| lang.text(id).to_string())
+        .to_vec();
+    if r.start.is_some() {
+        header.push(lang.text("col-ends").to_string());
+    }
+    let mut elapsed = 0.0;
+    let rows = steps
+        .iter()
+        .map(|s| {
+            let step = match s.stage {
+                Stage::Combine => lang.text("mix-combine").to_string(),
+                Stage::Rest => lang.text("mix-rest").to_string(),
+                Stage::Knead => lang.text("mix-knead").to_string(),
+                Stage::AddWater { share } => tr!(
+                    lang,
+                    "mix-add-water",
+                    water = fmt_weight(r.ing.water_g * share, a.units)
+                ),
+                Stage::Windowpane => lang.text("mix-windowpane").to_string(),
+            };
+            let speed = match s.speed {
+                Some(n) => tr!(lang, "mix-speed", speed = n),
+                None => "—".to_string(),
+            };
+            let duration = match s.minutes < 1.0 {
+                true => format!("{:.0} s", s.minutes * 60.0),
+                false => format!("{:.0} min", s.minutes),
+            };
+            elapsed += s.minutes;
+            let mut row = vec![step, speed, duration];
+            if let Some(start) = r.start {
+                let secs = (elapsed * 60.0).round() as i64;
+                row.push(fmt_hhmm(start + chrono::Duration::seconds(secs)));
+            }
+            row
+        })
+        .collect();
+    (header, rows)
+}
+
+/// Loading of the Detroit pans: a header (per pan, then the total when
+/// there is more than one) and rows of dough, brick cheese with the part
+/// along the walls, and sauce.
+pub fn detroit_rows(r: &Recipe) -> (Vec<String>, Vec<Vec<String>>) {
+    let a = r.args;
+    let u = a.units;
+    let lang = a.lang();
+    let load = pans::detroit(a.detroit_pan().expect("only called for --style detroit"));
+    let mut header = vec![
+        lang.text("col-ingredient").to_string(),
+        lang.text("per-pan").to_string(),
+    ];
+    let mut factors = vec![1.0];
+    let count = a.ball_count();
+    if count > 1 {
+        header.push(tr!(lang, "toppings-total", count = count));
+        factors.push(count as f64);
+    }
+    let row = |id: &'static str, g: &dyn Fn(&PanLoad) -> f64| {
+        let mut row = vec![lang.text(id).to_string()];
+        row.extend(factors.iter().map(|n| fmt_rough_weight(g(&load) * n, u)));
+        row
+    };
+    let rows = vec![
+        row("pan-dough", &|_| a.ball_weight()),
+        row("pan-cheese", &|l| l.cheese_g),
+        row("pan-edge-cheese", &|l| l.edge_cheese_g),
+        row("pan-sauce", &|l| l.sauce_g),
+    ];
+    (header, rows)
+}
+
+/// Cost table: rows of (item, amount, cost) for the batch, then the total
+/// and the cost per ball of each size.
+pub fn cost_rows(r: &Recipe, p: &Prices) -> Vec<[String; 3]> {
+    let a = r.args;
+    let u = a.units;
+    let lang = a.lang();
+    let money = |x: f64| format!("{}{:.2}", p.currency, x);
+    let yeast_per_g = match a.yeast() {
+        YeastFlag::Dry => p.dry_yeast_per_g,
+        YeastFlag::Fresh => p.fresh_yeast_per_g,
+    };
+    let items = [
+        (
+            flour_label(a),
+            r.ing.flour_g,
+            r.ing.flour_g / 1000.0 * p.flour_per_kg,
+        ),
+        (
+            lang.text("ing-water"),
+            r.ing.water_g,
+            r.ing.water_g / 1000.0 * p.water_per_l,
+        ),
+        (
+            lang.text("ing-salt"),
+            r.ing.salt_g,
+            r.ing.salt_g / 1000.0 * p.salt_per_kg,
+        ),
+        (
+            lang.text("ing-yeast"),
+            r.ing.yeast_g,
+            r.ing.yeast_g * yeast_per_g,
+        ),
+    ];
+    let total: f64 = items.iter().map(|(_, _, cost)| cost).sum();
+    let mut rows: Vec<[String; 3]> = items
+        .iter()
+        .map(|&(name, grams, cost)| [name.to_string(), fmt_weight(grams, u), money(cost)])
+        .collect();
+    rows.push([
+        lang.text("batch").to_string(),
+        fmt_weight(a.total_dough(), u),
+        money(total),
+    ]);
+    for b in a.sizes() {
+        rows.push([
+            lang.text("per-ball").to_string(),
+            fmt_weight(b.weight, u),
+            money(total * b.weight / a.total_dough()),
+        ]);
+    }
+    rows
+}
+
+/// Effective hours of the timeline and the model's factors for them, before
+/// viability, fresh yeast or `--yeast-pct`.
+pub fn yeast_derivation(r: &Recipe) -> (f64, YeastFactors) {
+    let a = r.args;
+    let eff = timeline_effective_hours(&r.tl, a.fridge_factor());
+    let f = yeast_factors(a.model().model().as_ref(), a.temp(), plan_w(a), eff);
+    (eff, f)
+}
+
+/// W and hydration the plan computes with: a gluten-free dough's are the
+/// fixed [`gluten_free::W`] and the hydration over blend and binder.
+fn plan_w(a: &Args) -> u16 {
+    match a.gluten_free() {
+        Some(_) => gluten_free::W,
+        None => a.w(),
+    }
+}
+
+fn plan_hydration(a: &Args) -> f64 {
+    match a.gluten_free() {
+        Some(b) => gluten_free::overall_hydration(a.hydration(), b),
+        None => a.hydration(),
+    }
+}
+
+/// `--explain`: the steps from the inputs to the yeast and flour, as
+/// (step, formula with the numbers filled in, result).
+pub fn explain_rows(r: &Recipe) -> Vec<[String; 3]> {
+    let a = r.args;
+    let lang = a.lang();
+    let t = |id| lang.text(id).to_string();
+    let pct = |x: f64| format!("{:.3}%", x * 100.0);
+    let (temp, w, tl) = (a.temp(), plan_w(a), &r.tl);
+    let ff = a.fridge_factor().clamp(0.05, 0.5);
+    let (eff, f) = yeast_derivation(r);
+
+    let mut eff_formula = format!("{:.1} + {:.1} + {:.1}", tl.bulk_h, tl.warmup_h, tl.proof_h);
+    if tl.fridge_h > 0.0 {
+        eff_formula += &format!(" + {:.1} × {ff:.2}", tl.fridge_h);
+    }
+    let temp_formula = match a.model() {
+        ModelFlag::Q10 => format!("1 / 2^(({temp:.1} - {REFERENCE_TEMP_C}) / 10)"),
+        ModelFlag::Arrhenius => format!(
+            "1 / exp({DEFAULT_ACTIVATION_ENERGY_KJ} kJ/mol / R × (1/{:.2} - 1/{:.2} K))",
+            REFERENCE_TEMP_C + 273.15,
+            temp + 273.15
+        ),
+    };
+    let mut rows = vec![
+        [t("explain-hours"), eff_formula, format!("{eff:.2} h")],
+        [t("explain-temp"), temp_formula, format!("{:.3}", f.temp)],
+        [
+            t("explain-w"),
+            format!("({w} / 260)^0.2"),
+            format!("{:.3}", f.w),
+        ],
+        [
+            t("explain-time"),
+            format!("12 / {eff:.2}"),
+            format!("{:.3}", f.time),
+        ],
+        [
+            t("explain-dry-yeast"),
+            format!(
+                "{} × {:.3} × {:.3} × {:.3}",
+                pct(f.base),
+                f.temp,
+                f.w,
+                f.time
+            ),
+            pct(f.raw()),
+        ],
+    ];
+    let mut yeast = f.percent_dry();
+    if yeast != f.raw() {
+        let range = YEAST_PERCENT_DRY_RANGE;
+        rows.push([
+            t("explain-clamped"),
+            format!("{} … {}", pct(*range.start()), pct(*range.end())),
+            pct(yeast),
+        ]);
+    }
+    if a.no_knead {
+        let range = YEAST_PERCENT_DRY_RANGE;
+        yeast = (yeast * no_knead::YEAST_FACTOR).clamp(*range.start(), *range.end());
+        rows.push([
+            t("explain-no-knead"),
+            format!("× {}", no_knead::YEAST_FACTOR),
+            pct(yeast),
+        ]);
+    }
+    let v = a.viability();
+    if v < 1.0 {
+        yeast /= v.max(0.1);
+        rows.push([t("explain-viability"), format!("÷ {v:.2}"), pct(yeast)]);
+    }
+    let e = a.enrichment();
+    if e.yeast_factor() != 1.0 {
+        yeast *= e.yeast_factor();
+        rows.push([
+            t("explain-enrichment"),
+            format!("× {:.3}", e.yeast_factor()),
+            pct(yeast),
+        ]);
+    }
+    if a.yeast() == YeastFlag::Fresh {
+        yeast *= a.fresh_factor();
+        rows.push([
+            t("explain-fresh"),
+            format!("× {}", a.fresh_factor()),
+            pct(yeast),
+        ]);
+    }
+    if let Some(fixed) = a.yeast_pct {
+        yeast = fixed / 100.0;
+        rows.push([t("explain-fixed"), "--yeast-pct".to_string(), pct(yeast)]);
+    }
+    // All the dry flour: the blend and binder, or wheat, rice and soy.
+    let ing = &r.ing;
+    let dry = ing.flour_g + ing.binder_g + ing.rice_g + ing.soy_g;
+    let salt = a.salt_per_kg() / 1000.0;
+    let malt = ing.malt_g / dry;
+    let mut flour_formula = format!(
+        "{:.0} g / (1 + {:.3} + {salt:.3} + {yeast:.4} + {malt:.3}",
+        a.total_dough(),
+        e.liquid(plan_hydration(a))
+    );
+    if e.oil_pct + e.sugar_pct > 0.0 {
+        flour_formula += &format!(" + {:.3}", e.oil_pct + e.sugar_pct);
+    }
+    rows.push([t("explain-flour"), flour_formula + ")", fmt_g(dry)]);
+    rows
+}
+
+/// Flour notes: name (if chosen from the list), W and hydration, with a hint
+/// when the hydration is well above what the flour typically absorbs.
+fn flour_note(r: &Recipe) -> String {
+    let a = r.args;
+    if a.gluten_free().is_some() {
+        return format!("H={:.0}%", a.hydration() * 100.0);
+    }
+    let w = match (a.protein, a.flour()) {
+        (Some(protein), None) => tr!(a.lang(), "w-from-protein", w = a.w(), protein = protein),
+        _ => format!("W={}", a.w()),
+    };
+    let wh = format!("{w} | H={:.0}%", a.hydration() * 100.0);
+    match a.flour() {
+        None => wh,
+        Some(f) if a.hydration() > f.absorption + 0.10 => tr!(
+            a.lang(),
+            "flour-absorption",
+            flour = f.name,
+            wh = wh,
+            absorption = format!("{:.0}", f.absorption * 100.0)
+        ),
+        Some(f) => format!("{} · {wh}", f.name),
+    }
+}
+
+/// Water temperature for the desired dough temperature and how to get there,
+/// when requested or in a hot kitchen.
+fn water_temp_row(r: &Recipe) -> Option<[String; 3]> {
+    let (target, prep) = r.water?;
+    let (u, t) = (r.args.units, r.args.temp_units());
+    let lang = r.args.lang();
+    let note = match prep {
+        WaterPrep::Tap if target > r.args.temp() + 2.0 => lang.text("water-warm").to_string(),
+        WaterPrep::Tap => lang.text("water-tap").to_string(),
+        WaterPrep::Chilled { tap_g, .. } if tap_g < 0.5 => {
+            lang.text("water-all-chilled").to_string()
+        }
+        WaterPrep::Chilled { chilled_g, tap_g } => tr!(
+            lang,
+            "water-chilled",
+            chilled = fmt_weight(chilled_g, u),
+            tap = fmt_weight(tap_g, u)
+        ),
+        WaterPrep::Iced { ice_g, chilled_g } => tr!(
+            lang,
+            "water-iced",
+            ice = fmt_weight(ice_g, u),
+            chilled = fmt_weight(chilled_g, u)
+        ),
+        WaterPrep::AllIce { ice_g } => tr!(lang, "water-all-ice", ice = fmt_weight(ice_g, u)),
+    };
+    Some([
+        lang.text("ing-water-temp").to_string(),
+        fmt_temp(target.max(0.0), t),
+        tr!(
+            lang,
+            "water-temp-note",
+            ddt = fmt_temp(r.args.ddt.map_or(ddt::DEFAULT_DDT_C, |d| d.celsius), t),
+            rise = fmt_temp_delta(ddt::mix_rise(r.args.friction()), t),
+            prep = note
+        ),
+    ])
+}
+
+/// All steps in order, alternating passive phases and hands-on actions.
+pub fn steps(r: &Recipe) -> Vec<Step> {
+    let mix = r.args.lang().text("action-mix");
+    let minutes = |action: &str| match (action == mix, r.args.no_knead) {
+        (true, true) => NO_KNEAD_MIX_MIN,
+        (true, false) => MIX_MIN,
+        (false, _) => ACTION_MIN,
+    };
+    let (first, mut start) = first_action(r);
+    let mut steps = vec![Step {
+        label: first,
+        activity: Activity::HandsOn,
+        minutes: minutes(first),
+        start,
+    }];
+    for row in phase_rows(r) {
+        steps.push(Step {
+            label: row.label,
+            activity: Activity::Passive,
+            minutes: (row.hours * 60.0).round() as i64,
+            start,
+        });
+        steps.push(Step {
+            label: row.action,
+            activity: Activity::HandsOn,
+            minutes: minutes(row.action),
+            start: row.end,
+        });
+        start = row.end;
+    }
+    steps
+}
+
+/// With `--levain`, the build of that much levain from `--levain-seed`,
+/// timed to peak at the mix.
+pub fn levain_stages(r: &Recipe) -> Vec<BuildStage> {
+    match (r.args.levain, r.args.levain_seed) {
+        (Some(target), Some(seed)) => levain_build(target, seed, r.args.temp()),
+        _ => Vec::new(),
+    }
+}
+
+/// Minutes of the levain build ahead of the mix (0 without `--levain`).
+pub fn levain_minutes(r: &Recipe) -> i64 {
+    levain_stages(r)
+        .iter()
+        .map(|s| (s.hours * 60.0).round() as i64)
+        .sum()
+}
+
+/// The first hands-on action and its time: feeding the levain with
+/// `--levain`, mixing otherwise.
+pub fn first_action(r: &Recipe) -> (&'static str, Option<NaiveDateTime>) {
+    let lang = r.args.lang();
+    match levain_minutes(r) {
+        0 => (lang.text("action-mix"), r.start),
+        min => (
+            lang.text("action-feed-levain"),
+            r.start.map(|s| s - chrono::Duration::minutes(min)),
+        ),
+    }
+}
+
+/// A row per levain build stage, with its feed as a note; the last one
+/// ends at the mix.
+fn levain_rows<'a>(r: &Recipe<'a>) -> Vec<PhaseRow<'a>> {
+    let (lang, u) = (r.args.lang(), r.args.units);
+    let stages = levain_stages(r);
+    let mut end = first_action(r).1;
+    stages
+        .iter()
+        .enumerate()
+        .map(|(i, stage)| {
+            let f = stage.feed;
+            let text = tr!(
+                lang,
+                "event-levain",
+                seed = fmt_weight(f.seed_g, u),
+                flour = fmt_weight(f.flour_g, u),
+                water = fmt_weight(f.water_g, u),
+                total = fmt_weight(f.total_g(), u)
+            );
+            let note = match end {
+                Some(fed) => format!("~{} {text}", fmt_hhmm(fed)),
+                None => text,
+            };
+            end = end.map(|e| e + chrono::Duration::minutes((stage.hours * 60.0).round() as i64));
+            PhaseRow {
+                label: lang.text("phase-levain"),
+                action: lang.text(match i + 1 == stages.len() {
+                    true => "action-mix",
+                    false => "action-feed-levain",
+                }),
+                hours: stage.hours,
+                end,
+                notes: vec![Cow::Owned(note)],
+            }
+        })
+        .collect()
+}
+
+/// Total hands-on minutes.
+pub fn hands_on_minutes(r: &Recipe) -> i64 {
+    steps(r)
+        .iter()
+        .filter(|s| s.activity == Activity::HandsOn)
+        .map(|s| s.minutes)
+        .sum()
+}
+
+/// "~50 min active work across 11.0 h".
+pub fn fmt_effort(r: &Recipe) -> String {
+    let min = hands_on_minutes(r);
+    let active = if min >= 60 {
+        format!("{} h {:02} min", min / 60, min % 60)
+    } else {
+        format!("{min} min")
+    };
+    tr!(
+        r.args.lang(),
+        "effort",
+        active = active,
+        hours = format!("{:.1}", total_hours(&r.tl))
+    )
+}
+
+/// Timeline rows in order; fridge and warmup only appear in fridge mode, and
+/// a `--levain` build comes first.
+pub fn phase_rows<'a>(r: &Recipe<'a>) -> Vec<PhaseRow<'a>> {
+    let notes = |kind: PhaseKind| -> Vec<Cow<'a, str>> {
+        r.args
+            .notes
+            .iter()
+            .filter(|n| n.phase == kind)
+            .map(|n| Cow::Borrowed(n.text.as_str()))
+            .collect()
+    };
+    let lang = r.args.lang();
+    let fridge = r.tl.fridge_h > 0.0;
+    // A gluten-free dough rests for its binder, is shaped and rises shaped;
+    // a pan dough has its final proof in the pan, and a pinsa is pressed
+    // into an oval only when it goes in the oven.
+    let (bulk, ball, proof) = match r.args.style.unwrap_or_default() {
+        DoughFlag::GlutenFree => ("phase-rest", "action-shape", "phase-rise"),
+        DoughFlag::Pan | DoughFlag::Detroit => ("phase-bulk", "action-pan", "phase-pan-proof"),
+        DoughFlag::Classic | DoughFlag::NewYork | DoughFlag::Pinsa => {
+            ("phase-bulk", "action-ball", "phase-proof")
+        }
+    };
+    let mut rows = vec![PhaseRow {
+        label: lang.text(bulk),
+        action: lang.text(if fridge { "action-fridge" } else { ball }),
+        hours: r.tl.bulk_h,
+        end: r.ends.bulk,
+        notes: notes(PhaseKind::Bulk),
+    }];
+    if fridge {
+        rows.push(PhaseRow {
+            label: lang.text("phase-fridge"),
+            action: lang.text("action-unfridge"),
+            hours: r.tl.fridge_h,
+            end: r.ends.fridge,
+            notes: notes(PhaseKind::Fridge),
+        });
+        rows.push(PhaseRow {
+            label: lang.text("phase-warmup"),
+            action: lang.text(ball),
+            hours: r.tl.warmup_h,
+            end: r.ends.warmup,
+            notes: notes(PhaseKind::Warmup),
+        });
+    }
+    rows.push(PhaseRow {
+        label: lang.text(proof),
+        action: lang.text(match r.args.pinsa() {
+            Some(_) => "action-oval",
+            None => "action-bake",
+        }),
+        hours: r.tl.proof_h,
+        end: r.ends.proof,
+        notes: notes(PhaseKind::Proof),
+    });
+    add_salt(r, &mut rows);
+    add_folds(r, &mut rows);
+    add_prep(r, &mut rows);
+    rows.splice(0..0, levain_rows(r));
+    rows
+}
+
+/// With `--no-knead`, note when to fold the dough during the bulk rise,
+/// with the clock time when the start is known.
+fn add_folds(r: &Recipe, rows: &mut [PhaseRow]) {
+    if !r.args.no_knead {
+        return;
+    }
+    let lang = r.args.lang();
+    for (i, min) in no_knead::FOLDS_MIN.into_iter().enumerate() {
+        let text = tr!(
+            lang,
+            "event-fold",
+            n = i + 1,
+            count = no_knead::FOLDS_MIN.len(),
+            minutes = min
+        );
+        rows[0].notes.push(Cow::Owned(match r.start {
+            Some(start) => format!(
+                "~{} {text}",
+                fmt_hhmm(start + chrono::Duration::minutes(min.into()))
+            ),
+            None => text,
+        }));
+    }
+}
+
+/// With `--salt-after`, note when to add the salt at the start of the first
+/// phase, with the clock time when the start is known.
+fn add_salt(r: &Recipe, rows: &mut [PhaseRow]) {
+    let Some(min) = r.args.salt_after else {
+        return;
+    };
+    let text = tr!(r.args.lang(), "event-salt", minutes = min);
+    rows[0].notes.insert(
+        0,
+        Cow::Owned(match r.start {
+            Some(start) => format!(
+                "~{} {text}",
+                fmt_hhmm(start + chrono::Duration::minutes(min.into()))
+            ),
+            None => text,
+        }),
+    );
+}
+
+/// Jobs ahead of baking as (minutes before the bake, what to do), earliest
+/// first: preheat the oven (`--oven`, `--preheat`) so the stone or steel is
+/// saturated, drain the mozzarella so it does not flood the pizza, and let
+/// the sauce lose its fridge chill.
+pub fn prep(r: &Recipe) -> Vec<(i64, String)> {
+    let (a, lang) = (r.args, r.args.lang());
+    let mut jobs = Vec::new();
+    if let Some(min) = a.preheat_minutes() {
+        jobs.push((min as i64, tr!(lang, "prep-preheat", minutes = min)));
+    }
+    if a.toppings.is_some() {
+        jobs.push((120, lang.text("prep-drain").to_string()));
+    }
+    if a.toppings.is_some() || a.sauce {
+        jobs.push((60, lang.text("prep-sauce").to_string()));
+    }
+    jobs.sort_by_key(|&(min, _)| std::cmp::Reverse(min));
+    jobs
+}
+
+/// Add the [`prep`] jobs as notes to the phases they fall in, with the
+/// clock time when the start is known.
+fn add_prep(r: &Recipe, rows: &mut [PhaseRow]) {
+    for (min, text) in prep(r) {
+        let note = match r.ends.proof {
+            Some(bake) => format!(
+                "~{} {text}",
+                fmt_hhmm(bake - chrono::Duration::minutes(min))
+            ),
+            None => text,
+        };
+        // Walk back from the bake to the phase `min` before it
+        let mut left = min as f64 / 60.0;
+        let mut i = rows.len() - 1;
+        while left > rows[i].hours && i > 0 {
+            left -= rows[i].hours;
+            i -= 1;
+        }
+        rows[i].notes.push(Cow::Owned(note));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plan, prepare, repl};
+
+    #[test]
+    fn explained_flour_matches_the_plan() {
+        for line in [
+            "--w 270 --malt --total-hours 72 --fridge-hours 60",
+            "--style gluten-free --total-hours 4",
+            "--style pinsa --w 300",
+        ] {
+            let tokens: Vec<String> = format!("{line} --lang en")
+                .split(' ')
+                .map(String::from)
+                .collect();
+            let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+            let r = plan(&args).unwrap();
+            let rows = explain_rows(&r);
+            let flour = rows.iter().find(|row| row[0] == "Flour").unwrap();
+            // total / (1 + liquid + salt + yeast + malt)
+            let nums: Vec<f64> = flour[1]
+                .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .filter_map(|n| n.parse().ok())
+                .collect();
+            let explained = nums[0] / nums[1..].iter().sum::<f64>();
+            let i = &r.ing;
+            let dry = i.flour_g + i.binder_g + i.rice_g + i.soy_g;
+            assert!(
+                (explained - dry).abs() < dry * 0.002,
+                "{line}: {flour:?} vs {dry}"
+            );
+            assert_eq!(flour[2], fmt_g(dry));
+        }
+    }
+
+    #[test]
+    fn levain_build_ends_at_the_mix() {
+        let tokens: Vec<String> =
+            "--w 260 --total-hours 8 --start 10:00 --levain 500 --levain-seed 20 --lang en"
+                .split(' ')
+                .map(String::from)
+                .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let r = plan(&args).unwrap();
+        let rows = phase_rows(&r);
+        // 25:1 is more than one feed takes: two stages, the last one
+        // peaking when the dough is mixed.
+        assert_eq!(rows[0].label, "Levain build");
+        assert_eq!(rows[0].action, "Feed the levain");
+        assert_eq!(rows[1].action, "Mix the dough");
+        assert_eq!(rows[1].end, r.start);
+        assert_eq!(rows[2].end, r.ends.bulk);
+
+        let (first, at) = first_action(&r);
+        assert_eq!(first, "Feed the levain");
+        let start = r.start.unwrap();
+        assert_eq!(
+            start - at.unwrap(),
+            chrono::Duration::minutes(levain_minutes(&r))
+        );
+        let steps = steps(&r);
+        assert_eq!(steps[0].start, at);
+        let mix = steps.iter().find(|s| s.label == "Mix the dough").unwrap();
+        assert_eq!((mix.start, mix.minutes), (r.start, MIX_MIN));
+
+        let tokens: Vec<String> = "--levain 500 --levain-seed 600"
+            .split(' ')
+            .map(String::from)
+            .collect();
+        assert!(prepare(repl::parse(&tokens).unwrap()).is_err());
+    }
+}
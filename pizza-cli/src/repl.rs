@@ -0,0 +1,185 @@
+//! Interactive session keeping the current recipe between commands.
+//!
+//! The state is the list of recipe flags, re-parsed by clap after every
+//! change, so `set hydration 0.72` is validated exactly like `--hydration 0.72`.
+
+use crate::{Args, history, html, pdf, plan, prepare, profile, text};
+use clap::{Args as _, FromArgMatches};
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+const HELP: &str = "\
+Commands:
+  set <param> <value>      set a recipe flag (e.g. `set hydration 0.72`, `set note proof=oven`)
+  unset <param>            back to the default (or profile) value
+  show [recipe|ingredients|timeline|params]
+  export html <file>       write the printable HTML card
+  export pdf <file>        write the printable PDF card
+  export profile <name>    save the current parameters as a profile
+  history                  recent commands (kept across sessions)
+  help                     this text
+  quit                     leave (also Ctrl-D)";
+
+pub(crate) fn command() -> clap::Command {
+    Args::augment_args(
+        clap::Command::new("pizza")
+            .no_binary_name(true)
+            .args_override_self(true),
+    )
+}
+
+/// Parse the accumulated flags into recipe arguments.
+pub(crate) fn parse(tokens: &[String]) -> Result<Args, String> {
+    let matches = command()
+        .try_get_matches_from(tokens)
+        .map_err(|e| e.render().to_string().trim_end().to_string())?;
+    Args::from_arg_matches(&matches).map_err(|e| e.to_string())
+}
+
+/// Whether the flag `--name` takes a value (unknown flags are assumed to).
+pub(crate) fn takes_value(name: &str) -> bool {
+    command()
+        .get_arguments()
+        .find(|a| a.get_long() == Some(name))
+        .is_none_or(|a| a.get_action().takes_values())
+}
+
+/// Remove every occurrence of `--name` (and its value) from the flags.
+fn remove_flag(tokens: &mut Vec<String>, name: &str) {
+    let flag = format!("--{name}");
+    let with_value = takes_value(name);
+    let mut out = Vec::with_capacity(tokens.len());
+    let mut it = std::mem::take(tokens).into_iter();
+    while let Some(t) = it.next() {
+        if t == flag {
+            if with_value {
+                it.next();
+            }
+        } else if !t.starts_with(&format!("{flag}=")) {
+            out.push(t);
+        }
+    }
+    *tokens = out;
+}
+
+/// Split a line into words, honouring double quotes.
+fn split_words(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut cur = String::new();
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !cur.is_empty() {
+                    words.push(std::mem::take(&mut cur));
+                }
+            }
+            c => cur.push(c),
+        }
+    }
+    if !cur.is_empty() {
+        words.push(cur);
+    }
+    words
+}
+
+/// Run one command against the state. Returns `false` to quit.
+fn eval(tokens: &mut Vec<String>, words: &[String]) -> Result<bool, String> {
+    let word = |i: usize| words.get(i).map(String::as_str);
+    match (word(0), word(1)) {
+        (None, _) => {}
+        (Some("quit" | "exit"), _) => return Ok(false),
+        (Some("help"), _) => println!("{HELP}"),
+        (Some("history"), _) => {
+            for line in history::recent() {
+                println!("  {line}");
+            }
+        }
+        (Some("set"), Some(name)) => {
+            let name = name.replace('_', "-");
+            let mut next = tokens.clone();
+            next.push(format!("--{name}"));
+            if takes_value(&name) {
+                if words.len() < 3 {
+                    return Err(format!("usage: set {name} <value>"));
+                }
+                next.push(words[2..].join(" "));
+            }
+            // Reject values that would not validate, keeping the previous state.
+            prepare(parse(&next)?)?;
+            *tokens = next;
+        }
+        (Some("unset"), Some(name)) => remove_flag(tokens, &name.replace('_', "-")),
+        (Some("show"), what) => {
+            let args = prepare(parse(tokens)?)?;
+            let recipe = plan(&args)?;
+            history::remember(&args);
+            match what.unwrap_or("recipe") {
+                "recipe" => text::print_recipe(&recipe),
+                "ingredients" => text::print_ingredients(&recipe),
+                "timeline" => text::print_timeline(&recipe),
+                "params" => text::print_profile(
+                    &serde_json::to_value(profile::Profile::from(&args)).unwrap(),
+                ),
+                other => return Err(format!("unknown view '{other}'")),
+            }
+        }
+        (Some("export"), Some("html")) => {
+            let file = word(2).ok_or("usage: export html <file>")?;
+            let args = prepare(parse(tokens)?)?;
+            let recipe = plan(&args)?;
+            fs::write(file, html::render(&recipe)).map_err(|e| e.to_string())?;
+            println!("Wrote {file}");
+        }
+        (Some("export"), Some("pdf")) => {
+            let file = word(2).ok_or("usage: export pdf <file>")?;
+            let args = prepare(parse(tokens)?)?;
+            let recipe = plan(&args)?;
+            fs::write(file, pdf::render(&recipe)).map_err(|e| e.to_string())?;
+            println!("Wrote {file}");
+        }
+        (Some("export"), Some("profile")) => {
+            let name = word(2).ok_or("usage: export profile <name>")?;
+            let args = prepare(parse(tokens)?)?;
+            let path = profile::write(Path::new(name), &profile::Profile::from(&args))
+                .map_err(|e| format!("Failed to save profile: {e}"))?;
+            println!("Profile saved to {}", path.display());
+        }
+        (Some(cmd), _) => return Err(format!("unknown command '{cmd}' (try `help`)")),
+    }
+    Ok(true)
+}
+
+/// Read commands from stdin until `quit` or end of input.
+pub fn run(initial: Vec<String>) {
+    let mut tokens = initial;
+    if let Err(e) = parse(&tokens) {
+        eprintln!("{e}");
+        std::process::exit(2);
+    }
+    println!("pizza repl — type `help` for commands");
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("pizza> ");
+        io::stdout().flush().ok();
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            break;
+        };
+        let words = split_words(&line);
+        if !words.is_empty() && words[0] != "history" {
+            history::append(line.trim());
+        }
+        match eval(&mut tokens, &words) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+}
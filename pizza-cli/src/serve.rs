@@ -0,0 +1,207 @@
+//! `serve`: a small HTTP server answering `GET /ingredients` and
+//! `GET /timeline` with JSON. Query parameters are the recipe flags without
+//! dashes (`/ingredients?w=270&balls=4&fridge-hours=16`), validated exactly
+//! like the command line; a flag without a value (`?malt`) is a switch.
+//!
+//! One request at a time, no TLS: meant for a home network, not the internet.
+
+use crate::{fields::Summary, plan, prepare, repl};
+use serde_json::{Value, json};
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+};
+
+/// Whether a client must not set the flag `name`: it reads or writes files
+/// on the server. That is every flag taking a path, and `--export`.
+pub(crate) fn refused(name: &str) -> bool {
+    let path = clap::value_parser!(PathBuf).type_id();
+    name == "export"
+        || repl::command()
+            .get_arguments()
+            .any(|a| a.get_long() == Some(name) && a.get_value_parser().type_id() == path)
+}
+
+/// Decode `%XX` escapes and `+` in a query component.
+fn decode(s: &str) -> String {
+    let mut out = Vec::with_capacity(s.len());
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(b) => {
+                        out.push(b);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Recipe flags from (name, value) pairs; `_` in names counts as `-` and
+/// switches take no value.
+pub(crate) fn flag_tokens(
+    pairs: impl IntoIterator<Item = (String, String)>,
+) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    for (key, value) in pairs {
+        let key = key.replace('_', "-");
+        if refused(&key) {
+            return Err(format!("'{key}' is not available here"));
+        }
+        tokens.push(format!("--{key}"));
+        if repl::takes_value(&key) {
+            tokens.push(value);
+        }
+    }
+    Ok(tokens)
+}
+
+/// Turn `w=270&temp=24&malt` into `--w 270 --temp 24 --malt`.
+fn query_tokens(query: &str) -> Result<Vec<String>, String> {
+    flag_tokens(query.split('&').filter(|p| !p.is_empty()).map(|pair| {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (decode(key), decode(value))
+    }))
+}
+
+/// Which part of the result to return.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum View {
+    Ingredients,
+    Timeline,
+}
+
+/// The `--fields` result object for the flags, cut down to `view`.
+pub(crate) fn compute(view: View, tokens: &[String]) -> Result<Value, String> {
+    let args = prepare(repl::parse(tokens)?)?;
+    let recipe = plan(&args)?;
+    let Value::Object(mut all) = serde_json::to_value(Summary::new(&recipe)).unwrap() else {
+        unreachable!("Summary serializes to an object");
+    };
+    Ok(match view {
+        View::Timeline => json!({
+            "timeline": all["timeline"],
+            "schedule": all["schedule"],
+            "warnings": all["warnings"],
+        }),
+        View::Ingredients => {
+            all.remove("timeline");
+            all.remove("schedule");
+            Value::Object(all)
+        }
+    })
+}
+
+/// Status and JSON body for a request path (with query).
+fn respond(target: &str) -> (u16, Value) {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let view = match path {
+        "/ingredients" => View::Ingredients,
+        "/timeline" => View::Timeline,
+        _ => {
+            return (
+                404,
+                json!({ "error": format!("unknown path '{path}'; try /ingredients or /timeline") }),
+            );
+        }
+    };
+    match query_tokens(query).and_then(|tokens| compute(view, &tokens)) {
+        Ok(body) => (200, body),
+        Err(e) => (400, json!({ "error": e })),
+    }
+}
+
+fn handle(stream: TcpStream) -> std::io::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Skip the headers; requests have no body.
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) => respond(target),
+        (Some(_), Some(_)) => (405, json!({ "error": "only GET is supported" })),
+        _ => (400, json!({ "error": "malformed request" })),
+    };
+    eprintln!("{} {status}", request_line.trim_end());
+
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Method Not Allowed",
+    };
+    let body = serde_json::to_string_pretty(&body).unwrap();
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// Serve until interrupted.
+pub fn run(listen: &str) -> Result<(), String> {
+    let listener =
+        TcpListener::bind(listen).map_err(|e| format!("Cannot listen on {listen}: {e}"))?;
+    eprintln!("Listening on http://{listen} (GET /ingredients, /timeline)");
+    for stream in listener.incoming() {
+        match stream {
+            Ok(s) => {
+                if let Err(e) = handle(s) {
+                    eprintln!("Connection error: {e}");
+                }
+            }
+            Err(e) => eprintln!("Connection error: {e}"),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_becomes_flags() {
+        assert_eq!(
+            query_tokens("w=270&fridge_hours=16&malt&start=18%3A00").unwrap(),
+            [
+                "--w",
+                "270",
+                "--fridge-hours",
+                "16",
+                "--malt",
+                "--start",
+                "18:00"
+            ]
+        );
+        assert_eq!(decode("a+b%2Cc"), "a b,c");
+        assert!(query_tokens("save-profile=x").is_err());
+        assert!(query_tokens("save_profile=x").is_err());
+        assert!(query_tokens("w=270&temp_log=/etc/passwd").is_err());
+        assert!(query_tokens("profile=/etc/passwd").is_err());
+        assert!(query_tokens("w=270&busy=/dev/zero").is_err());
+        assert!(query_tokens("qr_png=x.png").is_err() && query_tokens("export=pdf").is_err());
+    }
+}
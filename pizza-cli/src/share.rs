@@ -0,0 +1,80 @@
+//! Share strings: the effective parameters as URL-safe base64 of the profile
+//! JSON, so a recipe fits in a chat message. `share` prints one and `open`
+//! loads it like a profile.
+
+use crate::profile::Profile;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+
+/// Marks the format, so it can change without breaking old strings.
+const PREFIX: &str = "pz1.";
+
+pub fn encode(p: &Profile) -> String {
+    let json = serde_json::to_vec(p).unwrap();
+    format!("{PREFIX}{}", URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Accepts the string with surrounding whitespace or quotes from chat apps.
+pub fn decode(s: &str) -> Result<Profile, String> {
+    let s = s.trim().trim_matches(['"', '\'', '`']);
+    let body = s
+        .strip_prefix(PREFIX)
+        .ok_or_else(|| format!("Not a pizza-cli share string (expected '{PREFIX}...')"))?;
+    let json = URL_SAFE_NO_PAD
+        .decode(body)
+        .map_err(|e| format!("Damaged share string: {e}"))?;
+    serde_json::from_slice(&json).map_err(|e| format!("Damaged share string: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        plan, prepare,
+        recipe::{ingredient_rows, levain_minutes, mixing_rows, notes},
+        repl,
+    };
+
+    #[test]
+    fn share_strings_round_trip() {
+        let tokens: Vec<String> =
+            "--w 270 --balls 4 --fridge-hours 16 --total-hours 24 --note proof=basil"
+                .split(' ')
+                .map(String::from)
+                .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let code = encode(&Profile::from(&args));
+        assert!(
+            code.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+        );
+
+        let back = decode(&format!(" `{code}`\n")).unwrap();
+        assert_eq!(back.w, 270);
+        assert_eq!(back.balls, 4);
+        assert_eq!(back.notes.len(), 1);
+        assert_eq!(encode(&back), code);
+        assert!(decode("pz1.!!").is_err());
+        assert!(decode("hello").is_err());
+    }
+
+    #[test]
+    fn opened_share_plans_the_same_recipe() {
+        let parse = |line: &str| {
+            let tokens: Vec<String> = line.split(' ').map(String::from).collect();
+            repl::parse(&tokens).unwrap()
+        };
+        let args = prepare(parse(
+            "--w 270 --total-hours 24 --fridge-hours 16 --yeast-pct 0.2 --yeast-viability 0.8 \
+             --malt --salt-type coarse --salt-after 20 --humidity 30 --oven wood --preheat 50 \
+             --mixer spiral --ddt 24 --levain 500 --levain-seed 20 --lang en",
+        ))
+        .unwrap();
+        let shared = decode(&encode(&Profile::from(&args))).unwrap();
+        let opened = crate::prepare_with(parse("--lang en"), Some(shared)).unwrap();
+        let (a, b) = (plan(&args).unwrap(), plan(&opened).unwrap());
+        assert_eq!(ingredient_rows(&a), ingredient_rows(&b));
+        assert_eq!(notes(&a), notes(&b));
+        assert_eq!(mixing_rows(&a), mixing_rows(&b));
+        assert_eq!(levain_minutes(&a), levain_minutes(&b));
+    }
+}
@@ -0,0 +1,180 @@
+//! `starter feed`: feed amounts, expected peak and readiness for the mix.
+//! `starter build`: a levain build that ends at the mix, printed as a prologue.
+
+use crate::recipe::{fmt_g, fmt_hhmm};
+use crate::units::{Temperature, fmt_temp};
+use chrono::{Duration, Local, NaiveDateTime, NaiveTime};
+use pizza_core::starter::{Feed, PEAK_WINDOW_H, levain_build, peak_hours};
+use std::str::FromStr;
+
+/// Feeding ratio `starter:flour:water`, e.g. `1:2:2`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FeedRatio {
+    pub starter: f64,
+    pub flour: f64,
+    pub water: f64,
+}
+
+impl FromStr for FeedRatio {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<f64> = s
+            .split(':')
+            .map(|p| p.trim().parse::<f64>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("expected STARTER:FLOUR:WATER (e.g. 1:2:2), got '{s}'"))?;
+        match parts[..] {
+            [starter, flour, water] if starter > 0.0 && flour >= 0.0 && water >= 0.0 => {
+                Ok(FeedRatio {
+                    starter,
+                    flour,
+                    water,
+                })
+            }
+            _ => Err(format!(
+                "expected STARTER:FLOUR:WATER (e.g. 1:2:2), got '{s}'"
+            )),
+        }
+    }
+}
+
+/// The next `HH:MM` at or after `from`.
+fn next_at(hhmm: &str, from: NaiveDateTime) -> Result<NaiveDateTime, String> {
+    let t = NaiveTime::parse_from_str(hhmm, "%H:%M")
+        .map_err(|_| format!("expected HH:MM, got '{hhmm}'"))?;
+    let at = from.date().and_time(t);
+    Ok(if at < from {
+        at + Duration::days(1)
+    } else {
+        at
+    })
+}
+
+fn fmt_hours(h: f64) -> String {
+    let min = (h * 60.0).round() as i64;
+    format!("{} h {:02} min", min / 60, min % 60)
+}
+
+/// Print the feed, its peak and, with `mix`, whether it is ready in time.
+pub fn feed(
+    grams: f64,
+    ratio: FeedRatio,
+    temp: Temperature,
+    at: Option<&str>,
+    mix: Option<&str>,
+) -> Result<(), String> {
+    if grams.is_nan() || grams <= 0.0 {
+        return Err("starter grams must be > 0".to_string());
+    }
+    let now = Local::now().naive_local();
+    let fed_at = match at {
+        Some(hhmm) => next_at(hhmm, now)?,
+        None => now,
+    };
+    let feed = Feed::from_ratio(grams, ratio.starter, ratio.flour, ratio.water);
+    let hours = peak_hours(feed.dilution(), temp.celsius);
+    let to_min = |h: f64| Duration::minutes((h * 60.0).round() as i64);
+    let peak = fed_at + to_min(hours);
+    let (ready_from, ready_until) = (peak - to_min(PEAK_WINDOW_H), peak + to_min(PEAK_WINDOW_H));
+
+    println!(
+        "=== Starter feed {}:{}:{} (room {}) ===",
+        ratio.starter,
+        ratio.flour,
+        ratio.water,
+        fmt_temp(temp.celsius, temp.unit)
+    );
+    println!(
+        "- {:<12}{} starter + {} flour + {} water = {}",
+        "Feed:",
+        fmt_g(feed.seed_g),
+        fmt_g(feed.flour_g),
+        fmt_g(feed.water_g),
+        fmt_g(feed.total_g())
+    );
+    println!("- {:<12}{}", "Fed at:", fmt_hhmm(fed_at));
+    println!(
+        "- {:<12}~{} after feeding, around {} (usable {}–{})",
+        "Peak:",
+        fmt_hours(hours),
+        fmt_hhmm(peak),
+        fmt_hhmm(ready_from),
+        fmt_hhmm(ready_until)
+    );
+
+    if let Some(hhmm) = mix {
+        let mix_at = next_at(hhmm, fed_at)?;
+        let feed_by = mix_at - to_min(hours);
+        let verdict = if mix_at < ready_from {
+            format!(
+                "not ready yet. Feed at {} instead, or keep it warmer.",
+                fmt_hhmm(feed_by)
+            )
+        } else if mix_at > ready_until {
+            format!(
+                "past its peak. Feed at {} instead, or feed more flour and water (e.g. 1:5:5).",
+                fmt_hhmm(feed_by)
+            )
+        } else {
+            "ready.".to_string()
+        };
+        println!("- {:<12}{} → {verdict}", "Mix:", fmt_hhmm(mix_at));
+    }
+    Ok(())
+}
+
+/// Print a one- or two-stage build of `target_g` levain from `seed_g` starter,
+/// timed to peak at `mix` (or started now), followed by the mix itself.
+pub fn build(
+    target_g: f64,
+    seed_g: f64,
+    temp: Temperature,
+    mix: Option<&str>,
+) -> Result<(), String> {
+    if target_g.is_nan() || target_g <= 0.0 || seed_g.is_nan() || seed_g <= 0.0 {
+        return Err("levain and seed grams must be > 0".to_string());
+    }
+    if seed_g >= target_g {
+        return Err(format!(
+            "{} of starter already covers the {} needed; no build required",
+            fmt_g(seed_g),
+            fmt_g(target_g)
+        ));
+    }
+    let stages = levain_build(target_g, seed_g, temp.celsius);
+    let to_min = |h: f64| Duration::minutes((h * 60.0).round() as i64);
+    let total: Duration = stages.iter().map(|s| to_min(s.hours)).sum();
+    let now = Local::now().naive_local();
+    let mut at = match mix {
+        Some(hhmm) => next_at(hhmm, now + total)? - total,
+        None => now,
+    };
+
+    println!(
+        "=== Levain build: {} from {} (room {}) ===",
+        fmt_g(target_g),
+        fmt_g(seed_g),
+        fmt_temp(temp.celsius, temp.unit)
+    );
+    for (i, stage) in stages.iter().enumerate() {
+        let f = stage.feed;
+        println!(
+            "- {:<12}{} feed {} starter + {} flour + {} water = {} (~{})",
+            format!("Stage {}:", i + 1),
+            fmt_hhmm(at),
+            fmt_g(f.seed_g),
+            fmt_g(f.flour_g),
+            fmt_g(f.water_g),
+            fmt_g(f.total_g()),
+            fmt_hours(stage.hours)
+        );
+        at += to_min(stage.hours);
+    }
+    println!(
+        "- {:<12}{} mix the dough with the levain at its peak",
+        "Mix:",
+        fmt_hhmm(at)
+    );
+    Ok(())
+}
@@ -0,0 +1,179 @@
+//! `sweep`: plan the same recipe over a range of one parameter (hydration,
+//! temperature or total hours) and compare yeast, amounts and phases side by
+//! side, to see how sensitive the recipe is to it.
+
+use crate::{Args, i18n::tr, recipe::plan_input, units::fmt_temp};
+use pizza_core::plan;
+use std::str::FromStr;
+
+/// Most rows a sweep prints.
+const MAX_STEPS: usize = 50;
+
+/// What `--vary` changes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Param {
+    Hydration,
+    Temp,
+    Hours,
+}
+
+/// `--vary PARAM=FROM..TO:STEP`, e.g. `hydration=0.60..0.80:0.05`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Vary {
+    pub param: Param,
+    pub from: f64,
+    pub to: f64,
+    pub step: f64,
+}
+
+impl FromStr for Vary {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad =
+            || format!("expected PARAM=FROM..TO:STEP (e.g. hydration=0.60..0.80:0.05), got '{s}'");
+        let (param, range) = s.split_once('=').ok_or_else(bad)?;
+        let param = match param.trim() {
+            "hydration" => Param::Hydration,
+            "temp" => Param::Temp,
+            "hours" | "total-hours" => Param::Hours,
+            other => {
+                return Err(format!(
+                    "cannot vary '{other}' (use hydration, temp or hours)"
+                ));
+            }
+        };
+        let (range, step) = range.split_once(':').ok_or_else(bad)?;
+        let (from, to) = range.split_once("..").ok_or_else(bad)?;
+        let num = |x: &str| x.trim().parse::<f64>().map_err(|_| bad());
+        let (mut from, mut to, mut step) = (num(from)?, num(to)?, num(step)?);
+        // 60..80:5 means percent, as for --hydration.
+        if param == Param::Hydration && to > 1.5 {
+            (from, to, step) = (from / 100.0, to / 100.0, step / 100.0);
+        }
+        if !(step > 0.0 && from <= to) {
+            return Err(format!(
+                "the step must be > 0 and FROM at most TO, got '{s}'"
+            ));
+        }
+        let vary = Vary {
+            param,
+            from,
+            to,
+            step,
+        };
+        if vary.values().len() > MAX_STEPS {
+            return Err(format!("at most {MAX_STEPS} steps, got '{s}'"));
+        }
+        Ok(vary)
+    }
+}
+
+impl Vary {
+    /// FROM, FROM + STEP, ... up to TO (included when the steps land on it).
+    pub fn values(&self) -> Vec<f64> {
+        let n = ((self.to - self.from) / self.step + 1e-9).floor() as usize;
+        (0..=n.min(MAX_STEPS))
+            .map(|i| self.from + i as f64 * self.step)
+            .collect()
+    }
+}
+
+/// Title of the sweep table, with the parameter.
+pub fn title(a: &Args, vary: &Vary) -> String {
+    tr!(a.lang(), "sweep", param = param_name(a, vary))
+}
+
+/// One row per value of the varied parameter (prepared `a` for the rest):
+/// the value, yeast in percent of flour, the amounts and the phase hours.
+/// Values the recipe cannot be planned with show why instead.
+pub fn rows(a: &Args, vary: &Vary) -> (Vec<String>, Vec<Vec<String>>) {
+    let lang = a.lang();
+    let mut header = vec![param_name(a, vary)];
+    header.extend(
+        [
+            "sweep-yeast-pct",
+            "ing-flour",
+            "ing-water",
+            "ing-yeast",
+            "sweep-bulk",
+            "sweep-fridge",
+            "sweep-proof",
+        ]
+        .map(|id| lang.text(id).to_string()),
+    );
+    let rows = vary
+        .values()
+        .into_iter()
+        .map(|v| {
+            let mut input = plan_input(a);
+            match vary.param {
+                Param::Hydration => input.hydration = v,
+                Param::Temp => input.temp_c = v,
+                Param::Hours => input.total_hours = v,
+            }
+            let label = match vary.param {
+                Param::Hydration => format!("{:.0}%", v * 100.0),
+                Param::Temp => fmt_temp(v, a.temp_units()),
+                Param::Hours => format!("{v} h"),
+            };
+            let valid = match a.expert {
+                true => input.validate_lenient(),
+                false => input.validate(),
+            };
+            if let Err(e) = valid {
+                return vec![label, e.to_string()];
+            }
+            let p = plan(input);
+            let (ing, tl) = (p.ingredients, p.timeline);
+            let flour = ing.flour_g + ing.binder_g + ing.rice_g + ing.soy_g;
+            vec![
+                label,
+                format!("{:.3}%", ing.yeast_g / flour * 100.0),
+                format!("{flour:.0} g"),
+                format!("{:.0} g", ing.water_g),
+                format!("{:.1} g", ing.yeast_g),
+                format!("{:.1} h", tl.bulk_h),
+                format!("{:.1} h", tl.fridge_h + tl.warmup_h),
+                format!("{:.1} h", tl.proof_h),
+            ]
+        })
+        .collect();
+    (header, rows)
+}
+
+/// The varied parameter, as a column header.
+fn param_name(a: &Args, vary: &Vary) -> String {
+    a.lang()
+        .text(match vary.param {
+            Param::Hydration => "sweep-hydration",
+            Param::Temp => "sweep-temp",
+            Param::Hours => "sweep-hours",
+        })
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prepare, repl};
+
+    #[test]
+    fn parses_ranges_and_plans_each_value() {
+        let vary: Vary = "hydration=60..80:5".parse().unwrap();
+        assert_eq!(vary.param, Param::Hydration);
+        assert_eq!(vary.values().len(), 5);
+        assert!((vary.values()[4] - 0.80).abs() < 1e-9);
+        assert!("salt=1..2:1".parse::<Vary>().is_err());
+        assert!("hours=24..12:2".parse::<Vary>().is_err());
+
+        let tokens: Vec<String> = "--w 280 --lang en".split(' ').map(String::from).collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let (header, rows) = rows(&args, &"temp=18..30:4".parse().unwrap());
+        assert_eq!(header.len(), 8);
+        assert_eq!(rows.len(), 4);
+        // Warmer means less yeast.
+        let pct = |row: &Vec<String>| row[1].trim_end_matches('%').parse::<f64>().unwrap();
+        assert!(pct(&rows[0]) > pct(&rows[3]), "{rows:?}");
+    }
+}
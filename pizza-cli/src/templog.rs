@@ -0,0 +1,174 @@
+//! `--temp-log`: kitchen temperatures recorded over time, from a CSV
+//! (`time,temp` rows, header optional) or a JSON array of
+//! `{"time": ..., "temp": ...}` objects. Each reading holds until the next
+//! one, and the fermentation model turns them into the single temperature
+//! with the same yeast activity.
+
+use crate::units::Temperature;
+use chrono::{DateTime, Local, NaiveDateTime};
+use pizza_core::{ambient::equivalent_temp_with, model::FermentationModel};
+use serde_json::Value;
+use std::{fs, path::Path};
+
+/// Readings in °C, oldest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TempLog {
+    pub readings: Vec<(NaiveDateTime, f64)>,
+}
+
+/// Reads `path`: JSON if it starts with `[`, CSV otherwise.
+pub fn read(path: &Path) -> Result<TempLog, String> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+    parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Parses a log in either format.
+pub fn parse(text: &str) -> Result<TempLog, String> {
+    let mut readings = match text.trim_start().starts_with('[') {
+        true => parse_json(text)?,
+        false => parse_csv(text)?,
+    };
+    if readings.is_empty() {
+        return Err("no temperature readings".to_string());
+    }
+    readings.sort_by_key(|&(at, _)| at);
+    Ok(TempLog { readings })
+}
+
+fn parse_csv(text: &str) -> Result<Vec<(NaiveDateTime, f64)>, String> {
+    let mut readings = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad = || format!("line {}: expected TIME,TEMP", i + 1);
+        let (time, temp) = line.split_once([',', ';', '\t']).ok_or_else(bad)?;
+        let (time, temp) = (time.trim().trim_matches('"'), temp.trim().trim_matches('"'));
+        match (timestamp(time), temp.parse::<Temperature>()) {
+            (Some(at), Ok(t)) => readings.push((at, t.celsius)),
+            // A header row.
+            (None, Err(_)) if readings.is_empty() => {}
+            _ => return Err(bad()),
+        }
+    }
+    Ok(readings)
+}
+
+fn parse_json(text: &str) -> Result<Vec<(NaiveDateTime, f64)>, String> {
+    let v: Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    let field = |o: &Value, keys: &[&str]| keys.iter().find_map(|k| o.get(k).cloned());
+    v.as_array()
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(i, o)| {
+            let bad = || format!("entry {}: expected time and temp", i + 1);
+            let at = match field(o, &["time", "timestamp", "at"]).ok_or_else(bad)? {
+                Value::String(s) => timestamp(&s),
+                Value::Number(n) => n.as_i64().and_then(unix),
+                _ => None,
+            };
+            let temp = match field(o, &["temp", "temperature", "celsius"]).ok_or_else(bad)? {
+                Value::String(s) => s.parse::<Temperature>().ok().map(|t| t.celsius),
+                Value::Number(n) => n.as_f64(),
+                _ => None,
+            };
+            at.zip(temp).ok_or_else(bad)
+        })
+        .collect()
+}
+
+/// RFC 3339 (in local time), `2025-06-01 18:30[:00]` or unix seconds.
+fn timestamp(s: &str) -> Option<NaiveDateTime> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(s) {
+        return Some(t.with_timezone(&Local).naive_local());
+    }
+    [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|f| NaiveDateTime::parse_from_str(s, f).ok())
+    .or_else(|| s.parse().ok().and_then(unix))
+}
+
+fn unix(secs: i64) -> Option<NaiveDateTime> {
+    DateTime::from_timestamp(secs, 0).map(|t| t.with_timezone(&Local).naive_local())
+}
+
+impl TempLog {
+    pub fn first(&self) -> NaiveDateTime {
+        self.readings[0].0
+    }
+
+    /// The last reading at or before `t`.
+    pub fn at(&self, t: NaiveDateTime) -> Option<f64> {
+        self.readings.iter().rev().find(|r| r.0 <= t).map(|r| r.1)
+    }
+
+    /// Hours and °C between `from` and `to`; the last reading holds until `to`.
+    pub fn segments(&self, from: NaiveDateTime, to: NaiveDateTime) -> Vec<(f64, f64)> {
+        let mut segments = Vec::new();
+        for (i, &(at, temp)) in self.readings.iter().enumerate() {
+            let end = self.readings.get(i + 1).map_or(to, |r| r.0).min(to);
+            let start = at.max(from);
+            if end > start {
+                let hours = (end - start).num_seconds() as f64 / 3600.0;
+                segments.push((hours, temp));
+            }
+        }
+        segments
+    }
+
+    /// Temperature as active for `model` as the readings between `from` and `to`.
+    pub fn equivalent_temp(
+        &self,
+        model: &dyn FermentationModel,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Option<f64> {
+        equivalent_temp_with(model, &self.segments(from, to))
+    }
+
+    /// Over the whole log: a single reading is the temperature itself.
+    pub fn overall(&self, model: &dyn FermentationModel) -> f64 {
+        let (first, last) = (self.first(), self.readings[self.readings.len() - 1]);
+        self.equivalent_temp(model, first, last.0).unwrap_or(last.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pizza_core::{ambient::equivalent_temp, model::Q10};
+
+    #[test]
+    fn csv_and_json_logs_integrate_by_time() {
+        let csv = "time;temp\n2025-06-01 20:00;20\n2025-06-01 18:00;30\n2025-06-01 22:00;77F\n";
+        let log = parse(csv).unwrap();
+        assert_eq!(log.readings.len(), 3);
+        assert_eq!(log.readings[0].1, 30.0);
+        // 2 h at 30 °C and 2 h at 20 °C, as with a schedule.
+        let t = equivalent_temp(&[(2.0, 20.0), (2.0, 30.0)]).unwrap();
+        assert!((log.overall(&Q10::default()) - t).abs() < 1e-6);
+
+        let json = r#"[{"time": "2025-06-01T18:00:00", "temp": 24},
+                       {"timestamp": "2025-06-01 19:30", "temperature": "25C"}]"#;
+        let log = parse(json).unwrap();
+        let from = log.first() + chrono::Duration::minutes(30);
+        let to = log.first() + chrono::Duration::hours(3);
+        assert_eq!(log.segments(from, to), vec![(1.0, 24.0), (1.5, 25.0)]);
+        assert_eq!(log.at(from), Some(24.0));
+        assert_eq!(log.at(from - chrono::Duration::hours(1)), None);
+
+        assert!(parse("time,temp\n").is_err());
+        assert_eq!(
+            parse("2025-06-01 18:00,warm\n"),
+            Err("line 1: expected TIME,TEMP".to_string())
+        );
+    }
+}
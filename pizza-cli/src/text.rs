@@ -0,0 +1,268 @@
+//! Terminal rendering: ingredient table, timeline and notes.
+
+use crate::Args;
+use crate::compare;
+use crate::config::Prices;
+use crate::i18n::tr;
+use crate::recipe::{
+    Recipe, cost_rows, detroit_rows, detroit_title, explain_rows, fmt_effort, fmt_hhmm, fmt_room,
+    ingredient_rows, launch_rows, launches_title, mixing_rows, mixing_title, notes, nutrition_rows,
+    per_ball_rows, phase_rows, sauce_rows, sauce_title, topping_rows, toppings_title, total_hours,
+    warnings,
+};
+use crate::sweep::{self, Vary};
+use crate::theme::{self, Role, glyphs};
+use comfy_table::{Attribute, Cell, ContentArrangement, Table, presets::UTF8_FULL};
+use pizza_core::flours::FLOURS;
+
+/// Print the full recipe: ingredients, timeline and notes.
+pub fn print_recipe(r: &Recipe) {
+    print_ingredients(r);
+    if r.args.per_ball {
+        print_per_ball(r);
+    }
+    if r.args.nutrition {
+        print_nutrition(r);
+    }
+    if let Some(p) = &r.prices {
+        print_cost(r, p);
+    }
+    if r.args.toppings.is_some() {
+        print_toppings(r);
+    }
+    if r.args.detroit_pan().is_some() {
+        print_grid(&detroit_title(r), detroit_rows(r));
+    }
+    if r.args.sauce {
+        print_sauce(r);
+    }
+    if r.args.kneading().is_some() {
+        print_grid(&mixing_title(r), mixing_rows(r));
+    }
+    print_timeline(r);
+    if r.args.launches {
+        print_launches(r);
+    }
+    print_notes(r);
+    if r.args.explain {
+        print_explain(r);
+    }
+}
+
+fn cell(s: &str) -> Cell {
+    Cell::new(glyphs(s))
+}
+
+/// An empty table in the house style.
+fn new_table() -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    theme::current().style_table(&mut table);
+    table
+}
+
+pub fn print_ingredients(r: &Recipe) {
+    let lang = r.args.lang();
+    let theme = theme::current();
+    let mut table = new_table();
+    table.set_header(
+        ["col-ingredient", "col-amount", "col-notes"]
+            .map(|id| cell(lang.text(id)).add_attribute(Attribute::Bold)),
+    );
+    for [name, amount, notes] in ingredient_rows(r) {
+        table.add_row(vec![
+            cell(&name),
+            theme.cell(Role::Amount, &amount),
+            cell(&notes),
+        ]);
+    }
+
+    println!("\n=== {} ===", glyphs(lang.text("ingredients-summary")));
+    println!("{}", table);
+}
+
+/// Print flour/water/salt/yeast for a single ball of each size.
+pub fn print_per_ball(r: &Recipe) {
+    print_grid(r.args.lang().text("per-ball"), per_ball_rows(r));
+}
+
+/// Print kcal and macronutrients per ball and per 100 g.
+pub fn print_nutrition(r: &Recipe) {
+    print_grid(r.args.lang().text("nutrition-estimate"), nutrition_rows(r));
+}
+
+/// Print the cost of each ingredient, the batch and one ball.
+pub fn print_cost(r: &Recipe, p: &Prices) {
+    let lang = r.args.lang();
+    let header = ["cost", "col-amount", "col-price"]
+        .map(|id| lang.text(id).to_string())
+        .to_vec();
+    let rows = cost_rows(r, p).into_iter().map(Vec::from).collect();
+    print_grid(lang.text("cost"), (header, rows));
+}
+
+/// Print sauce, mozzarella and basil per pizza.
+pub fn print_toppings(r: &Recipe) {
+    print_grid(&toppings_title(r), topping_rows(r));
+}
+
+/// Print the sauce for the batch.
+pub fn print_sauce(r: &Recipe) {
+    let lang = r.args.lang();
+    let header = ["col-ingredient", "col-amount", "col-notes"]
+        .map(|id| lang.text(id).to_string())
+        .to_vec();
+    let rows = sauce_rows(r).into_iter().map(Vec::from).collect();
+    print_grid(&sauce_title(r), (header, rows));
+}
+
+/// Print when each pizza goes in and comes out.
+pub fn print_launches(r: &Recipe) {
+    let lang = r.args.lang();
+    let header = ["col-pizza", "col-in", "col-out"]
+        .map(|id| lang.text(id).to_string())
+        .to_vec();
+    let rows = launch_rows(r).into_iter().map(Vec::from).collect();
+    print_grid(&launches_title(r), (header, rows));
+}
+
+/// Print a titled table from a header and rows.
+fn print_grid(title: &str, (header, rows): (Vec<String>, Vec<Vec<String>>)) {
+    let mut table = new_table();
+    table.set_header(
+        header
+            .iter()
+            .map(|h| cell(h).add_attribute(Attribute::Bold))
+            .collect::<Vec<_>>(),
+    );
+    for row in rows {
+        table.add_row(row.iter().map(|c| cell(c)).collect::<Vec<_>>());
+    }
+    println!("\n=== {} ===", glyphs(title));
+    println!("{}", table);
+}
+
+pub fn print_timeline(r: &Recipe) {
+    let lang = r.args.lang();
+    let title = tr!(lang, "timeline", room = fmt_room(r));
+    println!("\n=== {} ===", glyphs(&title));
+    for row in phase_rows(r) {
+        let line = format!(
+            "- {:<25}{:.1} h{}",
+            format!("{}:", row.label),
+            row.hours,
+            match row.end {
+                Some(t) => format!(" {}", tr!(lang, "ends-at", time = fmt_hhmm(t))),
+                None => "".to_string(),
+            }
+        );
+        println!("{}", glyphs(&line));
+        for note in row.notes {
+            println!("{}", glyphs(&format!("    ↳ {note}")));
+        }
+    }
+    let label = |id| format!("{}:", lang.text(id));
+    println!("- {:<25}{:.1} h", label("total"), total_hours(&r.tl));
+    println!("- {:<25}{}", label("hands-on"), glyphs(&fmt_effort(r)));
+}
+
+pub fn print_notes(r: &Recipe) {
+    println!("\n{}:", r.args.lang().text("notes"));
+    let bullet = glyphs("•");
+    let warnings = warnings(r).len();
+    for (i, note) in notes(r).iter().enumerate() {
+        let note = glyphs(note);
+        match i < warnings {
+            true => println!("{bullet} {}", theme::current().paint(Role::Warning, &note)),
+            false => println!("{bullet} {note}"),
+        }
+    }
+}
+
+/// Print how the yeast and flour were derived (`--explain`).
+pub fn print_explain(r: &Recipe) {
+    let lang = r.args.lang();
+    let header = ["col-step", "col-formula", "col-result"]
+        .map(|id| lang.text(id).to_string())
+        .to_vec();
+    let rows = explain_rows(r).into_iter().map(Vec::from).collect();
+    print_grid(lang.text("explain"), (header, rows));
+}
+
+/// Print the built-in flour list.
+/// Print `compare` rows, with the values that differ highlighted.
+pub fn print_compare(header: Vec<String>, rows: Vec<compare::Row>) {
+    let theme = theme::current();
+    let mut table = new_table();
+    table.set_header(
+        header
+            .iter()
+            .map(|h| cell(h).add_attribute(Attribute::Bold))
+            .collect::<Vec<_>>(),
+    );
+    for [label, a, b, delta] in rows {
+        let value = |v: &str| match delta.is_empty() {
+            true => cell(v),
+            false => theme.cell(Role::Warning, v),
+        };
+        table.add_row(vec![cell(&label), value(&a), value(&b), cell(&delta)]);
+    }
+    println!("{}", table);
+}
+
+/// Print the recipe over the `sweep` range.
+pub fn print_sweep(a: &Args, vary: &Vary) {
+    print_grid(&sweep::title(a, vary), sweep::rows(a, vary));
+}
+
+pub fn print_flours() {
+    let header = ["Flour", "W", "Protein", "Absorption", "Also known as"]
+        .map(String::from)
+        .to_vec();
+    let rows = FLOURS
+        .iter()
+        .map(|f| {
+            vec![
+                f.name.to_string(),
+                f.w.to_string(),
+                format!("{:.1}%", f.protein_pct),
+                format!("{:.0}%", f.absorption * 100.0),
+                f.aliases.join(", "),
+            ]
+        })
+        .collect();
+    print_grid("Flours", (header, rows));
+}
+
+/// Print a profile as a two-column key/value table.
+pub fn print_profile(p: &serde_json::Value) {
+    let mut table = new_table();
+    table.set_header(vec![
+        cell("Parameter").add_attribute(Attribute::Bold),
+        cell("Value").add_attribute(Attribute::Bold),
+    ]);
+    if let Some(obj) = p.as_object() {
+        for (k, v) in obj {
+            table.add_row(vec![cell(k), cell(&fmt_value(v))]);
+        }
+    }
+    println!("{}", table);
+}
+
+/// Human-friendly rendering of a JSON value (one line per array element).
+fn fmt_value(v: &serde_json::Value) -> String {
+    use serde_json::Value;
+    match v {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        Value::Array(items) => items.iter().map(fmt_value).collect::<Vec<_>>().join("\n"),
+        Value::Object(obj) => obj
+            .iter()
+            .map(|(k, v)| format!("{k}={}", fmt_value(v)))
+            .collect::<Vec<_>>()
+            .join(" "),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,198 @@
+//! Terminal colours for ingredient amounts, warnings and the next action.
+//! `--theme` picks a palette for dark or light backgrounds, or none at all.
+//! Without it, output that is not a terminal and dumb terminals
+//! (`TERM=dumb`) get plain text; `NO_COLOR` and `--no-color` always do.
+//!
+//! `--ascii`, or a locale that is not UTF-8, swaps box drawing and symbols
+//! for ASCII.
+
+use clap::ValueEnum;
+use comfy_table::{Cell, Color as CellColor, Table, presets::ASCII_FULL};
+use ratatui::crossterm::style::{Color, Stylize};
+use std::{
+    borrow::Cow,
+    env,
+    io::{self, IsTerminal},
+    sync::OnceLock,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Theme {
+    /// Bright colours for dark backgrounds
+    Dark,
+    /// Deeper colours for light backgrounds
+    Light,
+    /// No colours or bold text
+    Plain,
+}
+
+/// What a piece of text is, for its colour.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Role {
+    Amount,
+    Warning,
+    Action,
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+static ASCII: OnceLock<bool> = OnceLock::new();
+
+/// `NO_COLOR` set to anything but the empty string (https://no-color.org).
+fn no_color_env() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// The theme without `--theme`.
+fn detect() -> Theme {
+    let dumb = env::var("TERM").is_ok_and(|t| t == "dumb");
+    if no_color_env() || dumb || !io::stdout().is_terminal() {
+        Theme::Plain
+    } else {
+        Theme::Dark
+    }
+}
+
+/// Whether the locale (`LC_ALL`, `LC_CTYPE`, `LANG`) is UTF-8; taken to be
+/// when none is set.
+fn utf8_locale() -> bool {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|k| env::var(k).ok())
+        .find(|v| !v.is_empty())
+        .is_none_or(|v| {
+            let v = v.to_lowercase();
+            v.contains("utf-8") || v.contains("utf8")
+        })
+}
+
+/// Set the theme for this run; call once, before anything is printed.
+pub fn init(chosen: Option<Theme>, no_color: bool, ascii: bool) {
+    let theme = match chosen {
+        _ if no_color || no_color_env() => Theme::Plain,
+        Some(t) => t,
+        None => detect(),
+    };
+    let _ = THEME.set(theme);
+    let _ = ASCII.set(ascii || !utf8_locale());
+}
+
+/// Whether to stay within ASCII.
+pub fn ascii() -> bool {
+    *ASCII.get_or_init(|| !utf8_locale())
+}
+
+/// `s` with the symbols the renderers use replaced by ASCII look-alikes, in
+/// ASCII mode. Accented vowels get the Italian apostrophe (`à` → `a'`).
+pub fn glyphs(s: &str) -> Cow<'_, str> {
+    if !ascii() || s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '×' => out.push('x'),
+            '÷' => out.push('/'),
+            '≈' => out.push('~'),
+            '→' => out.push_str("->"),
+            '↳' | '·' | '–' | '—' => out.push('-'),
+            '•' => out.push('*'),
+            '✓' => out.push('x'),
+            '°' => {}
+            '…' => out.push_str("..."),
+            '¼' => out.push_str("1/4"),
+            '½' => out.push_str("1/2"),
+            '¾' => out.push_str("3/4"),
+            '⅓' => out.push_str("1/3"),
+            '⅔' => out.push_str("2/3"),
+            '⅛' => out.push_str("1/8"),
+            '⅜' => out.push_str("3/8"),
+            '⅝' => out.push_str("5/8"),
+            '⅞' => out.push_str("7/8"),
+            'à' => out.push_str("a'"),
+            'è' | 'é' => out.push_str("e'"),
+            'ì' => out.push_str("i'"),
+            'ò' => out.push_str("o'"),
+            'ù' => out.push_str("u'"),
+            c => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+pub fn current() -> Theme {
+    *THEME.get_or_init(detect)
+}
+
+/// The theme for messages on stderr, plain when it is redirected.
+pub fn stderr() -> Theme {
+    match io::stderr().is_terminal() {
+        true => current(),
+        false => Theme::Plain,
+    }
+}
+
+impl Theme {
+    /// 256-colour palette index for `role`.
+    fn color(self, role: Role) -> Option<u8> {
+        match (self, role) {
+            (Theme::Plain, _) => None,
+            (Theme::Dark, Role::Amount) => Some(114),
+            (Theme::Dark, Role::Warning) => Some(214),
+            (Theme::Dark, Role::Action) => Some(203),
+            (Theme::Light, Role::Amount) => Some(28),
+            (Theme::Light, Role::Warning) => Some(130),
+            (Theme::Light, Role::Action) => Some(160),
+        }
+    }
+
+    /// A table cell coloured for `role`.
+    pub fn cell(self, role: Role, text: &str) -> Cell {
+        let cell = Cell::new(glyphs(text));
+        match self.color(role) {
+            Some(c) => cell.fg(CellColor::AnsiValue(c)),
+            None => cell,
+        }
+    }
+
+    /// `text` with the escape codes for `role` (bold for actions).
+    pub fn paint(self, role: Role, text: &str) -> String {
+        match (self.color(role), role) {
+            (None, _) => text.to_string(),
+            (Some(c), Role::Action) => text.with(Color::AnsiValue(c)).bold().to_string(),
+            (Some(c), _) => text.with(Color::AnsiValue(c)).to_string(),
+        }
+    }
+
+    /// Table styling (bold headers) follows the theme rather than comfy-table's
+    /// own terminal check, so an explicit `--theme` also colours piped output.
+    /// The borders are ASCII in ASCII mode.
+    pub fn style_table(self, table: &mut Table) {
+        if ascii() {
+            table.load_preset(ASCII_FULL);
+        }
+        match self {
+            Theme::Plain => table.force_no_tty(),
+            _ => table.enforce_styling(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_output_has_no_escape_codes() {
+        assert_eq!(
+            Theme::Plain.paint(Role::Warning, "Hot kitchen"),
+            "Hot kitchen"
+        );
+        let mut table = Table::new();
+        Theme::Plain.style_table(&mut table);
+        table.add_row(vec![Theme::Plain.cell(Role::Amount, "316 g")]);
+        assert!(!table.to_string().contains('\x1b'));
+
+        let painted = Theme::Light.paint(Role::Action, "Bake");
+        assert!(painted.starts_with("\x1b[") && painted.contains("Bake"));
+    }
+}
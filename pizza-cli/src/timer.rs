@@ -0,0 +1,158 @@
+//! `timer`: a countdown to the next action on a single terminal line that
+//! keeps running until it is time to bake, ringing the bell when an action
+//! comes due. Built with the `notify` feature it also raises a desktop
+//! notification. The dashboard (`tui`) shares the action list from here.
+
+use crate::{
+    recipe::{Recipe, first_action, fmt_hhmm, phase_rows, prep},
+    theme::{self, Role},
+};
+use chrono::{Local, NaiveDateTime};
+use std::{
+    io::{self, IsTerminal, Write},
+    thread,
+    time::Duration,
+};
+
+/// Every action with its time: mixing (or feeding a `--levain`) at the start, the end of each phase
+/// and the jobs ahead of baking (preheating, toppings). Empty without a
+/// start time.
+pub fn actions(r: &Recipe) -> Vec<(String, NaiveDateTime)> {
+    let (first, at) = first_action(r);
+    let start = at.map(|s| (first.to_string(), s));
+    let mut actions: Vec<_> = start
+        .into_iter()
+        .chain(
+            phase_rows(r)
+                .into_iter()
+                .filter_map(|row| row.end.map(|end| (row.action.to_string(), end))),
+        )
+        .collect();
+    if let Some(bake) = r.ends.proof {
+        actions.extend(
+            prep(r)
+                .into_iter()
+                .map(|(min, job)| (job, bake - chrono::Duration::minutes(min))),
+        );
+        actions.sort_by_key(|&(_, at)| at);
+    }
+    actions
+}
+
+/// Next action and its time, e.g. "Ball the dough" at 18:30.
+pub fn next_action(r: &Recipe, now: NaiveDateTime) -> Option<(String, NaiveDateTime)> {
+    actions(r).into_iter().find(|(_, at)| *at > now)
+}
+
+/// Actions that came due after `since`, up to and including `now`.
+pub fn due(r: &Recipe, since: NaiveDateTime, now: NaiveDateTime) -> Vec<(String, NaiveDateTime)> {
+    actions(r)
+        .into_iter()
+        .filter(|(_, at)| since < *at && *at <= now)
+        .collect()
+}
+
+/// Desktop notification for an action; failures (no notification daemon)
+/// are ignored.
+#[cfg(feature = "notify")]
+pub fn notify(action: &str) {
+    let _ = notify_rust::Notification::new()
+        .summary(&format!("{action} now"))
+        .body("pizza-cli")
+        .appname("pizza-cli")
+        .show();
+}
+
+/// Minutes as `1 h 05 min` or `25 min`.
+pub fn fmt_remaining(mins: i64) -> String {
+    if mins >= 60 {
+        format!("{} h {:02} min", mins / 60, mins % 60)
+    } else {
+        format!("{mins} min")
+    }
+}
+
+/// `H:MM:SS` until the next action.
+fn fmt_countdown(secs: i64) -> String {
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/// Count down until the last action. Without a terminal only the due actions
+/// are printed, one per line.
+pub fn run(r: &Recipe) -> io::Result<()> {
+    let mut out = io::stdout();
+    let tty = out.is_terminal();
+    let theme = theme::current();
+    for (action, at) in actions(r) {
+        writeln!(out, "{}  {action}", fmt_hhmm(at))?;
+    }
+    writeln!(out)?;
+
+    let mut last = Local::now().naive_local();
+    loop {
+        let now = Local::now().naive_local();
+        for (action, at) in due(r, last, now) {
+            if tty {
+                write!(out, "\r\x1b[K")?;
+            }
+            let painted = theme.paint(Role::Action, &action);
+            writeln!(out, "\x07{}  {painted} now", fmt_hhmm(at))?;
+            #[cfg(feature = "notify")]
+            notify(&action);
+        }
+        last = now;
+        let Some((action, at)) = next_action(r, now) else {
+            writeln!(out, "All phases done. Time to bake!")?;
+            return Ok(());
+        };
+        if tty {
+            write!(
+                out,
+                "\r\x1b[KNext: {} at {} (in {})",
+                theme.paint(Role::Action, &action),
+                fmt_hhmm(at),
+                fmt_countdown((at - now).num_seconds())
+            )?;
+            out.flush()?;
+        }
+        thread::sleep(Duration::from_secs(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plan, prepare, repl};
+
+    #[test]
+    fn actions_come_due_in_order() {
+        let tokens: Vec<String> = "--w 260 --total-hours 8 --start 10:00 --lang en"
+            .split(' ')
+            .map(String::from)
+            .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let r = plan(&args).unwrap();
+        let all = actions(&r);
+        assert_eq!(all[0].0, "Mix the dough");
+        assert_eq!(all.last().unwrap().0, "Bake");
+
+        let day = r.start.unwrap().date();
+        let at = |h, m| day.and_hms_opt(h, m, 0).unwrap();
+        assert_eq!(next_action(&r, at(9, 0)).unwrap().0, "Mix the dough");
+        let mixed = due(&r, at(9, 59), at(10, 0));
+        assert_eq!(mixed.len(), 1);
+        assert!(due(&r, at(10, 0), at(10, 1)).is_empty());
+        assert_eq!(due(&r, at(9, 0), at(18, 0)).len(), all.len());
+        assert_eq!(fmt_countdown(3_723), "1:02:03");
+
+        let tokens: Vec<String> = "--w 260 --total-hours 8 --start 10:00 --lang en --oven gas"
+            .split(' ')
+            .map(String::from)
+            .collect();
+        let args = prepare(repl::parse(&tokens).unwrap()).unwrap();
+        let r = plan(&args).unwrap();
+        let (preheat, when) = &actions(&r)[actions(&r).len() - 2];
+        assert!(preheat.starts_with("Start preheating"), "{preheat}");
+        assert_eq!(*when, at(17, 35));
+    }
+}
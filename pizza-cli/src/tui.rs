@@ -0,0 +1,188 @@
+//! Full-screen dashboard: ingredients, a Gantt-style timeline and a live
+//! countdown to the next action. Redraws every minute; `q`/`Esc` quits.
+//! Built with the `notify` feature, it also raises a desktop notification
+//! when an action comes due.
+
+#[cfg(feature = "notify")]
+use crate::timer::{due, notify};
+use crate::{
+    i18n::tr,
+    recipe::{Recipe, first_action, fmt_hhmm, fmt_room, ingredient_rows, phase_rows},
+    timer::{fmt_remaining, next_action},
+};
+use chrono::{Local, NaiveDateTime, Timelike};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Row, Table},
+};
+use std::{io, time::Duration};
+
+const PHASE_COLORS: [Color; 4] = [Color::Yellow, Color::Cyan, Color::Magenta, Color::Green];
+const LABEL_WIDTH: usize = 26;
+/// Room for the " HH:MM" end time after each bar.
+const END_WIDTH: usize = 6;
+
+/// Run the dashboard until the user quits.
+pub fn run(r: &Recipe) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let res = event_loop(&mut terminal, r);
+    ratatui::restore();
+    res
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, r: &Recipe) -> io::Result<()> {
+    #[cfg(feature = "notify")]
+    let mut last = Local::now().naive_local();
+    loop {
+        let now = Local::now().naive_local();
+        #[cfg(feature = "notify")]
+        {
+            for (action, _) in due(r, last, now) {
+                notify(&action);
+            }
+            last = now;
+        }
+        terminal.draw(|f| draw(f, r, now))?;
+
+        // Sleep until the next minute boundary, waking up early for input/resize.
+        let wait = Duration::from_secs(60 - u64::from(now.second()));
+        if event::poll(wait)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn draw(f: &mut Frame, r: &Recipe, now: NaiveDateTime) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(9),
+        Constraint::Length(4),
+    ])
+    .areas(f.area());
+    let [left, right] =
+        Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(body);
+
+    f.render_widget(
+        Line::from(vec![
+            " pizza-cli ".bold().reversed(),
+            format!("  now {}  ·  q to quit", fmt_hhmm(now)).dim(),
+        ]),
+        header,
+    );
+    draw_ingredients(f, r, left);
+    draw_gantt(f, r, now, right);
+    draw_countdown(f, r, now, footer);
+}
+
+fn draw_ingredients(f: &mut Frame, r: &Recipe, area: Rect) {
+    let lang = r.args.lang();
+    let rows = ingredient_rows(r).into_iter().map(|[name, amount, notes]| {
+        Row::new(vec![
+            Span::raw(name),
+            Span::styled(amount, Style::new().add_modifier(Modifier::BOLD)),
+            Span::raw(notes).dim(),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(11),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(Row::new(["col-ingredient", "col-amount", "col-notes"].map(|id| lang.text(id))).bold())
+    .block(Block::bordered().title(format!(" {} ", lang.text("ingredients"))));
+    f.render_widget(table, area);
+}
+
+fn draw_gantt(f: &mut Frame, r: &Recipe, now: NaiveDateTime, area: Rect) {
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let bar_width = inner_width.saturating_sub(LABEL_WIDTH + END_WIDTH).max(1);
+    let rows = phase_rows(r);
+    // With a --levain build, the bars start at its first feed.
+    let total = rows
+        .iter()
+        .map(|row| row.hours)
+        .sum::<f64>()
+        .max(f64::EPSILON);
+    let now_h = first_action(r)
+        .1
+        .map(|s| (now - s).num_minutes() as f64 / 60.0)
+        .unwrap_or(-1.0);
+    let col = |h: f64| ((h / total) * bar_width as f64).round() as usize;
+
+    let mut lines = Vec::new();
+    let mut offset_h = 0.0;
+    for (i, row) in rows.iter().enumerate() {
+        let (from, to) = (
+            col(offset_h),
+            col(offset_h + row.hours).max(col(offset_h) + 1),
+        );
+        let color = PHASE_COLORS[i % PHASE_COLORS.len()];
+        let done = (col(now_h.clamp(0.0, total))).clamp(from, to);
+        let mut spans = vec![
+            Span::raw(format!("{:<LABEL_WIDTH$}", row.label)),
+            Span::raw(" ".repeat(from)),
+            Span::styled("█".repeat(done - from), Style::new().fg(Color::DarkGray)),
+            Span::styled("█".repeat(to - done), Style::new().fg(color)),
+        ];
+        if let Some(end) = row.end {
+            spans.push(Span::raw(format!(" {}", fmt_hhmm(end))).dim());
+        }
+        lines.push(Line::from(spans));
+        for note in &row.notes {
+            lines.push(Line::from(format!("  ↳ {note}")).italic().dim());
+        }
+        offset_h += row.hours;
+    }
+    if (0.0..=total).contains(&now_h) {
+        lines.push(Line::from(vec![
+            Span::raw(" ".repeat(LABEL_WIDTH + col(now_h))),
+            Span::raw("▲ now").bold(),
+        ]));
+    }
+    let lang = r.args.lang();
+    lines.push(Line::from(format!("{:<LABEL_WIDTH$}{total:.1} h", lang.text("total"))).dim());
+
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::bordered().title(format!(" {} ", tr!(lang, "timeline", room = fmt_room(r)))),
+        ),
+        area,
+    );
+}
+
+fn draw_countdown(f: &mut Frame, r: &Recipe, now: NaiveDateTime, area: Rect) {
+    let line = match next_action(r, now) {
+        Some((action, at)) => Line::from(vec![
+            "Next: ".into(),
+            Span::raw(action).bold().fg(Color::LightRed),
+            format!(" at {}", fmt_hhmm(at)).into(),
+            format!(
+                "  (in {})",
+                fmt_remaining(((at - now).num_seconds() + 59) / 60)
+            )
+            .bold(),
+        ]),
+        None if r.start.is_some() => Line::from("All phases done. Time to bake!".bold()),
+        None => Line::from("No start time: pass --start HH:MM for a countdown.".dim()),
+    };
+    f.render_widget(
+        Paragraph::new(line).block(Block::bordered().title(" Countdown ")),
+        area,
+    );
+}
@@ -0,0 +1,206 @@
+//! Metric or US customary presentation of amounts and temperatures.
+//!
+//! All computation stays in grams and °C; only the rendered strings change.
+
+use crate::{i18n::Lang, recipe::fmt_g};
+use clap::ValueEnum;
+use pizza_core::{celsius_to_fahrenheit, fahrenheit_to_celsius};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Measurement system for displayed amounts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Units {
+    /// Grams and °C
+    #[default]
+    Metric,
+    /// Ounces/pounds, teaspoons and °F
+    Imperial,
+}
+
+pub const GRAMS_PER_OZ: f64 = 28.349_523_125;
+
+/// Approximate grams per level teaspoon (instant dry yeast, crumbled fresh
+/// yeast, fine table salt, diastatic malt powder).
+pub const DRY_YEAST_G_PER_TSP: f64 = 3.1;
+pub const FRESH_YEAST_G_PER_TSP: f64 = 3.0;
+pub const FINE_SALT_G_PER_TSP: f64 = 6.0;
+pub const MALT_G_PER_TSP: f64 = 2.5;
+
+/// Salt as it goes into the dough; coarser crystals pack fewer grams per spoon.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SaltType {
+    /// Fine table or fine sea salt
+    #[default]
+    Fine,
+    /// Coarse sea salt
+    Coarse,
+    /// Flaky salt (e.g. Maldon)
+    Flaky,
+}
+
+impl SaltType {
+    /// Approximate grams per level teaspoon.
+    pub fn g_per_tsp(self) -> f64 {
+        match self {
+            SaltType::Fine => FINE_SALT_G_PER_TSP,
+            SaltType::Coarse => 5.0,
+            SaltType::Flaky => 2.5,
+        }
+    }
+
+    /// How to get it to dissolve in the dough, when it needs care.
+    pub fn guidance(self, lang: Lang) -> Option<&'static str> {
+        match self {
+            SaltType::Fine => None,
+            SaltType::Coarse => Some(lang.text("note-salt-coarse")),
+            SaltType::Flaky => Some(lang.text("note-salt-flaky")),
+        }
+    }
+}
+
+/// Flour/water-sized amounts: grams, or ounces (pounds and ounces from 1 lb).
+pub fn fmt_weight(g: f64, units: Units) -> String {
+    match units {
+        Units::Metric => fmt_g(g),
+        Units::Imperial => {
+            let oz = g / GRAMS_PER_OZ;
+            if oz >= 16.0 {
+                let lb = (oz / 16.0).floor();
+                format!("{lb:.0} lb {:.1} oz", oz - lb * 16.0)
+            } else {
+                format!("{oz:.1} oz")
+            }
+        }
+    }
+}
+
+/// Pinch-sized amounts (yeast, salt): grams with an approximate teaspoon
+/// equivalent, or teaspoons at `g_per_tsp`.
+pub fn fmt_spoons(g: f64, g_per_tsp: f64, units: Units) -> String {
+    match units {
+        Units::Metric => format!("{} (≈ {})", fmt_g(g), fmt_tsp(g / g_per_tsp)),
+        Units::Imperial => format!("{:.2} tsp", g / g_per_tsp),
+    }
+}
+
+/// Topping-sized amounts, rounded to 5 g or ½ oz.
+pub fn fmt_rough_weight(g: f64, units: Units) -> String {
+    fmt_rough_range(g, g, units)
+}
+
+/// A range of topping amounts such as `80–100 g`, rounded like
+/// [`fmt_rough_weight`]; a single amount when both ends round the same.
+pub fn fmt_rough_range(lo: f64, hi: f64, units: Units) -> String {
+    let (lo, hi, unit) = match units {
+        Units::Metric => ((lo / 5.0).round() * 5.0, (hi / 5.0).round() * 5.0, "g"),
+        Units::Imperial => {
+            let oz = |g: f64| (g / GRAMS_PER_OZ * 2.0).round() / 2.0;
+            (oz(lo), oz(hi), "oz")
+        }
+    };
+    match lo == hi {
+        true => format!("{lo} {unit}"),
+        false => format!("{lo}–{hi} {unit}"),
+    }
+}
+
+/// A pizza diameter: centimetres, or inches.
+pub fn fmt_length(cm: f64, units: Units) -> String {
+    match units {
+        Units::Metric => format!("{cm:.0} cm"),
+        Units::Imperial => format!("{:.0} in", cm / 2.54),
+    }
+}
+
+/// Teaspoons rounded to the nearest eighth, as a measuring-spoon fraction.
+pub fn fmt_tsp(tsp: f64) -> String {
+    const EIGHTHS: [&str; 8] = ["", "⅛", "¼", "⅜", "½", "⅝", "¾", "⅞"];
+    let eighths = (tsp * 8.0).round() as u64;
+    if eighths == 0 {
+        return "< ⅛ tsp".to_string();
+    }
+    let (whole, frac) = (eighths / 8, EIGHTHS[(eighths % 8) as usize]);
+    match (whole, frac) {
+        (0, f) => format!("{f} tsp"),
+        (w, "") => format!("{w} tsp"),
+        (w, f) => format!("{w} {f} tsp"),
+    }
+}
+
+/// A temperature.
+pub fn fmt_temp(c: f64, units: Units) -> String {
+    match units {
+        Units::Metric => format!("{c:.0}°C"),
+        Units::Imperial => format!("{:.0}°F", celsius_to_fahrenheit(c)),
+    }
+}
+
+/// A temperature difference.
+pub fn fmt_temp_delta(c: f64, units: Units) -> String {
+    match units {
+        Units::Metric => format!("{c:.0}°C"),
+        Units::Imperial => format!("{:.0}°F", c * 9.0 / 5.0),
+    }
+}
+
+/// A temperature as given by the user: `25`, `25C` or `77F` (°C without a unit).
+/// Remembers the unit so output can echo it.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Temperature {
+    pub celsius: f64,
+    /// Imperial when given in °F.
+    pub unit: Units,
+}
+
+impl Temperature {
+    pub fn celsius(c: f64) -> Self {
+        Temperature {
+            celsius: c,
+            unit: Units::Metric,
+        }
+    }
+
+    pub fn fahrenheit(f: f64) -> Self {
+        Temperature {
+            celsius: fahrenheit_to_celsius(f),
+            unit: Units::Imperial,
+        }
+    }
+}
+
+impl FromStr for Temperature {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (num, make): (&str, fn(f64) -> Temperature) = match s
+            .trim_end_matches(['C', 'c', 'F', 'f'])
+            .trim_end_matches('°')
+        {
+            num if s.ends_with(['F', 'f']) => (num, Temperature::fahrenheit),
+            num => (num, Temperature::celsius),
+        };
+        num.trim()
+            .parse::<f64>()
+            .map(make)
+            .map_err(|_| format!("expected a temperature like 25, 25C or 77F, got '{s}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn teaspoons_round_to_eighths() {
+        assert_eq!(fmt_tsp(0.04), "< ⅛ tsp");
+        assert_eq!(fmt_tsp(0.39), "⅜ tsp");
+        assert_eq!(fmt_tsp(2.0), "2 tsp");
+        assert_eq!(fmt_tsp(3.12), "3 ⅛ tsp");
+        assert_eq!(fmt_rough_range(78.0, 101.0, Units::Metric), "80–100 g");
+        assert_eq!(fmt_rough_weight(89.0, Units::Imperial), "3 oz");
+    }
+}
@@ -0,0 +1,152 @@
+//! Room temperature that changes over the day, and the single temperature
+//! with the same yeast activity (Q10≈2, as in the yeast model, or any
+//! [`FermentationModel`] with [`equivalent_temp_with`]).
+
+use crate::{math, model::FermentationModel};
+use alloc::{format, string::String, vec::Vec};
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Steps of (hour of day, °C), sorted by hour; each temperature holds until
+/// the next step and the last one wraps past midnight to the first.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TempSchedule {
+    points: Vec<(f64, f64)>,
+}
+
+impl TempSchedule {
+    /// `None` without points or with an hour outside 0..24.
+    pub fn new(mut points: Vec<(f64, f64)>) -> Option<Self> {
+        if points.is_empty() || points.iter().any(|&(h, _)| !(0.0..24.0).contains(&h)) {
+            return None;
+        }
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Some(TempSchedule { points })
+    }
+
+    /// Temperature at `hour` (hours since midnight, may exceed 24).
+    pub fn at(&self, hour: f64) -> f64 {
+        let h = math::rem_euclid(hour, 24.0);
+        self.points
+            .iter()
+            .rev()
+            .find(|&&(at, _)| at <= h)
+            .or(self.points.last())
+            .map(|&(_, t)| t)
+            .unwrap()
+    }
+
+    /// (hours, °C) pieces covering `from..to` (hours since midnight).
+    pub fn segments(&self, from: f64, to: f64) -> Vec<(f64, f64)> {
+        let mut out = Vec::new();
+        let mut t = from;
+        while t < to {
+            // Next step boundary after t.
+            let day = math::floor(t / 24.0) * 24.0;
+            let next = self
+                .points
+                .iter()
+                .map(|&(h, _)| day + h)
+                .chain(self.points.first().map(|&(h, _)| day + 24.0 + h))
+                .find(|&b| b > t)
+                .unwrap()
+                .min(to);
+            out.push((next - t, self.at(t)));
+            t = next;
+        }
+        out
+    }
+}
+
+/// Temperature with the same total yeast activity as the (hours, °C) pieces.
+pub fn equivalent_temp(segments: &[(f64, f64)]) -> Option<f64> {
+    let hours: f64 = segments.iter().map(|&(h, _)| h).sum();
+    if hours <= 0.0 {
+        return None;
+    }
+    let activity: f64 = segments
+        .iter()
+        .map(|&(h, t)| h * math::powf(2.0, t / 10.0))
+        .sum();
+    Some(10.0 * math::log2(activity / hours))
+}
+
+/// Like [`equivalent_temp`], with the activity of `model`: the temperature
+/// (within -20..60 °C) at which it is as active as over the pieces.
+pub fn equivalent_temp_with(model: &dyn FermentationModel, segments: &[(f64, f64)]) -> Option<f64> {
+    let hours: f64 = segments.iter().map(|&(h, _)| h).sum();
+    if hours <= 0.0 {
+        return None;
+    }
+    let mean = segments
+        .iter()
+        .map(|&(h, t)| h * model.activity(t))
+        .sum::<f64>()
+        / hours;
+    // Activity rises with temperature: bisect for the mean.
+    let (mut lo, mut hi) = (-20.0, 60.0);
+    for _ in 0..50 {
+        let mid = (lo + hi) / 2.0;
+        if model.activity(mid) < mean {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some((lo + hi) / 2.0)
+}
+
+/// Parses `18:00=26,23:00=20,07:00=19` (°C).
+impl FromStr for TempSchedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || format!("expected HH:MM=TEMP,... (e.g. 18:00=26,23:00=20), got '{s}'");
+        let points = s
+            .split(',')
+            .map(|item| {
+                let (at, temp) = item.split_once('=').ok_or_else(bad)?;
+                let (h, m) = at.trim().split_once(':').ok_or_else(bad)?;
+                let h: u32 = h.parse().map_err(|_| bad())?;
+                let m: u32 = m.parse().map_err(|_| bad())?;
+                let temp: f64 = temp.trim().parse().map_err(|_| bad())?;
+                if m >= 60 {
+                    return Err(bad());
+                }
+                Ok((h as f64 + m as f64 / 60.0, temp))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        TempSchedule::new(points).ok_or_else(bad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn schedule_steps_and_equivalent_temp() {
+        let s: TempSchedule = "18:00=26,23:00=20,07:00=19".parse().unwrap();
+        assert_relative_eq!(s.at(18.5), 26.0);
+        assert_relative_eq!(s.at(2.0), 20.0);
+        assert_relative_eq!(s.at(24.0 + 8.0), 19.0);
+
+        let seg = s.segments(20.0, 32.0);
+        assert_eq!(seg, vec![(3.0, 26.0), (8.0, 20.0), (1.0, 19.0)]);
+
+        assert_relative_eq!(equivalent_temp(&[(5.0, 22.0)]).unwrap(), 22.0);
+        // Equal time at 20 and 30 °C counts warmer than the plain average.
+        let t = equivalent_temp(&[(1.0, 20.0), (1.0, 30.0)]).unwrap();
+        assert!(t > 25.0 && t < 30.0, "{t}");
+        assert!("25:00=20".parse::<TempSchedule>().is_err());
+
+        let q10 = crate::model::Q10::default();
+        let with = equivalent_temp_with(&q10, &[(1.0, 20.0), (1.0, 30.0)]).unwrap();
+        assert_relative_eq!(with, t, epsilon = 1e-6);
+        assert_eq!(equivalent_temp_with(&q10, &[]), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! Desired dough temperature (DDT): the water temperature that brings the
+//! mixed dough to a target temperature, and how to prepare that water.
+//!
+//! Three-factor rule (°C): `water = 3 × DDT − flour − room − friction`, where
+//! the friction factor is three times the temperature rise caused by mixing
+//! (see [`Mixer`] for typical values).
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Target dough temperature after mixing (°C).
+pub const DEFAULT_DDT_C: f64 = 24.0;
+/// Friction factor for kneading by hand (°C).
+pub const DEFAULT_FRICTION_C: f64 = 2.0;
+/// Water straight from the fridge (°C).
+pub const FRIDGE_WATER_C: f64 = 4.0;
+/// From this room temperature on, water preparation is worth planning (°C):
+/// above it a tap-water dough starts warm enough to overferment.
+pub const HOT_ROOM_C: f64 = 28.0;
+
+/// Specific heat of water (J/g·K) and latent heat of fusion of ice (J/g).
+const WATER_HEAT: f64 = 4.186;
+const ICE_FUSION: f64 = 334.0;
+
+/// How the dough is kneaded; faster, longer machine mixing heats it more.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Mixer {
+    #[default]
+    Hand,
+    Planetary,
+    Spiral,
+    FoodProcessor,
+}
+
+impl Mixer {
+    /// Typical friction factor (°C) for the three-factor rule.
+    pub fn friction_c(self) -> f64 {
+        match self {
+            Mixer::Hand => DEFAULT_FRICTION_C,
+            Mixer::Planetary => 12.0,
+            Mixer::Spiral => 14.0,
+            Mixer::FoodProcessor => 25.0,
+        }
+    }
+}
+
+/// Expected dough temperature rise (°C) during mixing for a friction factor.
+pub fn mix_rise(friction_c: f64) -> f64 {
+    friction_c / 3.0
+}
+
+/// Water temperature (°C) that yields `ddt_c` after mixing.
+pub fn water_temp(ddt_c: f64, flour_c: f64, room_c: f64, friction_c: f64) -> f64 {
+    3.0 * ddt_c - flour_c - room_c - friction_c
+}
+
+/// How to obtain the dough water at the needed temperature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase", tag = "kind"))]
+pub enum WaterPrep {
+    /// Tap water is cold enough.
+    Tap,
+    /// Mix fridge-cold and tap water.
+    Chilled { chilled_g: f64, tap_g: f64 },
+    /// Fridge-cold water with part of it replaced by ice (ice melts during mixing).
+    Iced { ice_g: f64, chilled_g: f64 },
+    /// Even all ice is not enough: also chill the flour.
+    AllIce { ice_g: f64 },
+}
+
+/// Split `water_g` of dough water into tap, fridge-cold water and ice so the
+/// mix ends at `target_c`, tap water being at `tap_c`.
+pub fn water_prep(water_g: f64, tap_c: f64, target_c: f64) -> WaterPrep {
+    if target_c >= tap_c {
+        return WaterPrep::Tap;
+    }
+    if target_c >= FRIDGE_WATER_C {
+        let chilled_g = water_g * (tap_c - target_c) / (tap_c - FRIDGE_WATER_C);
+        return WaterPrep::Chilled {
+            chilled_g,
+            tap_g: water_g - chilled_g,
+        };
+    }
+    // Ice absorbs its fusion heat plus warming to the target; cold water gives
+    // up heat cooling to it: m_ice = W·c·(T_fridge − T) / (L + c·T_fridge).
+    let ice_g = water_g * WATER_HEAT * (FRIDGE_WATER_C - target_c)
+        / (ICE_FUSION + WATER_HEAT * FRIDGE_WATER_C);
+    if ice_g >= water_g {
+        WaterPrep::AllIce { ice_g: water_g }
+    } else {
+        WaterPrep::Iced {
+            ice_g,
+            chilled_g: water_g - ice_g,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_water_temp_rule() {
+        assert_relative_eq!(water_temp(24.0, 22.0, 22.0, 2.0), 26.0);
+        // The dough ends at the average of the three plus the mixing rise.
+        let friction = Mixer::Spiral.friction_c();
+        let water = water_temp(24.0, 20.0, 22.0, friction);
+        assert_relative_eq!(
+            (20.0 + 22.0 + water) / 3.0 + mix_rise(friction),
+            24.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_water_prep_splits() {
+        assert_eq!(water_prep(300.0, 20.0, 25.0), WaterPrep::Tap);
+        match water_prep(300.0, 32.0, 18.0) {
+            WaterPrep::Chilled { chilled_g, tap_g } => {
+                assert_relative_eq!(chilled_g, 150.0, epsilon = 1e-9);
+                assert_relative_eq!(chilled_g + tap_g, 300.0);
+            }
+            other => panic!("{other:?}"),
+        }
+        match water_prep(300.0, 32.0, 0.0) {
+            // Heat balance: ice melting + warming = cold water cooling to 0 °C.
+            WaterPrep::Iced { ice_g, chilled_g } => {
+                assert_relative_eq!(
+                    ice_g * ICE_FUSION,
+                    chilled_g * WATER_HEAT * FRIDGE_WATER_C,
+                    epsilon = 1e-6
+                );
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+}
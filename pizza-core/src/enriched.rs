@@ -0,0 +1,82 @@
+//! Enriched doughs for pan pizza and focaccia: milk in place of part of the
+//! water, oil and sugar. Fat coats the yeast and milk proteins slow it, and
+//! sugar beyond a little feeding draws water out of the cells, so enriched
+//! doughs need more yeast for the same rise.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What an enriched dough adds; all zero (the default) is a lean dough.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Enrichment {
+    /// Share of the water given as milk instead (0..=1); the hydration
+    /// counts the milk's water.
+    pub milk_share: f64,
+    /// Oil as fraction of flour.
+    pub oil_pct: f64,
+    /// Sugar as fraction of flour.
+    pub sugar_pct: f64,
+}
+
+/// Water in whole milk, g per g.
+pub const MILK_WATER: f64 = 0.87;
+
+/// Sugar (fraction of flour) the yeast feeds on without slowing down.
+pub const SUGAR_TOLERATED_PCT: f64 = 0.05;
+
+/// A typical pan pizza: 4% oil and 2% sugar, all water.
+pub const PAN: Enrichment = Enrichment {
+    milk_share: 0.0,
+    oil_pct: 0.04,
+    sugar_pct: 0.02,
+};
+
+/// New York style: 3% oil and 2% sugar, for browning in a home oven that
+/// does not get as hot as a pizza oven.
+pub const NEW_YORK: Enrichment = Enrichment {
+    milk_share: 0.0,
+    oil_pct: 0.03,
+    sugar_pct: 0.02,
+};
+
+impl Enrichment {
+    /// Yeast multiplier: +10% for all-milk, +2% per 1% oil and +4% per 1%
+    /// sugar above [`SUGAR_TOLERATED_PCT`]. A heuristic, like the model.
+    pub fn yeast_factor(&self) -> f64 {
+        1.0 + 0.1 * self.milk_share
+            + 2.0 * self.oil_pct
+            + 4.0 * (self.sugar_pct - SUGAR_TOLERATED_PCT).max(0.0)
+    }
+
+    /// Liquid per gram of flour for `hydration`: water, and milk weighing
+    /// more than the water it brings.
+    pub fn liquid(&self, hydration: f64) -> f64 {
+        hydration * (1.0 - self.milk_share) + self.milk(hydration)
+    }
+
+    /// Milk per gram of flour for `hydration`.
+    pub fn milk(&self, hydration: f64) -> f64 {
+        hydration * self.milk_share / MILK_WATER
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn milk_and_yeast_factor() {
+        assert_eq!(Enrichment::default().yeast_factor(), 1.0);
+        assert_relative_eq!(PAN.yeast_factor(), 1.08, epsilon = 1e-9);
+        let sweet = Enrichment {
+            milk_share: 1.0,
+            oil_pct: 0.0,
+            sugar_pct: 0.10,
+        };
+        assert_relative_eq!(sweet.yeast_factor(), 1.3, epsilon = 1e-9);
+        // 70% hydration all from milk: 0.7 / 0.87 g of milk per g of flour
+        assert_relative_eq!(sweet.liquid(0.7), 0.7 / MILK_WATER, epsilon = 1e-9);
+    }
+}
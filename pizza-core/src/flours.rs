@@ -0,0 +1,171 @@
+//! Small embedded database of common pizza flours.
+//!
+//! Figures are typical values from producers' data sheets; batches vary, so
+//! treat them as a starting point rather than a specification.
+
+use crate::math;
+use alloc::{string::String, vec::Vec};
+
+/// A flour with its strength and water absorption.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Flour {
+    pub name: &'static str,
+    /// Other names it is looked up by.
+    pub aliases: &'static [&'static str],
+    /// Strength (alveograph W).
+    pub w: u16,
+    /// Protein, % of flour.
+    pub protein_pct: f64,
+    /// Typical water absorption, fraction of flour weight.
+    pub absorption: f64,
+}
+
+pub const FLOURS: &[Flour] = &[
+    Flour {
+        name: "Caputo Pizzeria",
+        aliases: &["caputo blue", "caputo blu"],
+        w: 260,
+        protein_pct: 12.5,
+        absorption: 0.58,
+    },
+    Flour {
+        name: "Caputo Nuvola",
+        aliases: &["nuvola"],
+        w: 300,
+        protein_pct: 12.5,
+        absorption: 0.65,
+    },
+    Flour {
+        name: "Caputo Cuoco",
+        aliases: &["caputo chef", "caputo red", "caputo rossa"],
+        w: 310,
+        protein_pct: 13.0,
+        absorption: 0.65,
+    },
+    Flour {
+        name: "Manitoba",
+        aliases: &[],
+        w: 380,
+        protein_pct: 14.0,
+        absorption: 0.70,
+    },
+    Flour {
+        name: "Generic 00",
+        aliases: &["00", "tipo 00"],
+        w: 220,
+        protein_pct: 11.0,
+        absorption: 0.55,
+    },
+    Flour {
+        name: "All-purpose",
+        aliases: &["ap", "all purpose", "plain"],
+        w: 230,
+        protein_pct: 10.5,
+        absorption: 0.60,
+    },
+    Flour {
+        name: "Bread flour",
+        aliases: &["bread", "strong white"],
+        w: 300,
+        protein_pct: 12.7,
+        absorption: 0.65,
+    },
+];
+
+/// Lowercase letters and digits only, single-spaced.
+fn normalize(s: &str) -> String {
+    s.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Find a flour by name or alias, ignoring case and punctuation. A unique
+/// partial match (`nuvo`) is accepted too.
+pub fn find(name: &str) -> Option<&'static Flour> {
+    let key = normalize(name);
+    let names = |f: &Flour| {
+        core::iter::once(f.name)
+            .chain(f.aliases.iter().copied())
+            .map(normalize)
+            .collect::<Vec<_>>()
+    };
+    if let Some(f) = FLOURS.iter().find(|f| names(f).contains(&key)) {
+        return Some(f);
+    }
+    let mut partial = FLOURS
+        .iter()
+        .filter(|f| !key.is_empty() && names(f).iter().any(|n| n.contains(&key)));
+    match (partial.next(), partial.next()) {
+        (Some(f), None) => Some(f),
+        _ => None,
+    }
+}
+
+/// Wheat a flour is milled from, for [`w_from_protein`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum FlourType {
+    /// Italian soft wheat (tipo 00 and 0), usually sold by W.
+    Soft,
+    /// Hard wheat bread and all-purpose flours (US, UK, Canada), sold by
+    /// protein.
+    #[default]
+    Hard,
+}
+
+/// Estimated W for a flour with `protein_pct` protein. A rough fit to the
+/// flours above: hard wheat gives more strength at low protein, soft wheat
+/// gains it faster as protein rises. Rounded to 10 and kept within 150..=450.
+pub fn w_from_protein(protein_pct: f64, flour_type: FlourType) -> u16 {
+    let w = match flour_type {
+        FlourType::Soft => 50.0 * protein_pct - 330.0,
+        FlourType::Hard => 32.0 * protein_pct - 106.0,
+    };
+    (math::floor(w / 10.0 + 0.5) * 10.0).clamp(150.0, 450.0) as u16
+}
+
+/// Total fermentation (mix → bake, hours) a flour of strength `w` typically
+/// suits: weak flours break down in long rises, strong ones need time to
+/// relax and develop flavour.
+pub fn fermentation_hours(w: u16) -> core::ops::RangeInclusive<f64> {
+    match w {
+        ..=219 => 2.0..=10.0,
+        220..=269 => 6.0..=24.0,
+        270..=319 => 10.0..=48.0,
+        _ => 24.0..=96.0,
+    }
+}
+
+/// Weakest W whose [`fermentation_hours`] reach `total_hours`.
+pub fn min_w_for_hours(total_hours: f64) -> u16 {
+    [150, 220, 270, 320]
+        .into_iter()
+        .find(|&w| total_hours <= *fermentation_hours(w).end())
+        .unwrap_or(320)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_by_name_alias_and_prefix() {
+        assert_eq!(find("caputo pizzeria").unwrap().w, 260);
+        assert_eq!(find("Caputo-Chef").unwrap().name, "Caputo Cuoco");
+        assert_eq!(find("nuvo").unwrap().name, "Caputo Nuvola");
+        assert!(find("caputo").is_none(), "ambiguous");
+        assert!(FLOURS.iter().all(|f| (200..=450).contains(&f.w)));
+        assert!(fermentation_hours(260).contains(&11.0));
+        assert!(!fermentation_hours(350).contains(&11.0));
+        assert_eq!(min_w_for_hours(8.0), 150);
+        assert_eq!(min_w_for_hours(48.0), 270);
+        assert_eq!(min_w_for_hours(72.0), 320);
+        assert_eq!(w_from_protein(11.0, FlourType::Soft), 220);
+        assert_eq!(w_from_protein(14.0, FlourType::Soft), 370);
+        assert_eq!(w_from_protein(12.7, FlourType::Hard), 300);
+        assert_eq!(w_from_protein(5.0, FlourType::Hard), 150);
+    }
+}
@@ -0,0 +1,101 @@
+//! Gluten-free dough: a gluten-free flour blend held together by a binder
+//! (psyllium husk or xanthan gum) instead of gluten. It takes more water,
+//! is mixed to a paste rather than kneaded, and only rests for the binder to
+//! hydrate before it is shaped, so most of the time goes to the final rise.
+//!
+//! Set [`PlanInput::binder`](crate::PlanInput::binder) to plan one; its
+//! `hydration` is then the blend's, before the binder's own water.
+
+use crate::SplitPolicy;
+use alloc::sync::Arc;
+use core::ops::RangeInclusive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What holds a gluten-free dough together.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Binder {
+    /// Psyllium husk powder: elastic, shapes almost like wheat dough.
+    #[default]
+    Psyllium,
+    /// Xanthan gum: a pinch does it, but the dough stays soft and sticky.
+    Xanthan,
+}
+
+impl Binder {
+    /// Share of the blend's weight.
+    pub fn share(self) -> f64 {
+        match self {
+            Binder::Psyllium => 0.04,
+            Binder::Xanthan => 0.01,
+        }
+    }
+
+    /// Water it takes up on top of the blend's hydration, g per g.
+    pub fn water_per_g(self) -> f64 {
+        match self {
+            Binder::Psyllium => 6.0,
+            Binder::Xanthan => 0.0,
+        }
+    }
+}
+
+/// Blend hydrations that still shape: rice and starch blends absorb more
+/// than wheat flour.
+pub const HYDRATION_RANGE: RangeInclusive<f64> = 0.70..=1.0;
+
+/// Blend hydration when none is given.
+pub const DEFAULT_HYDRATION: f64 = 0.80;
+
+/// Total hours when none are given: there is no gluten to develop or relax,
+/// and a long rise only weakens the binder's hold.
+pub const DEFAULT_TOTAL_HOURS: f64 = 3.0;
+
+/// Rest after mixing for the binder to hydrate, in place of the bulk rise.
+pub const REST_HOURS: f64 = 0.5;
+
+/// W for the yeast estimate: a blend has no gluten strength, so the
+/// model's reference flour.
+pub const W: u16 = 260;
+
+/// Water over blend + binder for a blend `hydration`, i.e. the hydration
+/// the ingredients are computed with.
+pub fn overall_hydration(hydration: f64, binder: Binder) -> f64 {
+    let s = binder.share();
+    (hydration + binder.water_per_g() * s) / (1.0 + s)
+}
+
+/// Split the dry weight (blend + binder) into (blend, binder) grams.
+pub fn split_dry(dry_g: f64, binder: Binder) -> (f64, f64) {
+    let blend = dry_g / (1.0 + binder.share());
+    (blend, dry_g - blend)
+}
+
+/// The phases: [`REST_HOURS`] (at most half the room time) as the "bulk",
+/// the rest as the rise of the shaped pizzas.
+pub fn split_policy() -> SplitPolicy {
+    SplitPolicy::Custom(Arc::new(|window_h, _| REST_HOURS.min(window_h / 2.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn binder_water_and_split() {
+        // 500 g blend, 20 g psyllium, 400 + 120 g water
+        assert_relative_eq!(
+            overall_hydration(0.80, Binder::Psyllium),
+            520.0 / 520.0,
+            epsilon = 1e-9
+        );
+        let (blend, binder) = split_dry(520.0, Binder::Psyllium);
+        assert_relative_eq!(blend, 500.0, epsilon = 1e-9);
+        assert_relative_eq!(binder, 20.0, epsilon = 1e-9);
+        assert_eq!(split_policy().split(3.0, 25.0), (0.5, 2.5));
+        assert_eq!(split_policy().split(0.6, 25.0), (0.3, 0.3));
+    }
+}
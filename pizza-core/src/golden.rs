@@ -0,0 +1,241 @@
+//! Canonical reference recipes for conformance testing (feature `test-support`).
+//!
+//! Every port or binding of the model (WASM, Python, FFI, …) should reproduce
+//! these outputs within [`GRAMS_TOLERANCE`] and [`HOURS_TOLERANCE`]. Changing
+//! an expected value here is a change of the model and deserves a changelog entry.
+
+use crate::{FRESH_PER_DRY, Plan, PlanInput, YeastKind, enriched::Enrichment, model::Q10, plan};
+use alloc::{format, string::String, sync::Arc, vec, vec::Vec};
+
+/// Allowed absolute difference for ingredient amounts (grams).
+pub const GRAMS_TOLERANCE: f64 = 0.01;
+/// Allowed absolute difference for phase durations (hours).
+pub const HOURS_TOLERANCE: f64 = 0.001;
+
+/// Expected plan output (grams and hours).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Expected {
+    pub flour_g: f64,
+    pub water_g: f64,
+    pub salt_g: f64,
+    pub yeast_g: f64,
+    pub bulk_h: f64,
+    pub fridge_h: f64,
+    pub warmup_h: f64,
+    pub proof_h: f64,
+}
+
+/// A reference recipe: inputs and the outputs the model must produce.
+#[derive(Clone, Debug)]
+pub struct GoldenRecipe {
+    pub name: &'static str,
+    pub input: PlanInput,
+    pub expected: Expected,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn input(
+    total_dough_g: f64,
+    hydration: f64,
+    salt_per_kg: f64,
+    yeast: YeastKind,
+    temp_c: f64,
+    w: u16,
+    total_hours: f64,
+    fridge_hours: f64,
+    warmup_hours: f64,
+    fridge_factor: f64,
+) -> PlanInput {
+    PlanInput {
+        total_dough_g,
+        hydration,
+        salt_per_kg,
+        yeast,
+        temp_c,
+        w,
+        total_hours,
+        fridge_hours,
+        warmup_hours,
+        fridge_factor,
+        viability: 1.0,
+        fresh_per_dry: FRESH_PER_DRY,
+        malt_pct: 0.0,
+        enrichment: Enrichment::default(),
+        model: Arc::new(Q10::default()),
+        yeast_pct: None,
+        split: None,
+        binder: None,
+        blend: None,
+    }
+}
+
+/// The reference recipes, covering both timeline modes, both yeast kinds and
+/// temperatures on either side of the 25 °C baseline.
+pub fn recipes() -> Vec<GoldenRecipe> {
+    vec![
+        GoldenRecipe {
+            name: "same-day",
+            input: input(
+                560.0,
+                0.75,
+                20.0,
+                YeastKind::Dry,
+                25.0,
+                270,
+                11.0,
+                0.0,
+                3.0,
+                0.25,
+            ),
+            expected: Expected {
+                flour_g: 315.698,
+                water_g: 236.774,
+                salt_g: 6.314,
+                yeast_g: 1.215,
+                bulk_h: 6.05,
+                fridge_h: 0.0,
+                warmup_h: 0.0,
+                proof_h: 4.95,
+            },
+        },
+        GoldenRecipe {
+            name: "same-day-fresh",
+            input: input(
+                560.0,
+                0.75,
+                20.0,
+                YeastKind::Fresh,
+                25.0,
+                270,
+                11.0,
+                0.0,
+                3.0,
+                0.25,
+            ),
+            expected: Expected {
+                flour_g: 314.335,
+                water_g: 235.751,
+                salt_g: 6.287,
+                yeast_g: 3.628,
+                bulk_h: 6.05,
+                fridge_h: 0.0,
+                warmup_h: 0.0,
+                proof_h: 4.95,
+            },
+        },
+        GoldenRecipe {
+            name: "overnight-fridge",
+            input: input(
+                1500.0,
+                0.70,
+                25.0,
+                YeastKind::Dry,
+                22.0,
+                300,
+                24.0,
+                16.0,
+                3.0,
+                0.25,
+            ),
+            expected: Expected {
+                flour_g: 867.336,
+                water_g: 607.135,
+                salt_g: 21.683,
+                yeast_g: 3.846,
+                bulk_h: 1.9,
+                fridge_h: 16.0,
+                warmup_h: 3.0,
+                proof_h: 3.1,
+            },
+        },
+        GoldenRecipe {
+            name: "hot-kitchen",
+            input: input(
+                840.0,
+                0.65,
+                28.0,
+                YeastKind::Fresh,
+                31.0,
+                240,
+                8.0,
+                0.0,
+                3.0,
+                0.25,
+            ),
+            expected: Expected {
+                flour_g: 497.564,
+                water_g: 323.416,
+                salt_g: 13.932,
+                yeast_g: 5.088,
+                bulk_h: 4.1,
+                fridge_h: 0.0,
+                warmup_h: 0.0,
+                proof_h: 3.9,
+            },
+        },
+        GoldenRecipe {
+            name: "party-48h",
+            input: input(
+                3000.0,
+                0.68,
+                22.0,
+                YeastKind::Dry,
+                20.0,
+                330,
+                48.0,
+                40.0,
+                4.0,
+                0.2,
+            ),
+            expected: Expected {
+                flour_g: 1758.609,
+                water_g: 1195.854,
+                salt_g: 38.689,
+                yeast_g: 6.847,
+                bulk_h: 1.6,
+                fridge_h: 40.0,
+                warmup_h: 4.0,
+                proof_h: 2.4,
+            },
+        },
+    ]
+}
+
+/// Compare a plan against the expected output. Returns one message per
+/// value outside the tolerances.
+pub fn check(expected: &Expected, actual: &Plan) -> Result<(), Vec<String>> {
+    let (ing, tl) = (&actual.ingredients, &actual.timeline);
+    let values = [
+        ("flour_g", expected.flour_g, ing.flour_g, GRAMS_TOLERANCE),
+        ("water_g", expected.water_g, ing.water_g, GRAMS_TOLERANCE),
+        ("salt_g", expected.salt_g, ing.salt_g, GRAMS_TOLERANCE),
+        ("yeast_g", expected.yeast_g, ing.yeast_g, GRAMS_TOLERANCE),
+        ("bulk_h", expected.bulk_h, tl.bulk_h, HOURS_TOLERANCE),
+        ("fridge_h", expected.fridge_h, tl.fridge_h, HOURS_TOLERANCE),
+        ("warmup_h", expected.warmup_h, tl.warmup_h, HOURS_TOLERANCE),
+        ("proof_h", expected.proof_h, tl.proof_h, HOURS_TOLERANCE),
+    ];
+    let errors: Vec<String> = values
+        .iter()
+        .filter(|(_, want, got, tol)| (want - got).abs() > *tol)
+        .map(|(name, want, got, tol)| format!("{name}: expected {want} ± {tol}, got {got}"))
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run every reference recipe through [`plan`]; returns the failures as
+/// `(recipe name, messages)`.
+pub fn run() -> Vec<(&'static str, Vec<String>)> {
+    recipes()
+        .into_iter()
+        .filter_map(|g| {
+            check(&g.expected, &plan(g.input))
+                .err()
+                .map(|e| (g.name, e))
+        })
+        .collect()
+}
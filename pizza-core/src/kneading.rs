@@ -0,0 +1,144 @@
+//! Mixing and kneading before the bulk rise, by [`Mixer`] and hydration:
+//! bring the dough together, let it rest, develop the gluten and check it
+//! with the windowpane test. Wet doughs first come together at
+//! [`BASE_HYDRATION`] and take the rest of the water once the gluten has
+//! formed (bassinage); a dough that wet never tightens up otherwise.
+
+use crate::{ddt::Mixer, math};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// From this hydration on, part of the water is held back.
+pub const BASSINAGE_FROM: f64 = 0.70;
+
+/// Hydration the dough is kneaded at before the held-back water goes in.
+pub const BASE_HYDRATION: f64 = 0.65;
+
+/// What happens in one step of the mix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case", tag = "kind"))]
+pub enum Stage {
+    /// Flour, water and yeast until no dry flour is left.
+    Combine,
+    /// The flour hydrates and the gluten starts forming on its own.
+    Rest,
+    /// Develop the gluten.
+    Knead,
+    /// Add the held-back `share` of the water, a little at a time.
+    AddWater { share: f64 },
+    /// Stretch a piece thin: it should let light through without tearing.
+    Windowpane,
+}
+
+/// One step: the stage, the mixer speed (none by hand or for pulsing a
+/// food processor) and how long it takes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MixStep {
+    pub stage: Stage,
+    pub speed: Option<u8>,
+    pub minutes: f64,
+}
+
+/// Share of the water to hold back at `hydration` (0 below [`BASSINAGE_FROM`]).
+pub fn held_back(hydration: f64) -> f64 {
+    match hydration >= BASSINAGE_FROM {
+        true => (hydration - BASE_HYDRATION) / hydration,
+        false => 0.0,
+    }
+}
+
+/// The mixing plan for `mixer` at `hydration` (water over flour).
+pub fn schedule(mixer: Mixer, hydration: f64) -> Vec<MixStep> {
+    let step = |stage, speed, minutes| MixStep {
+        stage,
+        speed,
+        minutes,
+    };
+    // Wetter doughs take longer to come together.
+    let wet = (hydration - 0.60).max(0.0);
+    let mut steps = match mixer {
+        Mixer::Hand => Vec::from([
+            step(Stage::Combine, None, 3.0),
+            step(Stage::Rest, None, 20.0),
+            step(
+                Stage::Knead,
+                None,
+                math::round((10.0 + wet * 50.0).clamp(8.0, 20.0)),
+            ),
+        ]),
+        Mixer::Planetary => Vec::from([
+            step(Stage::Combine, Some(1), 3.0),
+            step(Stage::Rest, None, 10.0),
+            step(
+                Stage::Knead,
+                Some(2),
+                math::round((6.0 + wet * 40.0).clamp(4.0, 12.0)),
+            ),
+        ]),
+        Mixer::Spiral => Vec::from([
+            step(Stage::Combine, Some(1), 4.0),
+            step(
+                Stage::Knead,
+                Some(2),
+                math::round((5.0 + wet * 30.0).clamp(4.0, 10.0)),
+            ),
+        ]),
+        // Fast blades heat the dough: short pulses and a rest in between.
+        Mixer::FoodProcessor => Vec::from([
+            step(Stage::Combine, None, 0.5),
+            step(Stage::Rest, None, 10.0),
+            step(Stage::Knead, None, 1.0),
+        ]),
+    };
+    let share = held_back(hydration);
+    if share > 0.0 && mixer != Mixer::FoodProcessor {
+        let (slow, fast) = match mixer {
+            Mixer::Hand => (None, None),
+            _ => (Some(1), Some(2)),
+        };
+        steps.push(step(Stage::AddWater { share }, slow, 5.0));
+        steps.push(step(Stage::Knead, fast, 2.0));
+    }
+    steps.push(step(Stage::Windowpane, None, 1.0));
+    steps
+}
+
+/// Minutes from the first step to the end of the windowpane check.
+pub fn total_minutes(steps: &[MixStep]) -> f64 {
+    steps.iter().map(|s| s.minutes).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn wet_doughs_hold_back_water() {
+        let dry = schedule(Mixer::Spiral, 0.62);
+        assert_eq!(dry.len(), 3);
+        assert_eq!(dry[1].speed, Some(2));
+        assert_eq!(dry.last().unwrap().stage, Stage::Windowpane);
+
+        let wet = schedule(Mixer::Spiral, 0.75);
+        let add = wet
+            .iter()
+            .find_map(|s| match s.stage {
+                Stage::AddWater { share } => Some(share),
+                _ => None,
+            })
+            .unwrap();
+        assert_relative_eq!(add, 0.10 / 0.75, epsilon = 1e-9);
+        assert!(total_minutes(&wet) > total_minutes(&dry));
+
+        assert_eq!(held_back(0.69), 0.0);
+        assert!(
+            schedule(Mixer::FoodProcessor, 0.75)
+                .iter()
+                .all(|s| !matches!(s.stage, Stage::AddWater { .. }))
+        );
+    }
+}
@@ -1,16 +1,85 @@
+//! Dough recipes and fermentation timelines.
+//!
+//! Without the `std` feature the crate is `no_std` but still links `alloc`,
+//! so a binary using it needs a `#[global_allocator]`. [`compute_ingredients`],
+//! [`timeline`] and [`effective_hours`] do not allocate; [`plan`] takes an
+//! `Arc` model and [`PlanInput::warnings`], [`kneading`] and
+//! [`starter::levain_build`] return `Vec`s.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{sync::Arc, vec::Vec};
+use core::fmt;
+use enriched::Enrichment;
+use model::{FermentationModel, Q10};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+pub mod ambient;
+pub mod ddt;
+pub mod enriched;
+pub mod flours;
+pub mod gluten_free;
+#[cfg(any(test, feature = "test-support"))]
+pub mod golden;
+pub mod kneading;
+mod math;
+pub mod model;
+pub mod no_knead;
+pub mod nutrition;
+pub mod oven;
+pub mod pans;
+pub mod pinsa;
+pub mod starter;
+pub mod toppings;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+
 /// Yeast kind supported by the core.
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "lowercase")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum YeastKind {
     Dry,
     Fresh,
 }
 
+/// Grams of fresh yeast with the same leavening power as 1 g of dry yeast.
+/// Sources and brands vary between 2.5 and 3.5.
+pub const FRESH_PER_DRY: f64 = 3.0;
+
+/// Convert a yeast amount (grams) between kinds, keeping the same leavening power.
+pub fn convert_yeast(grams: f64, from: YeastKind, to: YeastKind) -> f64 {
+    convert_yeast_with(grams, from, to, FRESH_PER_DRY)
+}
+
+/// Like [`convert_yeast`], with `fresh_per_dry` grams of fresh yeast per gram of dry.
+pub fn convert_yeast_with(grams: f64, from: YeastKind, to: YeastKind, fresh_per_dry: f64) -> f64 {
+    let as_dry = match from {
+        YeastKind::Dry => grams,
+        YeastKind::Fresh => grams / fresh_per_dry,
+    };
+    match to {
+        YeastKind::Dry => as_dry,
+        YeastKind::Fresh => as_dry * fresh_per_dry,
+    }
+}
+
+/// Convert °F to °C (all heuristics work in °C).
+pub fn fahrenheit_to_celsius(f: f64) -> f64 {
+    (f - 32.0) * 5.0 / 9.0
+}
+
+/// Convert °C to °F.
+pub fn celsius_to_fahrenheit(c: f64) -> f64 {
+    c * 9.0 / 5.0 + 32.0
+}
+
 /// Input for ingredient computation.
 #[derive(Copy, Clone, Debug)]
-pub struct IngredientsInput {
+pub struct IngredientsInput<'a> {
     /// Total dough weight in grams (sum of all balls).
     pub total_dough_g: f64,
     /// Target hydration as fraction (e.g., 0.75 for 75%).
@@ -25,17 +94,171 @@ pub struct IngredientsInput {
     pub w: u16,
     /// Effective fermentation hours (counts fridge slower than room).
     pub effective_hours: f64,
+    /// Share of the yeast still active (1.0 = fresh pack, 0.7 = old jar).
+    pub viability: f64,
+    /// Grams of fresh yeast per gram of dry (usually [`FRESH_PER_DRY`]).
+    pub fresh_per_dry: f64,
+    /// Diastatic malt as fraction of flour (0.0 = none).
+    pub malt_pct: f64,
+    /// Milk, oil and sugar (the default adds none).
+    pub enrichment: Enrichment,
+    /// Yeast model (usually [`Q10`]).
+    pub model: &'a dyn FermentationModel,
+    /// Fixed yeast (fraction of flour, of the given kind) instead of the model's estimate.
+    pub yeast_pct: Option<f64>,
 }
 
-/// Output ingredients (in grams).
+impl<'a> IngredientsInput<'a> {
+    /// Check the input before [`compute_ingredients`], which does not.
+    pub fn validate(&self) -> Result<(), RecipeError> {
+        if self.total_dough_g.is_nan() || self.total_dough_g <= 0.0 {
+            return Err(RecipeError::NoDough(self.total_dough_g));
+        }
+        if !HYDRATION_RANGE.contains(&self.hydration) {
+            return Err(RecipeError::Hydration(self.hydration));
+        }
+        if self.effective_hours.is_nan() || self.effective_hours <= 0.0 {
+            return Err(RecipeError::EffectiveHours(self.effective_hours));
+        }
+        Ok(())
+    }
+
+    /// Start from the CLI defaults (2 × 280 g, 75%, 20 g/kg, dry yeast,
+    /// 25 °C, W 260, 11 h), set what differs and [`IngredientsBuilder::build`].
+    pub fn builder() -> IngredientsBuilder<'a> {
+        IngredientsBuilder(IngredientsInput {
+            total_dough_g: 560.0,
+            hydration: 0.75,
+            salt_per_kg: 20.0,
+            yeast: YeastKind::Dry,
+            temp_c: 25.0,
+            w: 260,
+            effective_hours: 11.0,
+            viability: 1.0,
+            fresh_per_dry: FRESH_PER_DRY,
+            malt_pct: 0.0,
+            enrichment: Enrichment::default(),
+            model: &Q10 { q10: 2.0 },
+            yeast_pct: None,
+        })
+    }
+}
+
+/// Builder for [`IngredientsInput`]; the setters name their unit.
 #[derive(Copy, Clone, Debug)]
+pub struct IngredientsBuilder<'a>(IngredientsInput<'a>);
+
+impl<'a> IngredientsBuilder<'a> {
+    pub fn total_dough_g(mut self, grams: f64) -> Self {
+        self.0.total_dough_g = grams;
+        self
+    }
+
+    /// Hydration in percent of flour (75 = 75%).
+    pub fn hydration_pct(mut self, pct: f64) -> Self {
+        self.0.hydration = pct / 100.0;
+        self
+    }
+
+    /// Salt in baker's percent (2.8 = 28 g/kg flour).
+    pub fn salt_bakers_pct(mut self, pct: f64) -> Self {
+        self.0.salt_per_kg = pct * 10.0;
+        self
+    }
+
+    /// Salt in grams per kg of flour.
+    pub fn salt_per_kg(mut self, grams: f64) -> Self {
+        self.0.salt_per_kg = grams;
+        self
+    }
+
+    pub fn yeast(mut self, yeast: YeastKind) -> Self {
+        self.0.yeast = yeast;
+        self
+    }
+
+    pub fn temp_c(mut self, temp_c: f64) -> Self {
+        self.0.temp_c = temp_c;
+        self
+    }
+
+    pub fn w(mut self, w: u16) -> Self {
+        self.0.w = w;
+        self
+    }
+
+    /// Effective fermentation hours (see [`effective_hours`]).
+    pub fn effective_hours(mut self, hours: f64) -> Self {
+        self.0.effective_hours = hours;
+        self
+    }
+
+    /// Share of the yeast still active, 0..=1.
+    pub fn viability(mut self, viability: f64) -> Self {
+        self.0.viability = viability;
+        self
+    }
+
+    pub fn fresh_per_dry(mut self, grams: f64) -> Self {
+        self.0.fresh_per_dry = grams;
+        self
+    }
+
+    /// Diastatic malt in percent of flour (0.3 = 0.3%).
+    pub fn malt_pct(mut self, pct: f64) -> Self {
+        self.0.malt_pct = pct / 100.0;
+        self
+    }
+
+    pub fn model(mut self, model: &'a dyn FermentationModel) -> Self {
+        self.0.model = model;
+        self
+    }
+
+    /// Fixed yeast in percent of flour (0.2 = 0.2%) instead of the model's estimate.
+    pub fn yeast_pct(mut self, pct: f64) -> Self {
+        self.0.yeast_pct = Some(pct / 100.0);
+        self
+    }
+
+    /// The input, or why it cannot be computed.
+    pub fn build(self) -> Result<IngredientsInput<'a>, RecipeError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
+}
+
+/// Output ingredients (in grams).
+///
+/// Serialized with the field names as keys (`flour_g`, `water_g`, ...); these
+/// names are stable, new fields are only added.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Ingredients {
     pub flour_g: f64,
     pub water_g: f64,
     pub salt_g: f64,
     /// For baker’s yeast (dry/fresh).
     pub yeast_g: f64,
-    /// For sourdough only: total starter (flour+water) at 100% hydration.
+    /// Diastatic malt (browning aid for long cold ferments).
+    pub malt_g: f64,
+    /// Gluten-free binder (psyllium or xanthan); `flour_g` is then the blend.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub binder_g: f64,
+    /// Rice and soy flour of a pinsa blend; `flour_g` is then the wheat.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rice_g: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub soy_g: f64,
+    /// Milk of an enriched dough, in place of part of `water_g`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub milk_g: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub oil_g: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sugar_g: f64,
+    /// Always 0: there is no sourdough path; kept so serialized output keeps
+    /// the key. A levain for a dough comes from [`starter::levain_build`].
     pub starter_total_g: f64,
 }
 
@@ -54,11 +277,30 @@ fn clamp<T: PartialOrd>(v: T, lo: T, hi: T) -> T {
 /// Baseline: 0.35% at 25°C, W=260, 12h.
 /// Q10 ≈ 2 per 10°C, mild W effect, inverse with time.
 pub fn estimate_yeast_percent_dry(temp_c: f64, w: u16, effective_hours: f64) -> f64 {
-    let base = 0.0035;
-    let f_temp = 2f64.powf((25.0 - temp_c) / 10.0);
-    let f_w = (w as f64 / 260.0).powf(0.2);
-    let f_time = 12.0 / effective_hours;
-    clamp(base * f_temp * f_w * f_time, 0.0005, 0.015) // 0.05%..1.5%
+    Q10::default().yeast_percent_dry(temp_c, w, effective_hours)
+}
+
+/// Dry yeast percent of flour for yeast of which only `viability` (0..=1) is
+/// still active: the same amount of live yeast, so `1 / viability` more.
+pub fn estimate_yeast_percent_dry_with_viability(
+    temp_c: f64,
+    w: u16,
+    effective_hours: f64,
+    viability: f64,
+) -> f64 {
+    estimate_yeast_percent_dry(temp_c, w, effective_hours) / clamp(viability, 0.1, 1.0)
+}
+
+/// Viability of dry yeast `days` after opening (kept closed in the fridge):
+/// halves about every six months, never below 25%.
+pub fn viability_after_opening(days: f64) -> f64 {
+    math::powf(0.5, days.max(0.0) / 180.0).max(0.25)
+}
+
+/// Fridge activity relative to the room (the `fridge_factor`), from the same
+/// Q10≈2 rule as the yeast estimate: 4 °C vs 25 °C gives about 0.23.
+pub fn fridge_factor_for(fridge_c: f64, room_c: f64) -> f64 {
+    Q10::default().relative(room_c, fridge_c)
 }
 
 /// Effective hours model:
@@ -69,23 +311,71 @@ pub fn effective_hours(total_hours: f64, fridge_hours: f64, fridge_factor: f64)
     (total_hours - fridge_hours) + fridge_hours * rf
 }
 
+/// Fridge time from which the dough's own sugars run low for browning (hours).
+pub const MALT_MIN_FRIDGE_HOURS: f64 = 48.0;
+
+/// Suggested diastatic malt (fraction of flour) for `fridge_hours` of cold
+/// maturation: 0.2% at 48 h rising to 0.5% at 96 h; `None` below 48 h.
+pub fn suggested_malt_pct(fridge_hours: f64) -> Option<f64> {
+    (fridge_hours >= MALT_MIN_FRIDGE_HOURS).then(|| {
+        let extra = (fridge_hours - MALT_MIN_FRIDGE_HOURS) / 48.0 * 0.003;
+        clamp(0.002 + extra, 0.002, 0.005)
+    })
+}
+
+/// Hours a dough keeps at [`REFERENCE_TEMP_C`](model::REFERENCE_TEMP_C)
+/// without a fridge phase before it overproofs.
+pub const MAX_ROOM_HOURS: f64 = 13.0;
+
+/// Most total hours a dough should spend at `temp_c` without a fridge
+/// phase: [`MAX_ROOM_HOURS`] at the reference temperature, longer in a
+/// cooler kitchen and shorter in a warmer one, by `model`'s activity.
+pub fn max_room_hours(model: &dyn FermentationModel, temp_c: f64) -> f64 {
+    MAX_ROOM_HOURS / model.relative(model::REFERENCE_TEMP_C, temp_c)
+}
+
+/// Relative humidity (%) between which flour holds its usual moisture.
+pub const COMFORTABLE_HUMIDITY: core::ops::RangeInclusive<f64> = 40.0..=70.0;
+
+/// Hydration change (fraction of flour) for a kitchen at `relative_humidity`
+/// percent: flour kept in dry air is drier and takes about 1% more water per
+/// 10% below 40% (up to 3%); in humid air the dough turns sticky, so hold
+/// back 1% per 10% above 70% (up to 2%). Zero in between.
+pub fn humidity_hydration_delta(relative_humidity: f64) -> f64 {
+    let (lo, hi) = (*COMFORTABLE_HUMIDITY.start(), *COMFORTABLE_HUMIDITY.end());
+    if relative_humidity < lo {
+        clamp((lo - relative_humidity) / 1000.0, 0.0, 0.03)
+    } else if relative_humidity > hi {
+        -clamp((relative_humidity - hi) / 1000.0, 0.0, 0.02)
+    } else {
+        0.0
+    }
+}
+
 /// Compute ingredients for given input.
-/// - Dry/Fresh: dough = flour + water + salt + yeast
-/// - Sourdough: dough = flour + water + salt, where part of flour+water comes from starter (100%)
+/// Dough = flour + water + salt + yeast (+ malt and enrichment), with dry or fresh yeast.
 pub fn compute_ingredients(input: IngredientsInput) -> Ingredients {
     let salt_pct = input.salt_per_kg / 1000.0;
     let h = input.hydration;
+    let e = input.enrichment;
 
     match input.yeast {
         YeastKind::Dry | YeastKind::Fresh => {
-            let dry_pct = estimate_yeast_percent_dry(input.temp_c, input.w, input.effective_hours);
-            let yeast_pct = match input.yeast {
-                YeastKind::Dry => dry_pct,
-                YeastKind::Fresh => dry_pct * 3.0,
+            let dry_pct =
+                input
+                    .model
+                    .yeast_percent_dry(input.temp_c, input.w, input.effective_hours)
+                    / clamp(input.viability, 0.1, 1.0)
+                    * e.yeast_factor();
+            let yeast_pct = match (input.yeast_pct, input.yeast) {
+                (Some(pct), _) => pct,
+                (None, YeastKind::Dry) => dry_pct,
+                (None, YeastKind::Fresh) => dry_pct * input.fresh_per_dry,
             };
 
-            let flour = input.total_dough_g / (1.0 + h + salt_pct + yeast_pct);
-            let water = flour * h;
+            let extras = salt_pct + yeast_pct + input.malt_pct + e.oil_pct + e.sugar_pct;
+            let flour = input.total_dough_g / (1.0 + e.liquid(h) + extras);
+            let water = flour * h * (1.0 - e.milk_share);
             let salt = flour * salt_pct;
             let yeast = flour * yeast_pct;
 
@@ -94,6 +384,13 @@ pub fn compute_ingredients(input: IngredientsInput) -> Ingredients {
                 water_g: water,
                 salt_g: salt,
                 yeast_g: yeast,
+                malt_g: flour * input.malt_pct,
+                binder_g: 0.0,
+                rice_g: 0.0,
+                soy_g: 0.0,
+                milk_g: flour * e.milk(h),
+                oil_g: flour * e.oil_pct,
+                sugar_g: flour * e.sugar_pct,
                 starter_total_g: 0.0,
             }
         }
@@ -101,7 +398,10 @@ pub fn compute_ingredients(input: IngredientsInput) -> Ingredients {
 }
 
 /// Timeline (hours) for dough workflow.
+///
+/// Serialized like [`Ingredients`]: field names as keys, stable.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Timeline {
     pub bulk_h: f64,
     pub fridge_h: f64,
@@ -119,24 +419,127 @@ fn temp_adjust_ratio(temp_c: f64, base: f64, step: f64, min: f64, max: f64) -> f
     }
 }
 
-/// No-fridge timeline: split total into bulk/proof ~55/45 with temp adjustment.
-pub fn timeline_no_fridge(total_hours: f64, temp_c: f64) -> Timeline {
-    let mut bulk = total_hours * 0.55;
-    let mut proof = total_hours - bulk;
+/// How a fermentation window is split between bulk and final proof.
+///
+/// The built-in heuristics are [`SplitPolicy::no_fridge`] and
+/// [`SplitPolicy::fridge`]; tune their numbers or supply your own rule.
+#[derive(Clone)]
+pub enum SplitPolicy {
+    /// Bulk gets `base` of the window at 25 °C, `step` less per °C above
+    /// (more per °C below), kept within `min..=max`.
+    Ratio {
+        base: f64,
+        step: f64,
+        min: f64,
+        max: f64,
+    },
+    /// Bulk gets `base` of the window, then `step_h` hours per °C away from
+    /// 25 °C move from bulk to proof when hot (the opposite when cold), at most
+    /// `max_shift_h` and at most `max_frac` of the phase that shrinks.
+    Shift {
+        base: f64,
+        step_h: f64,
+        max_shift_h: f64,
+        max_frac: f64,
+    },
+    /// Bulk hours for `(window_hours, temp_c)`; proof gets the rest.
+    /// The result is clamped to the window.
+    Custom(Arc<dyn Fn(f64, f64) -> f64 + Send + Sync>),
+}
 
-    // shift up to ~1h from bulk→proof when hot, or the opposite when cold
-    if temp_c > 25.0 {
-        let delta = ((temp_c - 25.0) * 0.05).clamp(0.0, 1.0);
-        let adjust = delta.min(bulk * 0.2);
-        bulk -= adjust;
-        proof += adjust;
-    } else if temp_c < 25.0 {
-        let delta = ((25.0 - temp_c) * 0.05).clamp(0.0, 1.0);
-        let adjust = delta.min(proof * 0.2);
-        bulk += adjust;
-        proof -= adjust;
+impl SplitPolicy {
+    /// Default without fridge: ~55/45, shifting up to ~1h with temperature.
+    pub fn no_fridge() -> Self {
+        SplitPolicy::Shift {
+            base: 0.55,
+            step_h: 0.05,
+            max_shift_h: 1.0,
+            max_frac: 0.2,
+        }
     }
 
+    /// Default around a fridge phase: ~35% bulk of the room time, ±1%/°C within 20–60%.
+    pub fn fridge() -> Self {
+        SplitPolicy::Ratio {
+            base: 0.35,
+            step: 0.01,
+            min: 0.20,
+            max: 0.60,
+        }
+    }
+
+    /// Split `window_h` hours at `temp_c` into (bulk, proof).
+    pub fn split(&self, window_h: f64, temp_c: f64) -> (f64, f64) {
+        let bulk = match *self {
+            SplitPolicy::Ratio {
+                base,
+                step,
+                min,
+                max,
+            } => window_h * temp_adjust_ratio(temp_c, base, step, min, max),
+            SplitPolicy::Shift {
+                base,
+                step_h,
+                max_shift_h,
+                max_frac,
+            } => {
+                let bulk = window_h * base;
+                let proof = window_h - bulk;
+                let delta = ((temp_c - 25.0).abs() * step_h).clamp(0.0, max_shift_h);
+                if temp_c > 25.0 {
+                    bulk - delta.min(bulk * max_frac)
+                } else if temp_c < 25.0 {
+                    bulk + delta.min(proof * max_frac)
+                } else {
+                    bulk
+                }
+            }
+            SplitPolicy::Custom(ref f) => f(window_h, temp_c).clamp(0.0, window_h.max(0.0)),
+        };
+        (bulk, window_h - bulk)
+    }
+}
+
+impl fmt::Debug for SplitPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitPolicy::Ratio {
+                base,
+                step,
+                min,
+                max,
+            } => f
+                .debug_struct("Ratio")
+                .field("base", base)
+                .field("step", step)
+                .field("min", min)
+                .field("max", max)
+                .finish(),
+            SplitPolicy::Shift {
+                base,
+                step_h,
+                max_shift_h,
+                max_frac,
+            } => f
+                .debug_struct("Shift")
+                .field("base", base)
+                .field("step_h", step_h)
+                .field("max_shift_h", max_shift_h)
+                .field("max_frac", max_frac)
+                .finish(),
+            SplitPolicy::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// No-fridge timeline: split total into bulk/proof ~55/45 with temp adjustment.
+pub fn timeline_no_fridge(total_hours: f64, temp_c: f64) -> Timeline {
+    timeline_no_fridge_with(total_hours, temp_c, &SplitPolicy::no_fridge())
+}
+
+/// No-fridge timeline with a custom bulk/proof split.
+pub fn timeline_no_fridge_with(total_hours: f64, temp_c: f64, policy: &SplitPolicy) -> Timeline {
+    let (bulk, proof) = policy.split(total_hours, temp_c);
     Timeline {
         bulk_h: bulk,
         fridge_h: 0.0,
@@ -152,12 +555,26 @@ pub fn timeline_with_fridge(
     temp_c: f64,
     fridge_hours: f64,
     warmup_hours: f64,
+) -> Timeline {
+    timeline_with_fridge_with(
+        total_hours,
+        temp_c,
+        fridge_hours,
+        warmup_hours,
+        &SplitPolicy::fridge(),
+    )
+}
+
+/// Fridge timeline with a custom split of the remaining room time.
+pub fn timeline_with_fridge_with(
+    total_hours: f64,
+    temp_c: f64,
+    fridge_hours: f64,
+    warmup_hours: f64,
+    policy: &SplitPolicy,
 ) -> Timeline {
     let remaining = (total_hours - fridge_hours - warmup_hours).max(0.0);
-    // Base bulk ratio of remaining is 35%, adjusted by temperature
-    let bulk_ratio = temp_adjust_ratio(temp_c, 0.35, 0.01, 0.20, 0.60);
-    let bulk = remaining * bulk_ratio;
-    let proof = remaining - bulk;
+    let (bulk, proof) = policy.split(remaining, temp_c);
 
     Timeline {
         bulk_h: bulk,
@@ -167,6 +584,452 @@ pub fn timeline_with_fridge(
     }
 }
 
+/// Fridge or no-fridge timeline depending on `fridge_hours`.
+pub fn timeline(total_hours: f64, temp_c: f64, fridge_hours: f64, warmup_hours: f64) -> Timeline {
+    if fridge_hours > 0.0 {
+        timeline_with_fridge(total_hours, temp_c, fridge_hours, warmup_hours)
+    } else {
+        timeline_no_fridge(total_hours, temp_c)
+    }
+}
+
+/// Effective hours of a realized timeline: room phases count fully,
+/// the fridge phase at `fridge_factor` speed.
+pub fn timeline_effective_hours(tl: &Timeline, fridge_factor: f64) -> f64 {
+    let rf = clamp(fridge_factor, 0.05, 0.5);
+    tl.bulk_h + tl.warmup_h + tl.proof_h + tl.fridge_h * rf
+}
+
+/// Input for a full plan (ingredients + timeline).
+#[derive(Clone, Debug)]
+pub struct PlanInput {
+    /// Total dough weight in grams (sum of all balls).
+    pub total_dough_g: f64,
+    /// Target hydration as fraction (e.g., 0.75 for 75%).
+    pub hydration: f64,
+    /// Salt per kg flour in g/kg (e.g., 20.0).
+    pub salt_per_kg: f64,
+    /// Yeast type.
+    pub yeast: YeastKind,
+    /// Ambient temperature in °C.
+    pub temp_c: f64,
+    /// Flour strength W.
+    pub w: u16,
+    /// Total process hours (mix → bake).
+    pub total_hours: f64,
+    /// Fridge hours (0 = no fridge).
+    pub fridge_hours: f64,
+    /// Bench rest after the fridge, in hours.
+    pub warmup_hours: f64,
+    /// Fridge activity relative to room temperature.
+    pub fridge_factor: f64,
+    /// Share of the yeast still active (1.0 = fresh).
+    pub viability: f64,
+    /// Grams of fresh yeast per gram of dry.
+    pub fresh_per_dry: f64,
+    /// Diastatic malt as fraction of flour (0.0 = none).
+    pub malt_pct: f64,
+    /// Milk, oil and sugar (the default adds none).
+    pub enrichment: Enrichment,
+    /// Yeast model (usually [`Q10`]).
+    pub model: Arc<dyn FermentationModel>,
+    /// Fixed yeast (fraction of flour, of the given kind) instead of the model's estimate.
+    pub yeast_pct: Option<f64>,
+    /// Bulk/proof split; `None` uses the default for the fridge mode.
+    pub split: Option<SplitPolicy>,
+    /// Binder of a gluten-free dough (`None` for wheat); `hydration` is then
+    /// the blend's, see [`gluten_free`].
+    pub binder: Option<gluten_free::Binder>,
+    /// Flour blend of a pinsa (`None` for wheat only); `hydration` is then
+    /// over the whole blend and `w` the wheat's, see [`pinsa`].
+    pub blend: Option<pinsa::FlourBlend>,
+}
+
+/// Ingredients and timeline computed together.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Plan {
+    pub ingredients: Ingredients,
+    pub timeline: Timeline,
+    /// Effective hours of the realized phases, used for the yeast estimate.
+    pub effective_hours: f64,
+}
+
+/// Why a [`PlanInput`] cannot be planned.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RecipeError {
+    /// Dough weight is not positive.
+    NoDough(f64),
+    /// Hydration outside [`HYDRATION_RANGE`].
+    Hydration(f64),
+    /// Gluten-free blend hydration outside [`gluten_free::HYDRATION_RANGE`].
+    GlutenFreeHydration(f64),
+    /// Pinsa hydration outside [`pinsa::HYDRATION_RANGE`].
+    PinsaHydration(f64),
+    /// Total hours not positive.
+    TotalHours(f64),
+    /// Effective hours not positive.
+    EffectiveHours(f64),
+    /// Fridge or warmup hours negative.
+    NegativeHours { fridge_h: f64, warmup_h: f64 },
+    /// Fridge plus warmup leave no time for bulk and proof.
+    FridgeTooLong {
+        fridge_h: f64,
+        warmup_h: f64,
+        total_h: f64,
+    },
+}
+
+/// Hydrations the yeast and timeline heuristics are meant for.
+pub const HYDRATION_RANGE: core::ops::RangeInclusive<f64> = 0.55..=0.85;
+
+impl fmt::Display for RecipeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RecipeError::NoDough(g) => write!(f, "Dough weight must be > 0, got {g} g"),
+            RecipeError::Hydration(h) if h.is_nan() || h <= 0.0 => {
+                write!(f, "Hydration must be > 0, got {h}")
+            }
+            RecipeError::Hydration(h) => write!(
+                f,
+                "Hydration must be between {} and {}, got {h}",
+                HYDRATION_RANGE.start(),
+                HYDRATION_RANGE.end()
+            ),
+            RecipeError::GlutenFreeHydration(h) => write!(
+                f,
+                "Gluten-free hydration must be between {} and {}, got {h}",
+                gluten_free::HYDRATION_RANGE.start(),
+                gluten_free::HYDRATION_RANGE.end()
+            ),
+            RecipeError::PinsaHydration(h) => write!(
+                f,
+                "Pinsa hydration must be between {} and {}, got {h}",
+                pinsa::HYDRATION_RANGE.start(),
+                pinsa::HYDRATION_RANGE.end()
+            ),
+            RecipeError::TotalHours(h) => write!(f, "Total hours must be > 0, got {h}"),
+            RecipeError::EffectiveHours(h) => write!(f, "Effective hours must be > 0, got {h}"),
+            RecipeError::NegativeHours { .. } => {
+                write!(f, "Fridge and warmup hours must be >= 0")
+            }
+            RecipeError::FridgeTooLong {
+                fridge_h,
+                warmup_h,
+                total_h,
+            } => write!(
+                f,
+                "Fridge ({fridge_h} h) plus warmup ({warmup_h} h) must be less than the total ({total_h} h)"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for RecipeError {}
+
+/// Something about a valid [`PlanInput`] that may not turn out well.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum PlanWarning {
+    /// Hydration outside [`PlanInput::hydration_range`], accepted by
+    /// [`PlanInput::validate_lenient`].
+    Hydration { hydration: f64, min: f64, max: f64 },
+    /// Fewer total hours than a flour of strength `w` needs to relax;
+    /// `min_h..=max_h` is [`flours::fermentation_hours`].
+    ShortForW {
+        w: u16,
+        total_h: f64,
+        min_h: f64,
+        max_h: f64,
+    },
+    /// More total hours than a flour of strength `w` holds up to.
+    LongForW {
+        w: u16,
+        total_h: f64,
+        min_h: f64,
+        max_h: f64,
+    },
+}
+
+impl fmt::Display for PlanWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PlanWarning::Hydration {
+                hydration,
+                min,
+                max,
+            } => write!(
+                f,
+                "Hydration {hydration} is outside {min}-{max}; the yeast and timeline heuristics are not made for it"
+            ),
+            PlanWarning::ShortForW {
+                w,
+                total_h,
+                min_h,
+                max_h,
+            } => write!(
+                f,
+                "{total_h} h is short for W {w}; try {min_h}-{max_h} h or a weaker flour"
+            ),
+            PlanWarning::LongForW {
+                w,
+                total_h,
+                min_h,
+                max_h,
+            } => write!(
+                f,
+                "{total_h} h is long for W {w}; try {min_h}-{max_h} h or a stronger flour"
+            ),
+        }
+    }
+}
+
+impl PlanInput {
+    /// Hydrations the heuristics are meant for: [`HYDRATION_RANGE`], or
+    /// [`gluten_free::HYDRATION_RANGE`] for a gluten-free dough and
+    /// [`pinsa::HYDRATION_RANGE`] for a pinsa.
+    pub fn hydration_range(&self) -> core::ops::RangeInclusive<f64> {
+        match (self.binder, self.blend) {
+            (Some(_), _) => gluten_free::HYDRATION_RANGE,
+            (None, Some(_)) => pinsa::HYDRATION_RANGE,
+            (None, None) => HYDRATION_RANGE,
+        }
+    }
+
+    /// Check the input before planning; [`plan`] itself does not.
+    pub fn validate(&self) -> Result<(), RecipeError> {
+        self.validate_lenient()?;
+        if self.hydration_range().contains(&self.hydration) {
+            return Ok(());
+        }
+        Err(match (self.binder, self.blend) {
+            (Some(_), _) => RecipeError::GlutenFreeHydration(self.hydration),
+            (None, Some(_)) => RecipeError::PinsaHydration(self.hydration),
+            (None, None) => RecipeError::Hydration(self.hydration),
+        })
+    }
+
+    /// [`validate`](Self::validate) for recipes beyond the usual hydrations
+    /// (a 95% ciabatta-style dough, a 50% cracker base): any positive hydration passes, and
+    /// one outside [`hydration_range`](Self::hydration_range) is reported by
+    /// [`warnings`](Self::warnings) instead.
+    pub fn validate_lenient(&self) -> Result<(), RecipeError> {
+        if self.total_dough_g.is_nan() || self.total_dough_g <= 0.0 {
+            return Err(RecipeError::NoDough(self.total_dough_g));
+        }
+        if self.hydration.is_nan() || self.hydration <= 0.0 {
+            return Err(RecipeError::Hydration(self.hydration));
+        }
+        if self.total_hours.is_nan() || self.total_hours <= 0.0 {
+            return Err(RecipeError::TotalHours(self.total_hours));
+        }
+        if self.fridge_hours < 0.0 || self.warmup_hours < 0.0 {
+            return Err(RecipeError::NegativeHours {
+                fridge_h: self.fridge_hours,
+                warmup_h: self.warmup_hours,
+            });
+        }
+        if self.fridge_hours > 0.0 && self.fridge_hours + self.warmup_hours >= self.total_hours {
+            return Err(RecipeError::FridgeTooLong {
+                fridge_h: self.fridge_hours,
+                warmup_h: self.warmup_hours,
+                total_h: self.total_hours,
+            });
+        }
+        Ok(())
+    }
+
+    /// What may not turn out well with this input, even though it plans.
+    /// Gluten-free doughs have no W to suit.
+    pub fn warnings(&self) -> Vec<PlanWarning> {
+        let mut out = Vec::new();
+        let range = self.hydration_range();
+        if !range.contains(&self.hydration) {
+            out.push(PlanWarning::Hydration {
+                hydration: self.hydration,
+                min: *range.start(),
+                max: *range.end(),
+            });
+        }
+        if self.binder.is_some() {
+            return out;
+        }
+        let suited = flours::fermentation_hours(self.w);
+        let (w, total_h) = (self.w, self.total_hours);
+        let (min_h, max_h) = (*suited.start(), *suited.end());
+        if total_h < min_h {
+            out.push(PlanWarning::ShortForW {
+                w,
+                total_h,
+                min_h,
+                max_h,
+            });
+        } else if total_h > max_h {
+            out.push(PlanWarning::LongForW {
+                w,
+                total_h,
+                min_h,
+                max_h,
+            });
+        }
+        out
+    }
+}
+
+/// [`plan`] after [`PlanInput::validate`].
+pub fn try_plan(input: PlanInput) -> Result<Plan, RecipeError> {
+    input.validate()?;
+    Ok(plan(input))
+}
+
+/// Compute the phases first, then derive the yeast from the realized phase
+/// durations, so both always describe the same fermentation.
+///
+/// A gluten-free dough rests [`gluten_free::REST_HOURS`] instead of a bulk
+/// rise (unless `split` says otherwise) and is computed at its
+/// [`gluten_free::overall_hydration`], then its dry weight is split into
+/// blend and binder. A pinsa's flour is split into wheat, rice and soy.
+pub fn plan(input: PlanInput) -> Plan {
+    let gluten_free = input.binder.map(|_| gluten_free::split_policy());
+    let tl = match input.split.as_ref().or(gluten_free.as_ref()) {
+        None => timeline(
+            input.total_hours,
+            input.temp_c,
+            input.fridge_hours,
+            input.warmup_hours,
+        ),
+        Some(policy) if input.fridge_hours > 0.0 => timeline_with_fridge_with(
+            input.total_hours,
+            input.temp_c,
+            input.fridge_hours,
+            input.warmup_hours,
+            policy,
+        ),
+        Some(policy) => timeline_no_fridge_with(input.total_hours, input.temp_c, policy),
+    };
+    let eff = timeline_effective_hours(&tl, input.fridge_factor);
+    let (hydration, w) = match input.binder {
+        Some(b) => (
+            gluten_free::overall_hydration(input.hydration, b),
+            gluten_free::W,
+        ),
+        None => (input.hydration, input.w),
+    };
+    let mut ingredients = compute_ingredients(IngredientsInput {
+        total_dough_g: input.total_dough_g,
+        hydration,
+        salt_per_kg: input.salt_per_kg,
+        yeast: input.yeast,
+        temp_c: input.temp_c,
+        w,
+        effective_hours: eff,
+        viability: input.viability,
+        fresh_per_dry: input.fresh_per_dry,
+        malt_pct: input.malt_pct,
+        enrichment: input.enrichment,
+        model: &*input.model,
+        yeast_pct: input.yeast_pct,
+    });
+    if let Some(b) = input.binder {
+        let (blend, binder) = gluten_free::split_dry(ingredients.flour_g, b);
+        ingredients.flour_g = blend;
+        ingredients.binder_g = binder;
+    }
+    if let Some(blend) = input.blend {
+        let (wheat, rice, soy) = blend.split(ingredients.flour_g);
+        ingredients.flour_g = wheat;
+        ingredients.rice_g = rice;
+        ingredients.soy_g = soy;
+    }
+    Plan {
+        ingredients,
+        timeline: tl,
+        effective_hours: eff,
+    }
+}
+
+/// Smallest yeast amount (grams) a typical kitchen scale weighs reliably.
+pub const DEFAULT_MIN_MEASURABLE_YEAST_G: f64 = 0.3;
+
+/// Dissolve `dissolve_g` of yeast in `water_ml` of water and use `use_ml` of
+/// the solution; that water counts toward the dough water.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YeastDilution {
+    pub dissolve_g: f64,
+    pub water_ml: f64,
+    pub use_ml: f64,
+}
+
+/// Ways to avoid weighing a yeast amount below the measurable minimum.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SmallYeastAdvice {
+    /// The computed amount that is too small to weigh.
+    pub yeast_g: f64,
+    pub dilution: YeastDilution,
+    /// Shorter total hours at which the minimum amount is right, with the
+    /// resulting yeast grams (`None` if no shorter schedule reaches it).
+    pub reschedule: Option<(f64, f64)>,
+}
+
+/// If the plan's yeast is below `min_g`, suggest a dilution and, when
+/// possible, the longest shorter schedule that needs at least `min_g`.
+/// Total hours are rounded to the quarter hour.
+pub fn small_yeast_advice(input: &PlanInput, p: &Plan, min_g: f64) -> Option<SmallYeastAdvice> {
+    let yeast_g = p.ingredients.yeast_g;
+    if yeast_g >= min_g || yeast_g <= 0.0 {
+        return None;
+    }
+
+    // Whole grams are easy to weigh; 100 ml keeps the arithmetic simple.
+    let dissolve_g = math::ceil(min_g).max(1.0);
+    let water_ml = 100.0;
+    let dilution = YeastDilution {
+        dissolve_g,
+        water_ml,
+        use_ml: yeast_g / dissolve_g * water_ml,
+    };
+
+    // Yeast grows as time shrinks: bisect the total hours between the
+    // shortest sensible process and the requested one.
+    let yeast_at = |total_hours: f64| {
+        plan(PlanInput {
+            total_hours,
+            ..input.clone()
+        })
+        .ingredients
+        .yeast_g
+    };
+    let fixed = if input.fridge_hours > 0.0 {
+        input.fridge_hours + input.warmup_hours
+    } else {
+        0.0
+    };
+    let (mut lo, mut hi) = (fixed + 1.0, input.total_hours);
+    let reschedule = if lo < hi && yeast_at(lo) >= min_g {
+        for _ in 0..50 {
+            let mid = (lo + hi) / 2.0;
+            if yeast_at(mid) >= min_g {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let hours = math::floor(lo * 4.0) / 4.0;
+        Some((hours, yeast_at(hours)))
+    } else {
+        None
+    };
+
+    Some(SmallYeastAdvice {
+        yeast_g,
+        dilution,
+        reschedule,
+    })
+}
+
 /* ===========================
 Unit tests
 =========================== */
@@ -207,12 +1070,342 @@ mod tests {
             temp_c: 25.0,
             w: 270,
             effective_hours: 11.0,
+            viability: 1.0,
+            fresh_per_dry: FRESH_PER_DRY,
+            malt_pct: 0.0,
+            enrichment: Enrichment::default(),
+            model: &Q10::default(),
+            yeast_pct: None,
         };
         let out = compute_ingredients(input);
         let sum = out.flour_g + out.water_g + out.salt_g + out.yeast_g;
         assert_relative_eq!(sum, 560.0, epsilon = 0.2);
     }
 
+    #[test]
+    fn test_convert_yeast_roundtrip() {
+        let fresh = convert_yeast(2.0, YeastKind::Dry, YeastKind::Fresh);
+        assert_relative_eq!(fresh, 6.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            convert_yeast(fresh, YeastKind::Fresh, YeastKind::Dry),
+            2.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            convert_yeast(2.0, YeastKind::Dry, YeastKind::Dry),
+            2.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            convert_yeast_with(10.0, YeastKind::Fresh, YeastKind::Dry, 2.5),
+            4.0,
+            epsilon = 1e-9
+        );
+    }
+
+    fn plan_input(total_hours: f64, fridge_hours: f64, warmup_hours: f64) -> PlanInput {
+        PlanInput {
+            total_dough_g: 560.0,
+            hydration: 0.75,
+            salt_per_kg: 20.0,
+            yeast: YeastKind::Dry,
+            temp_c: 25.0,
+            w: 270,
+            total_hours,
+            fridge_hours,
+            warmup_hours,
+            fridge_factor: 0.25,
+            viability: 1.0,
+            fresh_per_dry: FRESH_PER_DRY,
+            malt_pct: 0.0,
+            enrichment: Enrichment::default(),
+            model: Arc::new(Q10::default()),
+            yeast_pct: None,
+            split: None,
+            binder: None,
+            blend: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_matches_legacy_when_phases_fill_total() {
+        let p = plan(plan_input(24.0, 16.0, 3.0));
+        assert_relative_eq!(
+            p.effective_hours,
+            effective_hours(24.0, 16.0, 0.25),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_plan_yeast_follows_realized_phases() {
+        // Fridge + warmup exceed the total: no room left for bulk/proof, so the
+        // realized fermentation is longer than `total_hours` suggests.
+        let p = plan(plan_input(10.0, 8.0, 4.0));
+        assert_relative_eq!(p.timeline.bulk_h + p.timeline.proof_h, 0.0);
+        assert_relative_eq!(p.effective_hours, 4.0 + 8.0 * 0.25, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_split_policies() {
+        // Cool kitchen: the default no-fridge split moves 0.05h/°C into bulk.
+        let t = timeline_no_fridge(11.0, 15.0);
+        assert_relative_eq!(t.bulk_h, 11.0 * 0.55 + 0.5, epsilon = 1e-9);
+
+        // Long bulk / short proof, whatever the temperature.
+        let long_bulk = SplitPolicy::Ratio {
+            base: 0.8,
+            step: 0.0,
+            min: 0.8,
+            max: 0.8,
+        };
+        let t = timeline_with_fridge_with(24.0, 30.0, 16.0, 3.0, &long_bulk);
+        assert_relative_eq!(t.bulk_h, 4.0, epsilon = 1e-9);
+        assert_relative_eq!(t.proof_h, 1.0, epsilon = 1e-9);
+
+        // Custom rules are clamped to the window.
+        let custom = SplitPolicy::Custom(Arc::new(|window, _| window + 5.0));
+        assert_eq!(custom.split(10.0, 25.0), (10.0, 0.0));
+    }
+
+    #[test]
+    fn test_golden_recipes() {
+        let failures = golden::run();
+        assert!(failures.is_empty(), "{failures:#?}");
+    }
+
+    #[test]
+    fn test_fahrenheit_roundtrip() {
+        assert_relative_eq!(fahrenheit_to_celsius(77.0), 25.0, epsilon = 1e-9);
+        assert_relative_eq!(celsius_to_fahrenheit(fahrenheit_to_celsius(50.0)), 50.0);
+    }
+
+    #[test]
+    fn test_small_yeast_advice() {
+        // One small ball over a long warm day needs a pinch of yeast.
+        let input = PlanInput {
+            total_dough_g: 250.0,
+            temp_c: 28.0,
+            ..plan_input(24.0, 0.0, 0.0)
+        };
+        let p = plan(input.clone());
+        let advice = small_yeast_advice(&input, &p, 0.3).expect("below 0.3 g");
+        assert_relative_eq!(
+            advice.dilution.use_ml / advice.dilution.water_ml * advice.dilution.dissolve_g,
+            p.ingredients.yeast_g,
+            epsilon = 1e-9
+        );
+        let (hours, grams) = advice.reschedule.expect("a shorter schedule exists");
+        assert!(hours < 24.0 && grams >= 0.3);
+
+        assert!(small_yeast_advice(&input, &p, 0.01).is_none());
+    }
+
+    #[test]
+    fn test_custom_model_replaces_the_estimate() {
+        #[derive(Debug)]
+        struct Fixed;
+        impl FermentationModel for Fixed {
+            fn activity(&self, _: f64) -> f64 {
+                1.0
+            }
+            fn yeast_percent_dry(&self, _: f64, _: u16, _: f64) -> f64 {
+                0.002
+            }
+        }
+        let p = plan(PlanInput {
+            model: Arc::new(Fixed),
+            ..plan_input(11.0, 0.0, 0.0)
+        });
+        assert_relative_eq!(p.ingredients.yeast_g / p.ingredients.flour_g, 0.002);
+    }
+
+    #[test]
+    fn test_yeast_pct_override() {
+        let p = plan(PlanInput {
+            yeast_pct: Some(0.002),
+            ..plan_input(11.0, 0.0, 0.0)
+        });
+        let i = p.ingredients;
+        assert_relative_eq!(i.yeast_g / i.flour_g, 0.002, epsilon = 1e-12);
+        assert_relative_eq!(
+            i.flour_g + i.water_g + i.salt_g + i.yeast_g,
+            560.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(try_plan(plan_input(11.0, 0.0, 0.0)).is_ok());
+        let bad = |input: PlanInput| input.validate().unwrap_err();
+        assert_eq!(
+            bad(PlanInput {
+                hydration: 0.95,
+                ..plan_input(11.0, 0.0, 0.0)
+            }),
+            RecipeError::Hydration(0.95)
+        );
+        assert_eq!(bad(plan_input(0.0, 0.0, 0.0)), RecipeError::TotalHours(0.0));
+        assert!(matches!(
+            bad(plan_input(10.0, -1.0, 0.0)),
+            RecipeError::NegativeHours { .. }
+        ));
+        assert!(matches!(
+            bad(plan_input(10.0, 8.0, 2.0)),
+            RecipeError::FridgeTooLong { .. }
+        ));
+    }
+
+    #[test]
+    fn test_max_room_hours() {
+        let q10 = Q10::default();
+        assert_relative_eq!(max_room_hours(&q10, 25.0), MAX_ROOM_HOURS);
+        assert_relative_eq!(max_room_hours(&q10, 15.0), 2.0 * MAX_ROOM_HOURS);
+        assert!(max_room_hours(&q10, 30.0) < MAX_ROOM_HOURS);
+    }
+
+    #[test]
+    fn test_warnings() {
+        assert!(plan_input(24.0, 0.0, 0.0).warnings().is_empty());
+        let strong = PlanInput {
+            w: 380,
+            ..plan_input(6.0, 0.0, 0.0)
+        };
+        assert_eq!(
+            strong.warnings(),
+            [PlanWarning::ShortForW {
+                w: 380,
+                total_h: 6.0,
+                min_h: 24.0,
+                max_h: 96.0
+            }]
+        );
+        let pinsa = PlanInput {
+            hydration: 0.9,
+            ..plan_input(24.0, 0.0, 0.0)
+        };
+        assert!(pinsa.validate().is_err());
+        assert!(pinsa.validate_lenient().is_ok());
+        assert!(matches!(
+            pinsa.warnings()[..],
+            [PlanWarning::Hydration { max: 0.85, .. }]
+        ));
+        let blended = PlanInput {
+            blend: Some(pinsa::PINSA),
+            ..pinsa
+        };
+        assert!(blended.validate().is_ok());
+        assert!(blended.warnings().is_empty());
+        let weak = PlanInput {
+            w: 220,
+            ..plan_input(48.0, 24.0, 2.0)
+        };
+        assert!(matches!(
+            weak.warnings()[..],
+            [PlanWarning::LongForW { max_h: 24.0, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_ingredients_builder() {
+        let input = IngredientsInput::builder()
+            .total_dough_g(1000.0)
+            .hydration_pct(70.0)
+            .salt_bakers_pct(2.8)
+            .effective_hours(24.0)
+            .build()
+            .unwrap();
+        assert_relative_eq!(input.hydration, 0.70);
+        assert_relative_eq!(input.salt_per_kg, 28.0);
+        let out = compute_ingredients(input);
+        assert_relative_eq!(out.water_g / out.flour_g, 0.70, epsilon = 1e-12);
+        // Still `Copy`: the input is usable after computing.
+        let warmer = IngredientsInput {
+            temp_c: 28.0,
+            ..input
+        };
+        assert!(compute_ingredients(warmer).yeast_g < out.yeast_g);
+
+        // A fraction passed as percent is out of range rather than silently 0.7%.
+        assert!(matches!(
+            IngredientsInput::builder().hydration_pct(0.7).build(),
+            Err(RecipeError::Hydration(_))
+        ));
+        assert!(matches!(
+            IngredientsInput::builder().effective_hours(0.0).build(),
+            Err(RecipeError::EffectiveHours(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_plan_serde_roundtrip() {
+        let p = plan(plan_input(24.0, 16.0, 3.0));
+        let json = serde_json::to_value(p).unwrap();
+        assert!(json["ingredients"]["flour_g"].is_f64());
+        assert!(json["timeline"]["fridge_h"].is_f64());
+        let back: Plan = serde_json::from_value(json).unwrap();
+        assert_eq!(back, p);
+    }
+
+    #[test]
+    fn test_fridge_factor_for() {
+        assert_relative_eq!(fridge_factor_for(5.0, 25.0), 0.25);
+        assert_relative_eq!(fridge_factor_for(20.0, 20.0), 1.0);
+    }
+
+    #[test]
+    fn test_malt_in_dough_balance() {
+        assert!(suggested_malt_pct(24.0).is_none());
+        assert_relative_eq!(suggested_malt_pct(48.0).unwrap(), 0.002);
+        assert_relative_eq!(suggested_malt_pct(200.0).unwrap(), 0.005);
+
+        let p = plan(PlanInput {
+            malt_pct: 0.005,
+            ..plan_input(72.0, 60.0, 3.0)
+        });
+        let i = p.ingredients;
+        assert_relative_eq!(i.malt_g, i.flour_g * 0.005, epsilon = 1e-12);
+        assert_relative_eq!(
+            i.flour_g + i.water_g + i.salt_g + i.yeast_g + i.malt_g,
+            560.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_humidity_hydration_delta() {
+        // No change anywhere in the comfortable band, ends included.
+        for rh in [40.0, 55.0, 70.0] {
+            assert_eq!(humidity_hydration_delta(rh), 0.0);
+        }
+        // 1% per 10% outside it.
+        assert_relative_eq!(humidity_hydration_delta(30.0), 0.01, epsilon = 1e-12);
+        assert_relative_eq!(humidity_hydration_delta(80.0), -0.01, epsilon = 1e-12);
+        // Up to 3% more water in dry air and 2% less in humid air.
+        assert_relative_eq!(humidity_hydration_delta(10.0), 0.03, epsilon = 1e-12);
+        assert_relative_eq!(humidity_hydration_delta(0.0), 0.03);
+        assert_relative_eq!(humidity_hydration_delta(90.0), -0.02, epsilon = 1e-12);
+        assert_relative_eq!(humidity_hydration_delta(100.0), -0.02);
+    }
+
+    #[test]
+    fn test_viability_scales_yeast() {
+        let fresh = plan(plan_input(11.0, 0.0, 0.0));
+        let old = plan(PlanInput {
+            viability: 0.5,
+            ..plan_input(11.0, 0.0, 0.0)
+        });
+        assert_relative_eq!(
+            old.ingredients.yeast_g / old.ingredients.flour_g,
+            2.0 * fresh.ingredients.yeast_g / fresh.ingredients.flour_g,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(viability_after_opening(180.0), 0.5, epsilon = 1e-12);
+        assert_relative_eq!(viability_after_opening(5000.0), 0.25);
+    }
+
     #[test]
     fn test_timeline_no_fridge_sums() {
         let t = timeline_no_fridge(11.0, 25.0);
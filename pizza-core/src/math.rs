@@ -0,0 +1,42 @@
+//! The few `f64` functions the model needs beyond `core`: the standard
+//! library's with feature `std`, `libm`'s without it.
+
+#[cfg(feature = "std")]
+mod imp {
+    pub fn powf(x: f64, y: f64) -> f64 {
+        x.powf(y)
+    }
+    pub fn exp(x: f64) -> f64 {
+        x.exp()
+    }
+    pub fn log2(x: f64) -> f64 {
+        x.log2()
+    }
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+    pub fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+    pub fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+    pub fn round(x: f64) -> f64 {
+        x.round()
+    }
+    pub fn rem_euclid(x: f64, m: f64) -> f64 {
+        x.rem_euclid(m)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub use libm::{ceil, exp, floor, log2, pow as powf, round, sqrt};
+
+    pub fn rem_euclid(x: f64, m: f64) -> f64 {
+        let r = libm::fmod(x, m);
+        if r < 0.0 { r + m.abs() } else { r }
+    }
+}
+
+pub use imp::*;
@@ -0,0 +1,151 @@
+//! Fermentation models: how much yeast a dough needs for a given temperature,
+//! flour and time.
+//!
+//! The built-in models share one calibrated baseline (0.35% dry yeast at
+//! 25 °C, W 260, 12 h) and differ in how activity depends on temperature:
+//!
+//! - [`Q10`]: activity doubles every 10 °C (the original heuristic).
+//! - [`Arrhenius`]: `k ∝ exp(−Ea / RT)`, with an activation energy from
+//!   fermentation studies of baker's yeast (≈ 50–65 kJ/mol). It differs most
+//!   from Q10 far from room temperature, e.g. in the fridge.
+//!
+//! Implement [`FermentationModel`] to plug in your own calibration; override
+//! [`FermentationModel::yeast_percent_dry`] to replace the baseline too.
+
+use crate::{clamp, math};
+use core::fmt;
+
+/// Temperature the yeast baseline refers to (°C).
+pub const REFERENCE_TEMP_C: f64 = 25.0;
+
+/// Activation energy for baker's yeast CO₂ production (kJ/mol).
+pub const DEFAULT_ACTIVATION_ENERGY_KJ: f64 = 60.0;
+
+/// Gas constant (J/mol·K).
+const R: f64 = 8.314;
+
+/// A yeast model for [`crate::PlanInput`] and [`crate::IngredientsInput`].
+pub trait FermentationModel: fmt::Debug + Send + Sync {
+    /// Yeast activity at `temp_c` relative to [`REFERENCE_TEMP_C`] (2.0 = twice as fast).
+    fn activity(&self, temp_c: f64) -> f64;
+
+    /// Activity at `to_c` relative to `from_c`, e.g. the fridge factor.
+    fn relative(&self, from_c: f64, to_c: f64) -> f64 {
+        self.activity(to_c) / self.activity(from_c)
+    }
+
+    /// Dry yeast percent of flour (fraction, e.g. 0.0035 = 0.35%).
+    /// Default: the calibrated baseline scaled by [`Self::activity`], a mild
+    /// W effect and inverse with time, kept within 0.05%..1.5%.
+    fn yeast_percent_dry(&self, temp_c: f64, w: u16, effective_hours: f64) -> f64 {
+        yeast_factors(self, temp_c, w, effective_hours).percent_dry()
+    }
+}
+
+/// The factors of the default [`FermentationModel::yeast_percent_dry`], to
+/// show how an estimate came about.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct YeastFactors {
+    /// Calibrated baseline (fraction of flour).
+    pub base: f64,
+    /// `1 / activity(temp_c)`.
+    pub temp: f64,
+    /// `(w / 260)^0.2`.
+    pub w: f64,
+    /// `12 / effective_hours`.
+    pub time: f64,
+}
+
+/// Bounds of the dry yeast estimate (fractions of flour).
+pub const YEAST_PERCENT_DRY_RANGE: core::ops::RangeInclusive<f64> = 0.0005..=0.015;
+
+impl YeastFactors {
+    /// The product of the factors before clamping.
+    pub fn raw(&self) -> f64 {
+        self.base * self.temp * self.w * self.time
+    }
+
+    /// The product kept within [`YEAST_PERCENT_DRY_RANGE`].
+    pub fn percent_dry(&self) -> f64 {
+        let range = YEAST_PERCENT_DRY_RANGE;
+        clamp(self.raw(), *range.start(), *range.end())
+    }
+}
+
+/// The baseline and the temperature, W and time factors `model` starts from.
+pub fn yeast_factors<M: FermentationModel + ?Sized>(
+    model: &M,
+    temp_c: f64,
+    w: u16,
+    effective_hours: f64,
+) -> YeastFactors {
+    YeastFactors {
+        base: 0.0035,
+        temp: 1.0 / model.activity(temp_c),
+        w: math::powf(w as f64 / 260.0, 0.2),
+        time: 12.0 / effective_hours,
+    }
+}
+
+/// Activity × `q10` per 10 °C.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Q10 {
+    pub q10: f64,
+}
+
+impl Default for Q10 {
+    fn default() -> Self {
+        Q10 { q10: 2.0 }
+    }
+}
+
+impl FermentationModel for Q10 {
+    fn activity(&self, temp_c: f64) -> f64 {
+        math::powf(self.q10, (temp_c - REFERENCE_TEMP_C) / 10.0)
+    }
+}
+
+/// Arrhenius law with activation energy `ea_kj` (kJ/mol).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Arrhenius {
+    pub ea_kj: f64,
+}
+
+impl Default for Arrhenius {
+    fn default() -> Self {
+        Arrhenius {
+            ea_kj: DEFAULT_ACTIVATION_ENERGY_KJ,
+        }
+    }
+}
+
+impl FermentationModel for Arrhenius {
+    fn activity(&self, temp_c: f64) -> f64 {
+        let kelvin = |c: f64| c + 273.15;
+        math::exp(self.ea_kj * 1000.0 / R * (1.0 / kelvin(REFERENCE_TEMP_C) - 1.0 / kelvin(temp_c)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn models_agree_near_room_and_differ_in_the_fridge() {
+        let q10 = Q10::default();
+        let arr = Arrhenius::default();
+        assert_relative_eq!(q10.activity(35.0), 2.0);
+        assert_relative_eq!(q10.relative(15.0, 5.0), 0.5);
+        assert_relative_eq!(arr.activity(REFERENCE_TEMP_C), 1.0);
+        // Within ~10% of each other at 30 °C...
+        assert!((arr.activity(30.0) / q10.activity(30.0) - 1.0).abs() < 0.1);
+        // ...but Arrhenius is slower at 4 °C.
+        assert!(arr.activity(4.0) < q10.activity(4.0));
+
+        let f = yeast_factors(&q10, 15.0, 260, 24.0);
+        assert_relative_eq!(f.temp, 2.0);
+        assert_relative_eq!(f.time, 0.5);
+        assert_relative_eq!(f.percent_dry(), q10.yeast_percent_dry(15.0, 260, 24.0));
+    }
+}
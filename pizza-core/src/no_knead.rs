@@ -0,0 +1,80 @@
+//! No-knead dough: stirred only until no dry flour is left, then given a
+//! couple of folds early in a long bulk rise at room temperature, which
+//! develops the gluten instead of kneading. Over 18–24 h the yeast keeps
+//! multiplying in the dough, so it takes less than the model's estimate.
+//!
+//! Plan one with [`split_policy`] as [`PlanInput::split`](crate::PlanInput::split)
+//! and the model wrapped in [`NoKnead`].
+
+use crate::{
+    SplitPolicy, clamp,
+    model::{FermentationModel, YEAST_PERCENT_DRY_RANGE},
+};
+use alloc::sync::Arc;
+use core::ops::RangeInclusive;
+
+/// Total hours the yeast estimate is tuned for, all at room temperature.
+pub const TOTAL_HOURS: RangeInclusive<f64> = 18.0..=24.0;
+
+/// Total hours when none are given.
+pub const DEFAULT_TOTAL_HOURS: f64 = 18.0;
+
+/// Share of the model's yeast a no-knead dough needs; from the classic
+/// no-knead bread (¼ tsp instant yeast for 400 g flour, 18 h at 21 °C).
+pub const YEAST_FACTOR: f64 = 0.65;
+
+/// Final proof of the balls, at most a quarter of the time.
+pub const PROOF_HOURS: f64 = 3.0;
+
+/// Minutes after mixing to stretch and fold the dough.
+pub const FOLDS_MIN: [u32; 2] = [30, 60];
+
+/// The phases: all but [`PROOF_HOURS`] go to the bulk rise.
+pub fn split_policy() -> SplitPolicy {
+    SplitPolicy::Custom(Arc::new(|window_h, _| {
+        window_h - PROOF_HOURS.min(window_h / 4.0)
+    }))
+}
+
+/// A model's activity with [`YEAST_FACTOR`] of its yeast, kept within
+/// [`YEAST_PERCENT_DRY_RANGE`].
+#[derive(Debug)]
+pub struct NoKnead(pub Arc<dyn FermentationModel>);
+
+impl FermentationModel for NoKnead {
+    fn activity(&self, temp_c: f64) -> f64 {
+        self.0.activity(temp_c)
+    }
+
+    fn yeast_percent_dry(&self, temp_c: f64, w: u16, effective_hours: f64) -> f64 {
+        let range = YEAST_PERCENT_DRY_RANGE;
+        clamp(
+            self.0.yeast_percent_dry(temp_c, w, effective_hours) * YEAST_FACTOR,
+            *range.start(),
+            *range.end(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Q10;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn long_bulk_and_less_yeast() {
+        let (bulk, proof) = split_policy().split(18.0, 21.0);
+        assert_relative_eq!(bulk, 15.0, epsilon = 1e-9);
+        assert_relative_eq!(proof, 3.0, epsilon = 1e-9);
+
+        let q10 = Q10::default();
+        let model = NoKnead(Arc::new(q10));
+        assert_relative_eq!(
+            model.yeast_percent_dry(21.0, 260, 18.0),
+            q10.yeast_percent_dry(21.0, 260, 18.0) * YEAST_FACTOR,
+            epsilon = 1e-12
+        );
+        assert_eq!(model.relative(21.0, 25.0), q10.relative(21.0, 25.0));
+    }
+}
@@ -0,0 +1,114 @@
+//! Approximate nutrition facts of the dough from an embedded ingredient table.
+//!
+//! Values per 100 g are typical label values (Italian 00/0 flour, instant dry
+//! yeast, compressed fresh yeast); treat results as estimates.
+
+use crate::{Ingredients, YeastKind};
+use core::ops::{Add, Mul};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Energy and macronutrients of some amount of food.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Nutrients {
+    pub kcal: f64,
+    pub carbs_g: f64,
+    pub protein_g: f64,
+    pub fat_g: f64,
+    pub salt_g: f64,
+}
+
+impl Add for Nutrients {
+    type Output = Nutrients;
+
+    fn add(self, o: Nutrients) -> Nutrients {
+        Nutrients {
+            kcal: self.kcal + o.kcal,
+            carbs_g: self.carbs_g + o.carbs_g,
+            protein_g: self.protein_g + o.protein_g,
+            fat_g: self.fat_g + o.fat_g,
+            salt_g: self.salt_g + o.salt_g,
+        }
+    }
+}
+
+impl Mul<f64> for Nutrients {
+    type Output = Nutrients;
+
+    fn mul(self, k: f64) -> Nutrients {
+        Nutrients {
+            kcal: self.kcal * k,
+            carbs_g: self.carbs_g * k,
+            protein_g: self.protein_g * k,
+            fat_g: self.fat_g * k,
+            salt_g: self.salt_g * k,
+        }
+    }
+}
+
+const fn per_100g(kcal: f64, carbs_g: f64, protein_g: f64, fat_g: f64, salt_g: f64) -> Nutrients {
+    Nutrients {
+        kcal,
+        carbs_g,
+        protein_g,
+        fat_g,
+        salt_g,
+    }
+}
+
+/// Per 100 g.
+pub const FLOUR: Nutrients = per_100g(345.0, 71.0, 12.0, 1.2, 0.0);
+pub const SALT: Nutrients = per_100g(0.0, 0.0, 0.0, 0.0, 100.0);
+pub const DRY_YEAST: Nutrients = per_100g(325.0, 41.0, 40.0, 7.6, 0.1);
+pub const FRESH_YEAST: Nutrients = per_100g(105.0, 18.0, 8.4, 1.9, 0.1);
+pub const OIL: Nutrients = per_100g(884.0, 0.0, 0.0, 100.0, 0.0);
+pub const SUGAR: Nutrients = per_100g(400.0, 100.0, 0.0, 0.0, 0.0);
+pub const WHOLE_MILK: Nutrients = per_100g(64.0, 4.8, 3.3, 3.6, 0.1);
+pub const RICE_FLOUR: Nutrients = per_100g(366.0, 80.0, 6.0, 1.4, 0.0);
+pub const SOY_FLOUR: Nutrients = per_100g(436.0, 35.0, 37.0, 20.0, 0.0);
+
+/// Nutrition of the whole batch (water and a gluten-free binder add
+/// nothing; the blend counts as flour).
+pub fn dough_nutrition(ing: &Ingredients, yeast: YeastKind) -> Nutrients {
+    let yeast_per_100g = match yeast {
+        YeastKind::Dry => DRY_YEAST,
+        YeastKind::Fresh => FRESH_YEAST,
+    };
+    // Malt flour is close enough to wheat flour per 100 g.
+    FLOUR * ((ing.flour_g + ing.malt_g) / 100.0)
+        + SALT * (ing.salt_g / 100.0)
+        + yeast_per_100g * (ing.yeast_g / 100.0)
+        + OIL * (ing.oil_g / 100.0)
+        + SUGAR * (ing.sugar_g / 100.0)
+        + WHOLE_MILK * (ing.milk_g / 100.0)
+        + RICE_FLOUR * (ing.rice_g / 100.0)
+        + SOY_FLOUR * (ing.soy_g / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_dough_nutrition_is_mostly_flour() {
+        let ing = Ingredients {
+            flour_g: 1000.0,
+            water_g: 700.0,
+            salt_g: 25.0,
+            yeast_g: 2.0,
+            malt_g: 0.0,
+            binder_g: 0.0,
+            rice_g: 0.0,
+            soy_g: 0.0,
+            milk_g: 0.0,
+            oil_g: 0.0,
+            sugar_g: 0.0,
+            starter_total_g: 0.0,
+        };
+        let n = dough_nutrition(&ing, YeastKind::Dry);
+        assert_relative_eq!(n.kcal, 3450.0 + 6.5, epsilon = 1e-9);
+        assert_relative_eq!(n.salt_g, 25.0 + 0.002, epsilon = 1e-9);
+    }
+}
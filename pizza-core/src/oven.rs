@@ -0,0 +1,82 @@
+//! Ovens: how long they need to preheat, so the stone or steel has soaked
+//! up heat all the way through (not just the air) when the dough is ready,
+//! and how fast they bake one pizza after another.
+//!
+//! Times are typical from a cold start; thick stones and windy days need more.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Oven {
+    /// Home oven with a baking stone.
+    #[default]
+    HomeStone,
+    /// Home oven with a baking steel, which heats up faster than a stone.
+    HomeSteel,
+    /// Electric countertop pizza oven.
+    Electric,
+    /// Portable gas pizza oven.
+    Gas,
+    /// Wood-fired oven; the dome needs the longest.
+    Wood,
+}
+
+impl Oven {
+    /// Minutes from switching on (or lighting) to a saturated baking surface.
+    pub fn preheat_minutes(self) -> u32 {
+        match self {
+            Oven::HomeStone => 60,
+            Oven::HomeSteel => 45,
+            Oven::Electric => 30,
+            Oven::Gas => 25,
+            Oven::Wood => 90,
+        }
+    }
+
+    /// Minutes one pizza bakes.
+    pub fn bake_minutes(self) -> f64 {
+        match self {
+            Oven::HomeStone => 7.0,
+            Oven::HomeSteel => 5.0,
+            Oven::Electric => 2.0,
+            Oven::Gas => 1.5,
+            Oven::Wood => 1.5,
+        }
+    }
+
+    /// Minutes the baking surface needs to get its heat back before the
+    /// next pizza goes in.
+    pub fn recovery_minutes(self) -> f64 {
+        match self {
+            Oven::HomeStone => 5.0,
+            Oven::HomeSteel => 4.0,
+            Oven::Electric => 3.0,
+            Oven::Gas => 2.0,
+            Oven::Wood => 1.0,
+        }
+    }
+}
+
+/// When each of `pizzas` goes in (minutes after the first), baking one at a
+/// time with `recovery_min` between taking one out and launching the next.
+pub fn launch_offsets(pizzas: u32, bake_min: f64, recovery_min: f64) -> Vec<f64> {
+    let every = bake_min.max(0.0) + recovery_min.max(0.0);
+    (0..pizzas).map(|i| i as f64 * every).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pizzas_launch_one_after_another() {
+        assert_eq!(launch_offsets(3, 7.0, 5.0), [0.0, 12.0, 24.0]);
+        assert!(launch_offsets(0, 7.0, 5.0).is_empty());
+        assert!(Oven::Wood.preheat_minutes() > Oven::Gas.preheat_minutes());
+    }
+}
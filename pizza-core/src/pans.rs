@@ -0,0 +1,100 @@
+//! Detroit-style pan pizza: dough, brick cheese and sauce from the pan's
+//! area at the classic loadings per square inch. The cheese goes right to
+//! the walls, where it fries against the steel into the crisp frico edge.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Square centimetres per square inch.
+pub const CM2_PER_IN2: f64 = 6.4516;
+
+/// Dough per square inch of pan (about 0.12 oz).
+pub const DOUGH_G_PER_IN2: f64 = 3.4;
+
+/// Brick cheese per square inch, edges included.
+pub const CHEESE_G_PER_IN2: f64 = 2.4;
+
+/// Sauce per square inch, ladled in stripes on top.
+pub const SAUCE_G_PER_IN2: f64 = 1.2;
+
+/// Width of the band along the walls whose cheese makes the frico edge.
+pub const EDGE_IN: f64 = 1.0;
+
+/// A rectangular pan, inside dimensions in inches.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Pan {
+    pub width_in: f64,
+    pub length_in: f64,
+}
+
+impl Pan {
+    /// The classic 10 × 14 in Detroit pan.
+    pub const DETROIT: Pan = Pan {
+        width_in: 10.0,
+        length_in: 14.0,
+    };
+
+    /// A pan measured in centimetres.
+    pub fn from_cm(width_cm: f64, length_cm: f64) -> Pan {
+        let in_per_cm = 1.0 / 2.54;
+        Pan {
+            width_in: width_cm * in_per_cm,
+            length_in: length_cm * in_per_cm,
+        }
+    }
+
+    pub fn area_in2(&self) -> f64 {
+        self.width_in * self.length_in
+    }
+
+    /// Area of the [`EDGE_IN`] band along the walls.
+    pub fn edge_area_in2(&self) -> f64 {
+        let inner =
+            (self.width_in - 2.0 * EDGE_IN).max(0.0) * (self.length_in - 2.0 * EDGE_IN).max(0.0);
+        self.area_in2() - inner
+    }
+}
+
+/// What one pan takes, in grams; `edge_cheese_g` is the part of
+/// `cheese_g` along the walls.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PanLoad {
+    pub dough_g: f64,
+    pub cheese_g: f64,
+    pub edge_cheese_g: f64,
+    pub sauce_g: f64,
+}
+
+/// Dough, cheese and sauce for a Detroit-style pizza in `pan`.
+pub fn detroit(pan: Pan) -> PanLoad {
+    PanLoad {
+        dough_g: pan.area_in2() * DOUGH_G_PER_IN2,
+        cheese_g: pan.area_in2() * CHEESE_G_PER_IN2,
+        edge_cheese_g: pan.edge_area_in2() * CHEESE_G_PER_IN2,
+        sauce_g: pan.area_in2() * SAUCE_G_PER_IN2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn loads_by_area() {
+        let load = detroit(Pan::DETROIT);
+        assert_relative_eq!(load.dough_g, 476.0, epsilon = 1e-9);
+        assert_relative_eq!(load.cheese_g, 336.0, epsilon = 1e-9);
+        // 140 in² minus the 8 × 12 in middle
+        assert_relative_eq!(load.edge_cheese_g, 44.0 * 2.4, epsilon = 1e-9);
+        let metric = Pan::from_cm(25.4, 35.56);
+        assert_relative_eq!(metric.area_in2(), 140.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            metric.area_in2() * CM2_PER_IN2,
+            25.4 * 35.56,
+            epsilon = 1e-6
+        );
+    }
+}
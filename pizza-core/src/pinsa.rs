@@ -0,0 +1,75 @@
+//! Pinsa romana: strong wheat flour cut with rice and soy flour, which soak
+//! up more water and keep the crumb light, at 75–90% hydration and with a
+//! long cold ferment. Shaped into ovals by hand just before baking.
+//!
+//! Set [`PlanInput::blend`](crate::PlanInput::blend) to plan one; its
+//! `hydration` is then over the whole blend and `w` the wheat flour's.
+
+use core::ops::RangeInclusive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Shares of the flour blend, summing to 1.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlourBlend {
+    pub wheat: f64,
+    pub rice: f64,
+    pub soy: f64,
+}
+
+/// The usual pinsa blend: 80% wheat, 15% rice, 5% soy.
+pub const PINSA: FlourBlend = FlourBlend {
+    wheat: 0.80,
+    rice: 0.15,
+    soy: 0.05,
+};
+
+impl FlourBlend {
+    /// Blend from parts in any unit (e.g. percent); `None` unless the wheat
+    /// and the total are positive and no part is negative.
+    pub fn from_parts(wheat: f64, rice: f64, soy: f64) -> Option<FlourBlend> {
+        let total = wheat + rice + soy;
+        let valid = wheat > 0.0 && rice >= 0.0 && soy >= 0.0 && total.is_finite();
+        valid.then(|| FlourBlend {
+            wheat: wheat / total,
+            rice: rice / total,
+            soy: soy / total,
+        })
+    }
+
+    /// Split `flour_g` of blend into (wheat, rice, soy) grams.
+    pub fn split(&self, flour_g: f64) -> (f64, f64, f64) {
+        (
+            flour_g * self.wheat,
+            flour_g * self.rice,
+            flour_g * self.soy,
+        )
+    }
+}
+
+/// Hydrations that still shape into a pinsa.
+pub const HYDRATION_RANGE: RangeInclusive<f64> = 0.75..=0.90;
+
+/// Hydration when none is given.
+pub const DEFAULT_HYDRATION: f64 = 0.80;
+
+/// Total hours when none are given, nearly all of them in the fridge.
+pub const DEFAULT_TOTAL_HOURS: f64 = 72.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn blend_from_parts_and_split() {
+        assert_eq!(FlourBlend::from_parts(80.0, 15.0, 5.0), Some(PINSA));
+        assert_eq!(FlourBlend::from_parts(0.0, 50.0, 50.0), None);
+        assert_eq!(FlourBlend::from_parts(90.0, -5.0, 15.0), None);
+        let (wheat, rice, soy) = PINSA.split(500.0);
+        assert_relative_eq!(wheat, 400.0, epsilon = 1e-9);
+        assert_relative_eq!(rice, 75.0, epsilon = 1e-9);
+        assert_relative_eq!(soy, 25.0, epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,122 @@
+//! Sourdough starter feeding: amounts for a feed ratio, a rough time to peak
+//! and levain builds.
+//!
+//! The peak model counts how many doublings the seed needs to reach the fed
+//! total, at [`HOURS_PER_DOUBLING`] each at [`REFERENCE_TEMP_C`] and twice as
+//! fast per 10 °C warmer (Q10≈2, as for yeast). 1:1:1 at 24 °C peaks after
+//! about 4 h, 1:5:5 after about 8–9 h.
+
+use crate::math;
+use alloc::vec::Vec;
+
+/// Temperature the doubling time refers to (°C).
+pub const REFERENCE_TEMP_C: f64 = 24.0;
+
+/// Hours per doubling of the starter at [`REFERENCE_TEMP_C`].
+pub const HOURS_PER_DOUBLING: f64 = 2.5;
+
+/// Hours around the peak during which the starter is still good to use.
+pub const PEAK_WINDOW_H: f64 = 1.5;
+
+/// A feed: seed starter plus fresh flour and water (grams).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Feed {
+    pub seed_g: f64,
+    pub flour_g: f64,
+    pub water_g: f64,
+}
+
+impl Feed {
+    /// Feed `seed_g` of starter at `starter:flour:water` (e.g. 1:2:2).
+    pub fn from_ratio(seed_g: f64, starter: f64, flour: f64, water: f64) -> Self {
+        let per_part = seed_g / starter;
+        Feed {
+            seed_g,
+            flour_g: flour * per_part,
+            water_g: water * per_part,
+        }
+    }
+
+    pub fn total_g(&self) -> f64 {
+        self.seed_g + self.flour_g + self.water_g
+    }
+
+    /// How many times the seed is diluted (3 for 1:1:1).
+    pub fn dilution(&self) -> f64 {
+        self.total_g() / self.seed_g
+    }
+}
+
+/// Hours from feeding to peak for a starter diluted `dilution` times at `temp_c`.
+pub fn peak_hours(dilution: f64, temp_c: f64) -> f64 {
+    let doublings = math::log2(dilution.max(1.0));
+    let temp_factor = math::powf(2.0, (REFERENCE_TEMP_C - temp_c) / 10.0);
+    doublings * HOURS_PER_DOUBLING * temp_factor
+}
+
+/// Largest dilution fed in one stage (1:5:5); beyond it the build gets two stages.
+pub const MAX_STAGE_DILUTION: f64 = 11.0;
+
+/// One stage of a levain build, fed at 100% hydration.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BuildStage {
+    pub feed: Feed,
+    /// Hours until this stage peaks.
+    pub hours: f64,
+}
+
+/// Build `target_g` of levain from `seed_g` of starter at `temp_c`: one stage,
+/// or two stages with equal dilution when one would exceed [`MAX_STAGE_DILUTION`].
+pub fn levain_build(target_g: f64, seed_g: f64, temp_c: f64) -> Vec<BuildStage> {
+    let dilution = (target_g / seed_g).max(1.0);
+    let stages = if dilution > MAX_STAGE_DILUTION { 2 } else { 1 };
+    let per_stage = math::powf(dilution, 1.0 / stages as f64);
+    let mut seed = seed_g;
+    (0..stages)
+        .map(|_| {
+            let fresh = seed * (per_stage - 1.0) / 2.0;
+            let feed = Feed {
+                seed_g: seed,
+                flour_g: fresh,
+                water_g: fresh,
+            };
+            seed = feed.total_g();
+            BuildStage {
+                feed,
+                hours: peak_hours(per_stage, temp_c),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn feed_amounts_and_peak() {
+        let feed = Feed::from_ratio(20.0, 1.0, 2.0, 2.0);
+        assert_relative_eq!(feed.flour_g, 40.0);
+        assert_relative_eq!(feed.total_g(), 100.0);
+        assert_relative_eq!(feed.dilution(), 5.0);
+
+        let h = peak_hours(3.0, REFERENCE_TEMP_C);
+        assert!((3.5..4.5).contains(&h), "{h}");
+        // 10 °C cooler: twice as long
+        assert_relative_eq!(peak_hours(3.0, 14.0), 2.0 * h, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn levain_build_stages() {
+        let one = levain_build(100.0, 20.0, 24.0);
+        assert_eq!(one.len(), 1);
+        assert_relative_eq!(one[0].feed.total_g(), 100.0);
+
+        let two = levain_build(400.0, 10.0, 24.0);
+        assert_eq!(two.len(), 2);
+        assert_relative_eq!(two[0].feed.dilution(), two[1].feed.dilution());
+        assert_relative_eq!(two[1].feed.seed_g, two[0].feed.total_g());
+        assert_relative_eq!(two[1].feed.total_g(), 400.0, epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,185 @@
+//! Toppings for a margherita: tomato sauce, mozzarella and basil per pizza,
+//! scaled by area from typical amounts for each style.
+//!
+//! The references are what pizzerias commonly use (e.g. 80–100 g sauce and
+//! 90 g fior di latte on a 32 cm Neapolitan); taste decides the rest.
+//! [`sauce`] turns the batch's sauce into a recipe.
+
+use crate::math;
+use core::{f64::consts::PI, ops::Add};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pizza style, for the topping amounts and the size of a ball's pizza.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Style {
+    /// Soft, with a puffy rim; fior di latte.
+    #[default]
+    Neapolitan,
+    /// Thin and crisp (tonda romana); fior di latte.
+    Roman,
+    /// Large and foldable; low-moisture mozzarella.
+    NewYork,
+}
+
+/// Typical amounts on a pizza of `diameter_cm`.
+struct Reference {
+    diameter_cm: f64,
+    sauce_g: (f64, f64),
+    cheese_g: f64,
+    basil_leaves: f64,
+    /// Dough per cm² of pizza, for the diameter a ball stretches to.
+    dough_g_per_cm2: f64,
+}
+
+impl Style {
+    fn reference(self) -> Reference {
+        match self {
+            Style::Neapolitan => Reference {
+                diameter_cm: 32.0,
+                sauce_g: (80.0, 100.0),
+                cheese_g: 90.0,
+                basil_leaves: 4.0,
+                dough_g_per_cm2: 0.35,
+            },
+            Style::Roman => Reference {
+                diameter_cm: 32.0,
+                sauce_g: (60.0, 80.0),
+                cheese_g: 100.0,
+                basil_leaves: 3.0,
+                dough_g_per_cm2: 0.22,
+            },
+            Style::NewYork => Reference {
+                diameter_cm: 40.0,
+                sauce_g: (150.0, 180.0),
+                cheese_g: 200.0,
+                basil_leaves: 5.0,
+                dough_g_per_cm2: 0.40,
+            },
+        }
+    }
+
+    /// Diameter (cm) a ball of `ball_g` stretches to in this style.
+    pub fn diameter_for(self, ball_g: f64) -> f64 {
+        let area = ball_g.max(0.0) / self.reference().dough_g_per_cm2;
+        2.0 * math::sqrt(area / PI)
+    }
+}
+
+/// Toppings for one pizza.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Toppings {
+    /// Tomato sauce, a range from light to generous (grams).
+    pub sauce_min_g: f64,
+    pub sauce_max_g: f64,
+    /// Mozzarella, drained (grams).
+    pub cheese_g: f64,
+    /// Basil leaves, at least one.
+    pub basil_leaves: u32,
+}
+
+impl Add for Toppings {
+    type Output = Toppings;
+
+    fn add(self, o: Toppings) -> Toppings {
+        Toppings {
+            sauce_min_g: self.sauce_min_g + o.sauce_min_g,
+            sauce_max_g: self.sauce_max_g + o.sauce_max_g,
+            cheese_g: self.cheese_g + o.cheese_g,
+            basil_leaves: self.basil_leaves + o.basil_leaves,
+        }
+    }
+}
+
+impl Toppings {
+    /// Toppings for `count` pizzas (basil rounded to whole leaves).
+    pub fn times(self, count: u32) -> Toppings {
+        let k = count as f64;
+        Toppings {
+            sauce_min_g: self.sauce_min_g * k,
+            sauce_max_g: self.sauce_max_g * k,
+            cheese_g: self.cheese_g * k,
+            basil_leaves: self.basil_leaves * count,
+        }
+    }
+}
+
+/// Toppings for a `style` pizza of `diameter_cm`: the style's reference
+/// amounts scaled by area.
+pub fn toppings(style: Style, diameter_cm: f64) -> Toppings {
+    let r = style.reference();
+    let ratio = diameter_cm.max(0.0) / r.diameter_cm;
+    let k = ratio * ratio;
+    Toppings {
+        sauce_min_g: r.sauce_g.0 * k,
+        sauce_max_g: r.sauce_g.1 * k,
+        cheese_g: r.cheese_g * k,
+        basil_leaves: (math::floor(r.basil_leaves * k + 0.5) as u32).max(1),
+    }
+}
+
+/// Salt in the sauce, fraction of the tomatoes.
+pub const SAUCE_SALT: f64 = 0.012;
+/// Olive oil in the sauce when used, fraction of the tomatoes.
+pub const SAUCE_OIL: f64 = 0.02;
+/// A standard tin of peeled tomatoes (grams).
+pub const TOMATO_TIN_G: f64 = 400.0;
+
+/// A raw San Marzano sauce: crushed peeled tomatoes with salt, basil and,
+/// optionally, olive oil.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Sauce {
+    pub tomatoes_g: f64,
+    pub salt_g: f64,
+    /// Zero without oil.
+    pub oil_g: f64,
+    pub basil_leaves: u32,
+}
+
+impl Sauce {
+    /// Tins of [`TOMATO_TIN_G`] to open.
+    pub fn tins(&self) -> u32 {
+        math::ceil(self.tomatoes_g / TOMATO_TIN_G) as u32
+    }
+}
+
+/// Sauce making `total_g` in all (e.g. the batch's [`Toppings::sauce_max_g`]).
+pub fn sauce(total_g: f64, oil: bool) -> Sauce {
+    let oil_pct = if oil { SAUCE_OIL } else { 0.0 };
+    let tomatoes = total_g.max(0.0) / (1.0 + SAUCE_SALT + oil_pct);
+    Sauce {
+        tomatoes_g: tomatoes,
+        salt_g: tomatoes * SAUCE_SALT,
+        oil_g: tomatoes * oil_pct,
+        basil_leaves: (math::floor(tomatoes / 100.0 + 0.5) as u32).max(2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scales_with_area() {
+        let t = toppings(Style::Neapolitan, 32.0);
+        assert_eq!((t.sauce_min_g, t.sauce_max_g), (80.0, 100.0));
+        assert_eq!((t.cheese_g, t.basil_leaves), (90.0, 4));
+        assert_relative_eq!(toppings(Style::Neapolitan, 16.0).cheese_g, 22.5);
+        assert_eq!(toppings(Style::Roman, 10.0).basil_leaves, 1);
+        assert_eq!(t.times(3).basil_leaves, 12);
+
+        let d = Style::Neapolitan.diameter_for(280.0);
+        assert!((31.0..33.0).contains(&d), "{d}");
+        assert!(Style::Roman.diameter_for(280.0) > d);
+
+        let s = sauce(4.0 * 100.0, true);
+        assert_relative_eq!(s.tomatoes_g + s.salt_g + s.oil_g, 400.0);
+        assert_eq!((s.tins(), s.basil_leaves), (1, 4));
+        assert_eq!(sauce(100.0, false).oil_g, 0.0);
+    }
+}
@@ -0,0 +1,104 @@
+//! JavaScript bindings (feature `wasm-bindgen`), so a web frontend runs the
+//! same math as the CLI. Results are plain objects with the field names of
+//! [`Ingredients`](crate::Ingredients) and [`Timeline`](crate::Timeline).
+//!
+//! ```js
+//! import init, { computeIngredients, effectiveHours, timeline } from "./pizza_core.js";
+//! await init();
+//! const tl = timeline(24, 25, 16, 3);
+//! const eff = effectiveHours(24, 16, 0.25);
+//! const ing = computeIngredients(560, 0.75, 20, "dry", 25, 270, eff);
+//! ```
+
+use crate::{FRESH_PER_DRY, IngredientsInput, YeastKind, enriched::Enrichment, model::Q10};
+use alloc::format;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    serde_wasm_bindgen::to_value(value).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Ingredients for the given dough (grams, hydration as a fraction, salt in
+/// g/kg flour, yeast `"dry"` or `"fresh"`); throws on invalid input.
+#[wasm_bindgen(js_name = computeIngredients)]
+pub fn compute_ingredients(
+    total_dough_g: f64,
+    hydration: f64,
+    salt_per_kg: f64,
+    yeast: &str,
+    temp_c: f64,
+    w: u16,
+    effective_hours: f64,
+) -> Result<JsValue, JsError> {
+    let yeast = match yeast {
+        "dry" => YeastKind::Dry,
+        "fresh" => YeastKind::Fresh,
+        other => {
+            return Err(JsError::new(&format!(
+                "yeast must be \"dry\" or \"fresh\", got \"{other}\""
+            )));
+        }
+    };
+    let input = IngredientsInput {
+        total_dough_g,
+        hydration,
+        salt_per_kg,
+        yeast,
+        temp_c,
+        w,
+        effective_hours,
+        viability: 1.0,
+        fresh_per_dry: FRESH_PER_DRY,
+        malt_pct: 0.0,
+        enrichment: Enrichment::default(),
+        model: &Q10::default(),
+        yeast_pct: None,
+    };
+    input.validate().map_err(|e| JsError::new(&e.to_string()))?;
+    to_js(&crate::compute_ingredients(input))
+}
+
+/// See [`crate::effective_hours`].
+#[wasm_bindgen(js_name = effectiveHours)]
+pub fn effective_hours(total_hours: f64, fridge_hours: f64, fridge_factor: f64) -> f64 {
+    crate::effective_hours(total_hours, fridge_hours, fridge_factor)
+}
+
+/// See [`crate::timeline`].
+#[wasm_bindgen]
+pub fn timeline(
+    total_hours: f64,
+    temp_c: f64,
+    fridge_hours: f64,
+    warmup_hours: f64,
+) -> Result<JsValue, JsError> {
+    to_js(&crate::timeline(
+        total_hours,
+        temp_c,
+        fridge_hours,
+        warmup_hours,
+    ))
+}
+
+/// See [`crate::timeline_no_fridge`].
+#[wasm_bindgen(js_name = timelineNoFridge)]
+pub fn timeline_no_fridge(total_hours: f64, temp_c: f64) -> Result<JsValue, JsError> {
+    to_js(&crate::timeline_no_fridge(total_hours, temp_c))
+}
+
+/// See [`crate::timeline_with_fridge`].
+#[wasm_bindgen(js_name = timelineWithFridge)]
+pub fn timeline_with_fridge(
+    total_hours: f64,
+    temp_c: f64,
+    fridge_hours: f64,
+    warmup_hours: f64,
+) -> Result<JsValue, JsError> {
+    to_js(&crate::timeline_with_fridge(
+        total_hours,
+        temp_c,
+        fridge_hours,
+        warmup_hours,
+    ))
+}
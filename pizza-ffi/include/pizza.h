@@ -0,0 +1,123 @@
+#ifndef PIZZA_H
+#define PIZZA_H
+
+/* Generated by cbindgen from pizza-ffi/src/lib.rs; do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of a call; `Ok` is 0, the rest mirror `pizza_core::RecipeError`.
+ */
+typedef enum PizzaStatus {
+  PIZZA_STATUS_OK = 0,
+  /**
+   * A required pointer was NULL.
+   */
+  PIZZA_STATUS_NULL_POINTER,
+  PIZZA_STATUS_NO_DOUGH,
+  PIZZA_STATUS_HYDRATION,
+  PIZZA_STATUS_TOTAL_HOURS,
+  PIZZA_STATUS_EFFECTIVE_HOURS,
+  PIZZA_STATUS_NEGATIVE_HOURS,
+  PIZZA_STATUS_FRIDGE_TOO_LONG,
+} PizzaStatus;
+
+/**
+ * Yeast type.
+ */
+typedef enum PizzaYeast {
+  PIZZA_YEAST_DRY = 0,
+  PIZZA_YEAST_FRESH = 1,
+} PizzaYeast;
+
+/**
+ * Input for [`pizza_plan`]: hydration as a fraction (0.75), salt in g/kg
+ * flour, temperature in °C, fridge speed relative to the room (0.25).
+ */
+typedef struct PizzaPlanInput {
+  double total_dough_g;
+  double hydration;
+  double salt_per_kg;
+  enum PizzaYeast yeast;
+  double temp_c;
+  uint16_t w;
+  double total_hours;
+  double fridge_hours;
+  double warmup_hours;
+  double fridge_factor;
+} PizzaPlanInput;
+
+/**
+ * Ingredient amounts in grams.
+ */
+typedef struct PizzaIngredients {
+  double flour_g;
+  double water_g;
+  double salt_g;
+  double yeast_g;
+  double malt_g;
+} PizzaIngredients;
+
+/**
+ * Phase durations in hours.
+ */
+typedef struct PizzaTimeline {
+  double bulk_h;
+  double fridge_h;
+  double warmup_h;
+  double proof_h;
+} PizzaTimeline;
+
+/**
+ * Ingredients and timeline as the CLI computes them (default Q10 model,
+ * fresh yeast at 3 g per g of dry).
+ *
+ * # Safety
+ *
+ * `input` must point to a valid `PizzaPlanInput`; `ingredients_out` and
+ * `timeline_out` must be valid for writes. NULL pointers return
+ * `PIZZA_STATUS_NULL_POINTER`.
+ */
+enum PizzaStatus pizza_plan(const struct PizzaPlanInput *input,
+                            struct PizzaIngredients *ingredients_out,
+                            struct PizzaTimeline *timeline_out);
+
+/**
+ * Ingredients for `total_dough_g` of dough fermented `effective_hours`.
+ *
+ * # Safety
+ *
+ * `out` must be valid for writes; NULL returns `PIZZA_STATUS_NULL_POINTER`.
+ */
+enum PizzaStatus pizza_compute_ingredients(double total_dough_g,
+                                           double hydration,
+                                           double salt_per_kg,
+                                           enum PizzaYeast yeast,
+                                           double temp_c,
+                                           uint16_t w,
+                                           double effective_hours,
+                                           struct PizzaIngredients *out);
+
+/**
+ * Phases for `total_hours` at `temp_c`; no fridge when `fridge_hours` is 0.
+ */
+struct PizzaTimeline pizza_timeline(double total_hours,
+                                    double temp_c,
+                                    double fridge_hours,
+                                    double warmup_hours);
+
+/**
+ * Room hours plus fridge hours at `fridge_factor` speed.
+ */
+double pizza_effective_hours(double total_hours, double fridge_hours, double fridge_factor);
+
+/**
+ * English description of `status` (static, NUL-terminated; do not free).
+ */
+const char *pizza_status_message(enum PizzaStatus status);
+
+#endif  /* PIZZA_H */
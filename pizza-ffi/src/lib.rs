@@ -0,0 +1,301 @@
+//! C interface to pizza-core, for embedding the calculator in apps written in
+//! other languages. The header is `include/pizza.h` (generated by cbindgen,
+//! see `cbindgen.toml`).
+//!
+//! All functions take and return plain values; results go through
+//! out-pointers and a [`PizzaStatus`]. The caller owns all memory; nothing
+//! returned needs freeing.
+
+use pizza_core::{
+    FRESH_PER_DRY, IngredientsInput, PlanInput, RecipeError, Timeline, YeastKind,
+    enriched::Enrichment, model::Q10,
+};
+use std::{ffi::c_char, sync::Arc};
+
+/// Yeast type.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PizzaYeast {
+    Dry = 0,
+    Fresh = 1,
+}
+
+impl From<PizzaYeast> for YeastKind {
+    fn from(y: PizzaYeast) -> Self {
+        match y {
+            PizzaYeast::Dry => YeastKind::Dry,
+            PizzaYeast::Fresh => YeastKind::Fresh,
+        }
+    }
+}
+
+/// Result of a call; `Ok` is 0, the rest mirror `pizza_core::RecipeError`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PizzaStatus {
+    Ok = 0,
+    /// A required pointer was NULL.
+    NullPointer,
+    NoDough,
+    Hydration,
+    TotalHours,
+    EffectiveHours,
+    NegativeHours,
+    FridgeTooLong,
+}
+
+impl From<RecipeError> for PizzaStatus {
+    fn from(e: RecipeError) -> Self {
+        match e {
+            RecipeError::NoDough(_) => PizzaStatus::NoDough,
+            RecipeError::Hydration(_)
+            | RecipeError::GlutenFreeHydration(_)
+            | RecipeError::PinsaHydration(_) => PizzaStatus::Hydration,
+            RecipeError::TotalHours(_) => PizzaStatus::TotalHours,
+            RecipeError::EffectiveHours(_) => PizzaStatus::EffectiveHours,
+            RecipeError::NegativeHours { .. } => PizzaStatus::NegativeHours,
+            RecipeError::FridgeTooLong { .. } => PizzaStatus::FridgeTooLong,
+        }
+    }
+}
+
+/// Ingredient amounts in grams.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PizzaIngredients {
+    pub flour_g: f64,
+    pub water_g: f64,
+    pub salt_g: f64,
+    pub yeast_g: f64,
+    pub malt_g: f64,
+}
+
+/// Phase durations in hours.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PizzaTimeline {
+    pub bulk_h: f64,
+    pub fridge_h: f64,
+    pub warmup_h: f64,
+    pub proof_h: f64,
+}
+
+impl From<Timeline> for PizzaTimeline {
+    fn from(t: Timeline) -> Self {
+        PizzaTimeline {
+            bulk_h: t.bulk_h,
+            fridge_h: t.fridge_h,
+            warmup_h: t.warmup_h,
+            proof_h: t.proof_h,
+        }
+    }
+}
+
+/// Input for [`pizza_plan`]: hydration as a fraction (0.75), salt in g/kg
+/// flour, temperature in °C, fridge speed relative to the room (0.25).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PizzaPlanInput {
+    pub total_dough_g: f64,
+    pub hydration: f64,
+    pub salt_per_kg: f64,
+    pub yeast: PizzaYeast,
+    pub temp_c: f64,
+    pub w: u16,
+    pub total_hours: f64,
+    pub fridge_hours: f64,
+    pub warmup_hours: f64,
+    pub fridge_factor: f64,
+}
+
+fn ingredients(i: pizza_core::Ingredients) -> PizzaIngredients {
+    PizzaIngredients {
+        flour_g: i.flour_g,
+        water_g: i.water_g,
+        salt_g: i.salt_g,
+        yeast_g: i.yeast_g,
+        malt_g: i.malt_g,
+    }
+}
+
+/// Ingredients and timeline as the CLI computes them (default Q10 model,
+/// fresh yeast at 3 g per g of dry).
+///
+/// # Safety
+///
+/// `input` must point to a valid `PizzaPlanInput`; `ingredients_out` and
+/// `timeline_out` must be valid for writes. NULL pointers return
+/// `PIZZA_STATUS_NULL_POINTER`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pizza_plan(
+    input: *const PizzaPlanInput,
+    ingredients_out: *mut PizzaIngredients,
+    timeline_out: *mut PizzaTimeline,
+) -> PizzaStatus {
+    if input.is_null() || ingredients_out.is_null() || timeline_out.is_null() {
+        return PizzaStatus::NullPointer;
+    }
+    // SAFETY: checked for NULL above, validity is the caller's contract.
+    let input = unsafe { *input };
+    let p = match pizza_core::try_plan(PlanInput {
+        total_dough_g: input.total_dough_g,
+        hydration: input.hydration,
+        salt_per_kg: input.salt_per_kg,
+        yeast: input.yeast.into(),
+        temp_c: input.temp_c,
+        w: input.w,
+        total_hours: input.total_hours,
+        fridge_hours: input.fridge_hours,
+        warmup_hours: input.warmup_hours,
+        fridge_factor: input.fridge_factor,
+        viability: 1.0,
+        fresh_per_dry: FRESH_PER_DRY,
+        malt_pct: 0.0,
+        enrichment: Enrichment::default(),
+        model: Arc::new(Q10::default()),
+        yeast_pct: None,
+        split: None,
+        binder: None,
+        blend: None,
+    }) {
+        Ok(p) => p,
+        Err(e) => return e.into(),
+    };
+    // SAFETY: as above.
+    unsafe {
+        *ingredients_out = ingredients(p.ingredients);
+        *timeline_out = p.timeline.into();
+    }
+    PizzaStatus::Ok
+}
+
+/// Ingredients for `total_dough_g` of dough fermented `effective_hours`.
+///
+/// # Safety
+///
+/// `out` must be valid for writes; NULL returns `PIZZA_STATUS_NULL_POINTER`.
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn pizza_compute_ingredients(
+    total_dough_g: f64,
+    hydration: f64,
+    salt_per_kg: f64,
+    yeast: PizzaYeast,
+    temp_c: f64,
+    w: u16,
+    effective_hours: f64,
+    out: *mut PizzaIngredients,
+) -> PizzaStatus {
+    if out.is_null() {
+        return PizzaStatus::NullPointer;
+    }
+    let input = IngredientsInput {
+        total_dough_g,
+        hydration,
+        salt_per_kg,
+        yeast: yeast.into(),
+        temp_c,
+        w,
+        effective_hours,
+        viability: 1.0,
+        fresh_per_dry: FRESH_PER_DRY,
+        malt_pct: 0.0,
+        enrichment: Enrichment::default(),
+        model: &Q10::default(),
+        yeast_pct: None,
+    };
+    if let Err(e) = input.validate() {
+        return e.into();
+    }
+    // SAFETY: checked for NULL above, validity is the caller's contract.
+    unsafe { *out = ingredients(pizza_core::compute_ingredients(input)) };
+    PizzaStatus::Ok
+}
+
+/// Phases for `total_hours` at `temp_c`; no fridge when `fridge_hours` is 0.
+#[unsafe(no_mangle)]
+pub extern "C" fn pizza_timeline(
+    total_hours: f64,
+    temp_c: f64,
+    fridge_hours: f64,
+    warmup_hours: f64,
+) -> PizzaTimeline {
+    pizza_core::timeline(total_hours, temp_c, fridge_hours, warmup_hours).into()
+}
+
+/// Room hours plus fridge hours at `fridge_factor` speed.
+#[unsafe(no_mangle)]
+pub extern "C" fn pizza_effective_hours(
+    total_hours: f64,
+    fridge_hours: f64,
+    fridge_factor: f64,
+) -> f64 {
+    pizza_core::effective_hours(total_hours, fridge_hours, fridge_factor)
+}
+
+/// English description of `status` (static, NUL-terminated; do not free).
+#[unsafe(no_mangle)]
+pub extern "C" fn pizza_status_message(status: PizzaStatus) -> *const c_char {
+    let msg: &'static std::ffi::CStr = match status {
+        PizzaStatus::Ok => c"ok",
+        PizzaStatus::NullPointer => c"a required pointer was NULL",
+        PizzaStatus::NoDough => c"dough weight must be > 0",
+        PizzaStatus::Hydration => c"hydration must be between 0.55 and 0.85",
+        PizzaStatus::TotalHours => c"total hours must be > 0",
+        PizzaStatus::EffectiveHours => c"effective hours must be > 0",
+        PizzaStatus::NegativeHours => c"fridge and warmup hours must be >= 0",
+        PizzaStatus::FridgeTooLong => c"fridge plus warmup must be less than the total",
+    };
+    msg.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_matches_core_and_reports_errors() {
+        let mut input = PizzaPlanInput {
+            total_dough_g: 560.0,
+            hydration: 0.75,
+            salt_per_kg: 20.0,
+            yeast: PizzaYeast::Dry,
+            temp_c: 25.0,
+            w: 270,
+            total_hours: 24.0,
+            fridge_hours: 16.0,
+            warmup_hours: 3.0,
+            fridge_factor: 0.25,
+        };
+        let mut ing = PizzaIngredients::default();
+        let mut tl = PizzaTimeline::default();
+        let status = unsafe { pizza_plan(&input, &mut ing, &mut tl) };
+        assert_eq!(status, PizzaStatus::Ok);
+        assert_eq!(tl, pizza_timeline(24.0, 25.0, 16.0, 3.0));
+
+        let mut direct = PizzaIngredients::default();
+        let eff = pizza_effective_hours(24.0, 16.0, 0.25);
+        let status = unsafe {
+            pizza_compute_ingredients(
+                560.0,
+                0.75,
+                20.0,
+                PizzaYeast::Dry,
+                25.0,
+                270,
+                eff,
+                &mut direct,
+            )
+        };
+        assert_eq!(status, PizzaStatus::Ok);
+        assert_eq!(direct, ing);
+
+        input.hydration = 0.95;
+        let status = unsafe { pizza_plan(&input, &mut ing, &mut tl) };
+        assert_eq!(status, PizzaStatus::Hydration);
+        assert_eq!(
+            unsafe { pizza_plan(std::ptr::null(), &mut ing, &mut tl) },
+            PizzaStatus::NullPointer
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Python bindings for pizza-core, for parameter sweeps and calibrating the
+//! yeast model in notebooks. Results are plain dicts keyed like the Rust
+//! structs (`flour_g`, `bulk_h`, ...), ready for `pandas.DataFrame`.
+//!
+//! ```python
+//! import pizza
+//! p = pizza.plan(560, hydration=0.70, temp_c=22, w=280, total_hours=24, fridge_hours=16)
+//! p["ingredients"]["yeast_g"], p["timeline"]["bulk_h"]
+//! [pizza.yeast_percent_dry(t, 260, 12, model="arrhenius", ea_kj=55) for t in range(16, 31)]
+//! ```
+
+use pizza_core::{
+    FRESH_PER_DRY, Ingredients, IngredientsInput, PlanInput, Timeline, YeastKind,
+    enriched::Enrichment,
+    model::{Arrhenius, DEFAULT_ACTIVATION_ENERGY_KJ, FermentationModel, Q10},
+};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+use std::sync::Arc;
+
+fn yeast_kind(name: &str) -> PyResult<YeastKind> {
+    match name {
+        "dry" => Ok(YeastKind::Dry),
+        "fresh" => Ok(YeastKind::Fresh),
+        other => Err(PyValueError::new_err(format!(
+            "yeast must be 'dry' or 'fresh', got '{other}'"
+        ))),
+    }
+}
+
+/// `"q10"` (activity × `q10` per 10 °C) or `"arrhenius"` (activation energy `ea_kj`).
+fn model(name: &str, q10: f64, ea_kj: f64) -> PyResult<Arc<dyn FermentationModel>> {
+    match name {
+        "q10" => Ok(Arc::new(Q10 { q10 })),
+        "arrhenius" => Ok(Arc::new(Arrhenius { ea_kj })),
+        other => Err(PyValueError::new_err(format!(
+            "model must be 'q10' or 'arrhenius', got '{other}'"
+        ))),
+    }
+}
+
+fn ingredients_dict<'py>(py: Python<'py>, i: &Ingredients) -> PyResult<Bound<'py, PyDict>> {
+    let d = PyDict::new(py);
+    d.set_item("flour_g", i.flour_g)?;
+    d.set_item("water_g", i.water_g)?;
+    d.set_item("salt_g", i.salt_g)?;
+    d.set_item("yeast_g", i.yeast_g)?;
+    d.set_item("malt_g", i.malt_g)?;
+    d.set_item("binder_g", i.binder_g)?;
+    d.set_item("rice_g", i.rice_g)?;
+    d.set_item("soy_g", i.soy_g)?;
+    d.set_item("milk_g", i.milk_g)?;
+    d.set_item("oil_g", i.oil_g)?;
+    d.set_item("sugar_g", i.sugar_g)?;
+    d.set_item("starter_total_g", i.starter_total_g)?;
+    Ok(d)
+}
+
+fn timeline_dict<'py>(py: Python<'py>, t: &Timeline) -> PyResult<Bound<'py, PyDict>> {
+    let d = PyDict::new(py);
+    d.set_item("bulk_h", t.bulk_h)?;
+    d.set_item("fridge_h", t.fridge_h)?;
+    d.set_item("warmup_h", t.warmup_h)?;
+    d.set_item("proof_h", t.proof_h)?;
+    Ok(d)
+}
+
+/// Ingredients (grams) for `total_dough_g` of dough fermented `effective_hours`.
+/// Hydration and `malt_pct`/`yeast_pct` are fractions, salt is g/kg flour.
+#[pyfunction]
+#[pyo3(signature = (
+    total_dough_g, hydration = 0.75, salt_per_kg = 20.0, yeast = "dry", temp_c = 25.0,
+    w = 260, effective_hours = 11.0, viability = 1.0, fresh_per_dry = FRESH_PER_DRY,
+    malt_pct = 0.0, yeast_pct = None, model = "q10", q10 = 2.0,
+    ea_kj = DEFAULT_ACTIVATION_ENERGY_KJ
+))]
+#[allow(clippy::too_many_arguments)]
+fn compute_ingredients<'py>(
+    py: Python<'py>,
+    total_dough_g: f64,
+    hydration: f64,
+    salt_per_kg: f64,
+    yeast: &str,
+    temp_c: f64,
+    w: u16,
+    effective_hours: f64,
+    viability: f64,
+    fresh_per_dry: f64,
+    malt_pct: f64,
+    yeast_pct: Option<f64>,
+    model: &str,
+    q10: f64,
+    ea_kj: f64,
+) -> PyResult<Bound<'py, PyDict>> {
+    let model = self::model(model, q10, ea_kj)?;
+    let input = IngredientsInput {
+        total_dough_g,
+        hydration,
+        salt_per_kg,
+        yeast: yeast_kind(yeast)?,
+        temp_c,
+        w,
+        effective_hours,
+        viability,
+        fresh_per_dry,
+        malt_pct,
+        enrichment: Enrichment::default(),
+        model: &*model,
+        yeast_pct,
+    };
+    input
+        .validate()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    ingredients_dict(py, &pizza_core::compute_ingredients(input))
+}
+
+/// Timeline and ingredients together, as the CLI computes them:
+/// `{"ingredients": {...}, "timeline": {...}, "effective_hours": ...}`.
+#[pyfunction]
+#[pyo3(signature = (
+    total_dough_g, hydration = 0.75, salt_per_kg = 20.0, yeast = "dry", temp_c = 25.0,
+    w = 260, total_hours = 11.0, fridge_hours = 0.0, warmup_hours = 3.0,
+    fridge_factor = 0.25, viability = 1.0, fresh_per_dry = FRESH_PER_DRY, malt_pct = 0.0,
+    yeast_pct = None, model = "q10", q10 = 2.0, ea_kj = DEFAULT_ACTIVATION_ENERGY_KJ
+))]
+#[allow(clippy::too_many_arguments)]
+fn plan<'py>(
+    py: Python<'py>,
+    total_dough_g: f64,
+    hydration: f64,
+    salt_per_kg: f64,
+    yeast: &str,
+    temp_c: f64,
+    w: u16,
+    total_hours: f64,
+    fridge_hours: f64,
+    warmup_hours: f64,
+    fridge_factor: f64,
+    viability: f64,
+    fresh_per_dry: f64,
+    malt_pct: f64,
+    yeast_pct: Option<f64>,
+    model: &str,
+    q10: f64,
+    ea_kj: f64,
+) -> PyResult<Bound<'py, PyDict>> {
+    let p = pizza_core::try_plan(PlanInput {
+        total_dough_g,
+        hydration,
+        salt_per_kg,
+        yeast: yeast_kind(yeast)?,
+        temp_c,
+        w,
+        total_hours,
+        fridge_hours,
+        warmup_hours,
+        fridge_factor,
+        viability,
+        fresh_per_dry,
+        malt_pct,
+        enrichment: Enrichment::default(),
+        model: self::model(model, q10, ea_kj)?,
+        yeast_pct,
+        split: None,
+        binder: None,
+        blend: None,
+    })
+    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let d = PyDict::new(py);
+    d.set_item("ingredients", ingredients_dict(py, &p.ingredients)?)?;
+    d.set_item("timeline", timeline_dict(py, &p.timeline)?)?;
+    d.set_item("effective_hours", p.effective_hours)?;
+    Ok(d)
+}
+
+/// Bulk, fridge, warmup and proof hours (no fridge when `fridge_hours` is 0).
+#[pyfunction]
+#[pyo3(signature = (total_hours, temp_c = 25.0, fridge_hours = 0.0, warmup_hours = 3.0))]
+fn timeline<'py>(
+    py: Python<'py>,
+    total_hours: f64,
+    temp_c: f64,
+    fridge_hours: f64,
+    warmup_hours: f64,
+) -> PyResult<Bound<'py, PyDict>> {
+    timeline_dict(
+        py,
+        &pizza_core::timeline(total_hours, temp_c, fridge_hours, warmup_hours),
+    )
+}
+
+/// Room hours plus fridge hours at `fridge_factor` speed.
+#[pyfunction]
+#[pyo3(signature = (total_hours, fridge_hours = 0.0, fridge_factor = 0.25))]
+fn effective_hours(total_hours: f64, fridge_hours: f64, fridge_factor: f64) -> f64 {
+    pizza_core::effective_hours(total_hours, fridge_hours, fridge_factor)
+}
+
+/// The model's dry yeast as a fraction of flour (0.0035 = 0.35%), the value
+/// to fit against logged bakes.
+#[pyfunction]
+#[pyo3(signature = (
+    temp_c, w, effective_hours, model = "q10", q10 = 2.0, ea_kj = DEFAULT_ACTIVATION_ENERGY_KJ
+))]
+fn yeast_percent_dry(
+    temp_c: f64,
+    w: u16,
+    effective_hours: f64,
+    model: &str,
+    q10: f64,
+    ea_kj: f64,
+) -> PyResult<f64> {
+    Ok(self::model(model, q10, ea_kj)?.yeast_percent_dry(temp_c, w, effective_hours))
+}
+
+#[pymodule]
+fn pizza(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(compute_ingredients, m)?)?;
+    m.add_function(wrap_pyfunction!(plan, m)?)?;
+    m.add_function(wrap_pyfunction!(timeline, m)?)?;
+    m.add_function(wrap_pyfunction!(effective_hours, m)?)?;
+    m.add_function(wrap_pyfunction!(yeast_percent_dry, m)?)?;
+    m.add("FRESH_PER_DRY", FRESH_PER_DRY)?;
+    Ok(())
+}